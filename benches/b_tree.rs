@@ -0,0 +1,69 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use ds_rs::b_tree::BTree;
+use ds_rs::binary_tree::BinaryTree;
+use rand::{thread_rng, Rng};
+
+fn random_values(count: usize) -> Vec<i32> {
+    (0..count)
+        .map(|_| thread_rng().gen_range(i32::MIN..i32::MAX))
+        .collect()
+}
+
+pub fn insert(c: &mut Criterion) {
+    let mut group = c.benchmark_group("insert random elements");
+
+    for count in [1_000, 10_000] {
+        let values = random_values(count);
+
+        group.bench_with_input(
+            BenchmarkId::new("BinaryTree", count),
+            &values,
+            |b, values| b.iter(|| values.iter().copied().collect::<BinaryTree<i32>>()),
+        );
+
+        group.bench_with_input(BenchmarkId::new("BTree", count), &values, |b, values| {
+            b.iter(|| values.iter().copied().collect::<BTree<i32>>())
+        });
+    }
+
+    group.finish();
+}
+
+pub fn contains(c: &mut Criterion) {
+    let mut group = c.benchmark_group("look up every element");
+
+    for count in [1_000, 10_000] {
+        let values = random_values(count);
+        let binary_tree: BinaryTree<i32> = values.iter().copied().collect();
+        let b_tree: BTree<i32> = values.iter().copied().collect();
+
+        group.bench_with_input(
+            BenchmarkId::new("BinaryTree", count),
+            &values,
+            |b, values| b.iter(|| values.iter().all(|v| binary_tree.contains(black_box(v)))),
+        );
+
+        group.bench_with_input(BenchmarkId::new("BTree", count), &values, |b, values| {
+            b.iter(|| values.iter().all(|v| b_tree.contains(black_box(v))))
+        });
+    }
+
+    group.finish();
+}
+
+pub fn iter(c: &mut Criterion) {
+    let mut group = c.benchmark_group("iterate over 10.000 element tree");
+    let values = random_values(10_000);
+    let binary_tree: BinaryTree<i32> = values.iter().copied().collect();
+    let b_tree: BTree<i32> = values.iter().copied().collect();
+
+    group.bench_function("BinaryTree", |b| {
+        b.iter(|| binary_tree.iter().for_each(|_| {}))
+    });
+    group.bench_function("BTree", |b| b.iter(|| b_tree.iter().for_each(|_| {})));
+
+    group.finish();
+}
+
+criterion_group!(benches, insert, contains, iter);
+criterion_main!(benches);
@@ -0,0 +1,1019 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::mem;
+
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
+
+use crate::sorted_set::SortedSet;
+
+/// A binary search tree that keeps all of its nodes in a single [`Vec`], linked by `u32` indices.
+///
+/// The tree behaves like a [`BinaryTree`](crate::binary_tree::BinaryTree), without rebalancing,
+/// but inserts only allocate when the arena grows, and nodes sit close together in memory.
+/// Slots freed by removals are reused by later inserts. Every node also links to its parent,
+/// which lets the iterators walk the tree without a stack.
+///
+/// # Examples
+/// ```
+/// # use ds_rs::arena_tree::ArenaTree;
+/// let mut tree = ArenaTree::with_capacity(3);
+/// tree.extend([5, 4, 6]);
+/// let capacity = tree.capacity();
+///
+/// assert_eq!(tree.remove(&4), Some(4));
+/// tree.insert(3);
+///
+/// // the slot of the removed element was reused
+/// assert_eq!(tree.capacity(), capacity);
+/// assert!(tree.in_order().eq(&[3, 5, 6]));
+/// ```
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "json",
+    serde(
+        try_from = "ArenaTreeData<T>",
+        bound(deserialize = "T: Ord + Deserialize<'de>")
+    )
+)]
+#[derive(Debug, Clone)]
+pub struct ArenaTree<T> {
+    slots: Vec<Slot<T>>,
+    root: Option<u32>,
+    free: Option<u32>,
+    count: usize,
+}
+
+/// The serialized form of an [`ArenaTree`], which is checked before it becomes one.
+#[cfg(feature = "json")]
+#[derive(Deserialize)]
+struct ArenaTreeData<T> {
+    slots: Vec<Slot<T>>,
+    root: Option<u32>,
+    free: Option<u32>,
+    count: usize,
+}
+
+#[cfg(feature = "json")]
+impl<T: Ord> TryFrom<ArenaTreeData<T>> for ArenaTree<T> {
+    type Error = &'static str;
+
+    fn try_from(data: ArenaTreeData<T>) -> Result<Self, Self::Error> {
+        let tree = Self {
+            slots: data.slots,
+            root: data.root,
+            free: data.free,
+            count: data.count,
+        };
+        tree.check_invariants()?;
+
+        Ok(tree)
+    }
+}
+
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+enum Slot<T> {
+    Occupied(Node<T>),
+    Free { next: Option<u32> },
+}
+
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+struct Node<T> {
+    value: T,
+    parent: Option<u32>,
+    left: Option<u32>,
+    right: Option<u32>,
+}
+
+impl<T> ArenaTree<T> {
+    /// Constructs a new empty `ArenaTree<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ds_rs::arena_tree::ArenaTree;
+    /// # #[allow(unused_mut)]
+    /// let mut tree: ArenaTree<i32> = ArenaTree::new();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Constructs a new empty `ArenaTree<T>` with room for at least `capacity` elements before it allocates.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ds_rs::arena_tree::ArenaTree;
+    /// let tree: ArenaTree<i32> = ArenaTree::with_capacity(100);
+    /// assert!(tree.capacity() >= 100);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            slots: Vec::with_capacity(capacity),
+            root: None,
+            free: None,
+            count: 0,
+        }
+    }
+
+    /// Returns the number of elements the tree can hold without allocating.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.slots.capacity()
+    }
+
+    /// Returns `true` if the tree contains no elements.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::arena_tree::ArenaTree;
+    /// let mut tree = ArenaTree::new();
+    /// assert!(tree.is_empty());
+    ///
+    /// tree.insert(0);
+    /// assert!(!tree.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Clears the tree of all elements, keeping the memory of the arena.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::arena_tree::ArenaTree;
+    /// let mut tree = ArenaTree::from(vec![1, 2, 3]);
+    ///
+    /// tree.clear();
+    /// assert!(tree.is_empty());
+    /// assert!(tree.capacity() >= 3);
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.slots.clear();
+        self.root = None;
+        self.free = None;
+        self.count = 0;
+    }
+
+    /// Traverses and returns the height of the tree.
+    ///
+    /// An empty tree has a height of `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::arena_tree::ArenaTree;
+    /// let tree = ArenaTree::from(vec![5, 4, 6, 3]);
+    /// assert_eq!(tree.height(), 3);
+    /// ```
+    pub fn height(&self) -> usize {
+        let mut height = 0;
+        let mut stack: Vec<_> = self.root.map(|root| (1, root)).into_iter().collect();
+
+        while let Some((depth, index)) = stack.pop() {
+            height = height.max(depth);
+            if let Some(node) = self.node(index) {
+                stack.extend(node.left.map(|left| (depth + 1, left)));
+                stack.extend(node.right.map(|right| (depth + 1, right)));
+            }
+        }
+
+        height
+    }
+
+    /// Returns a reference to the value of the root, or `None` if the tree is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::arena_tree::ArenaTree;
+    /// let tree = ArenaTree::from(vec![5, 4, 6]);
+    /// assert_eq!(tree.root(), Some(&5));
+    /// ```
+    #[inline]
+    pub fn root(&self) -> Option<&T> {
+        self.node(self.root?).map(|node| &node.value)
+    }
+
+    /// Returns the number of elements in the tree.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::arena_tree::ArenaTree;
+    /// let tree = ArenaTree::from(vec![5, 4, 6]);
+    /// assert_eq!(tree.count(), 3);
+    /// ```
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns a non-consuming iterator over the `ArenaTree`.
+    ///
+    /// The iterator yields all items in the tree using the **preorder tree traversal technique**.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::arena_tree::ArenaTree;
+    /// let tree = ArenaTree::from(vec![5, 4, 6]);
+    /// let mut tree_iter = tree.iter();
+    ///
+    /// assert_eq!(tree_iter.next(), Some(&5));
+    /// assert_eq!(tree_iter.next(), Some(&4));
+    /// assert_eq!(tree_iter.next(), Some(&6));
+    ///
+    /// // the iterator is now empty
+    /// assert_eq!(tree_iter.next(), None);
+    /// ```
+    #[inline]
+    #[must_use = "iterators are evaluated lazily"]
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.into_iter()
+    }
+
+    /// Returns a non-consuming iterator over the `ArenaTree` that yields all items in ascending order.
+    ///
+    /// The traversal follows the parent links, and keeps nothing but the position of the next item.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::arena_tree::ArenaTree;
+    /// let tree = ArenaTree::from(vec![5, 4, 6]);
+    /// let mut tree_iter = tree.in_order();
+    ///
+    /// assert_eq!(tree_iter.next(), Some(&4));
+    /// assert_eq!(tree_iter.next(), Some(&5));
+    /// assert_eq!(tree_iter.next(), Some(&6));
+    ///
+    /// // the iterator is now empty
+    /// assert_eq!(tree_iter.next(), None);
+    /// ```
+    #[must_use = "iterators are evaluated lazily"]
+    pub fn in_order(&self) -> InOrder<'_, T> {
+        InOrder {
+            next: self.root.and_then(|root| self.leftmost(root)),
+            tree: self,
+            remaining: self.count,
+        }
+    }
+
+    /// Returns the smallest element in the `ArenaTree`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::arena_tree::ArenaTree;
+    /// let tree = ArenaTree::from(vec![8, 4, 6, 16, -5, 25]);
+    /// assert_eq!(tree.min(), Some(&-5));
+    /// ```
+    pub fn min(&self) -> Option<&T> {
+        self.node(self.leftmost(self.root?)?)
+            .map(|node| &node.value)
+    }
+
+    /// Returns the largest element in the `ArenaTree`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::arena_tree::ArenaTree;
+    /// let tree = ArenaTree::from(vec![8, 4, 6, 16, -5, 25]);
+    /// assert_eq!(tree.max(), Some(&25));
+    /// ```
+    pub fn max(&self) -> Option<&T> {
+        let mut node = self.node(self.root?)?;
+        while let Some(right) = node.right.and_then(|right| self.node(right)) {
+            node = right;
+        }
+
+        Some(&node.value)
+    }
+
+    /// Returns the node in the slot at `index`, or `None` if the slot is free.
+    fn node(&self, index: u32) -> Option<&Node<T>> {
+        match self.slots.get(index as usize)? {
+            Slot::Occupied(node) => Some(node),
+            Slot::Free { .. } => None,
+        }
+    }
+
+    /// Returns the node in the slot at `index` mutably, or `None` if the slot is free.
+    fn node_mut(&mut self, index: u32) -> Option<&mut Node<T>> {
+        match self.slots.get_mut(index as usize)? {
+            Slot::Occupied(node) => Some(node),
+            Slot::Free { .. } => None,
+        }
+    }
+
+    /// Returns the index of the smallest node in the subtree rooted at `index`.
+    fn leftmost(&self, mut index: u32) -> Option<u32> {
+        while let Some(left) = self.node(index)?.left {
+            index = left;
+        }
+
+        Some(index)
+    }
+
+    /// Stores the node in a free slot, or at the end of the arena if there is none, and returns its index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arena would hold more than `u32::MAX` slots.
+    fn allocate(&mut self, node: Node<T>) -> u32 {
+        if let Some(index) = self.free {
+            if let Some(slot) = self.slots.get_mut(index as usize) {
+                if let Slot::Free { next } = *slot {
+                    *slot = Slot::Occupied(node);
+                    self.free = next;
+                    return index;
+                }
+            }
+        }
+
+        let Ok(index) = u32::try_from(self.slots.len()) else {
+            panic!("an arena tree holds at most u32::MAX elements");
+        };
+        self.slots.push(Slot::Occupied(node));
+
+        index
+    }
+
+    /// Frees the slot at `index` for later inserts, and returns the node it held.
+    fn release(&mut self, index: u32) -> Option<Node<T>> {
+        let slot = self.slots.get_mut(index as usize)?;
+        match mem::replace(slot, Slot::Free { next: self.free }) {
+            Slot::Occupied(node) => {
+                self.free = Some(index);
+                Some(node)
+            }
+            free => {
+                *slot = free;
+                None
+            }
+        }
+    }
+
+    /// Puts the subtree rooted at `replacement` in the place of the node at `index` under its parent.
+    fn transplant(&mut self, index: u32, replacement: Option<u32>) {
+        let parent = self.node(index).and_then(|node| node.parent);
+
+        match parent.and_then(|parent| self.node_mut(parent)) {
+            Some(parent) if parent.left == Some(index) => parent.left = replacement,
+            Some(parent) => parent.right = replacement,
+            None => self.root = replacement,
+        }
+        if let Some(node) = replacement.and_then(|replacement| self.node_mut(replacement)) {
+            node.parent = parent;
+        }
+    }
+}
+
+impl<T> Default for ArenaTree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: PartialEq> PartialEq for ArenaTree<T> {
+    /// Returns `true` if both trees hold equal elements in the same shape, regardless of where the nodes sit in their arenas.
+    fn eq(&self, other: &Self) -> bool {
+        self.count == other.count && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for ArenaTree<T> {}
+
+impl<T: Hash> Hash for ArenaTree<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.count.hash(state);
+        for value in self {
+            value.hash(state);
+        }
+    }
+}
+
+/// The methods in this implementation block require trait bounds to correctly apply the logic of the methods.
+///
+/// The trait bound in question is [`Ord`], which is used to compare elements in the tree.
+impl<T: Ord> ArenaTree<T> {
+    /// Inserts the provided value into the `ArenaTree`, and preserves the properties of the binary tree.
+    ///
+    /// Duplicate values are discarded.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the tree would hold more than `u32::MAX` elements.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::arena_tree::ArenaTree;
+    /// let mut tree = ArenaTree::new();
+    /// tree.insert(5);
+    /// assert_eq!(tree.root(), Some(&5));
+    /// assert_eq!(tree.height(), 1);
+    /// assert_eq!(tree.count(), 1);
+    /// ```
+    pub fn insert(&mut self, value: T) {
+        let mut parent = None;
+        let mut current = self.root;
+
+        while let Some(node) = current.and_then(|index| self.node(index)) {
+            let next = match value.cmp(&node.value) {
+                Ordering::Equal => return,
+                Ordering::Less => node.left,
+                Ordering::Greater => node.right,
+            };
+            parent = current.map(|index| (index, value < node.value));
+            current = next;
+        }
+
+        let index = self.allocate(Node {
+            value,
+            parent: parent.map(|(index, _)| index),
+            left: None,
+            right: None,
+        });
+        match parent {
+            Some((parent, is_left)) => {
+                if let Some(parent) = self.node_mut(parent) {
+                    if is_left {
+                        parent.left = Some(index);
+                    } else {
+                        parent.right = Some(index);
+                    }
+                }
+            }
+            None => self.root = Some(index),
+        }
+        self.count += 1;
+    }
+
+    /// Returns `true` if the `ArenaTree` contains the given value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::arena_tree::ArenaTree;
+    /// let tree = ArenaTree::from(vec![8, 4, 6]);
+    /// assert!(tree.contains(&6));
+    /// assert!(!tree.contains(&7));
+    /// ```
+    pub fn contains(&self, target: &T) -> bool {
+        self.find(target).is_some()
+    }
+
+    /// Removes the value equal to `target` from the `ArenaTree`, and returns it.
+    ///
+    /// A node with two children takes the smallest value of its right subtree, and the slot
+    /// of that value is freed instead.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::arena_tree::ArenaTree;
+    /// let mut tree = ArenaTree::from(vec![8, 4, 6, 16, -5, 25]);
+    /// assert_eq!(tree.remove(&8), Some(8));
+    /// assert_eq!(tree.remove(&8), None);
+    /// assert_eq!(tree.count(), 5);
+    /// ```
+    pub fn remove(&mut self, target: &T) -> Option<T> {
+        let index = self.find(target)?;
+        let node = self.node(index)?;
+
+        let removed = match (node.left, node.right) {
+            (Some(_), Some(right)) => {
+                let successor = self.leftmost(right)?;
+                let replacement = self.node(successor)?.right;
+                self.transplant(successor, replacement);
+
+                let successor = self.release(successor)?;
+                mem::replace(&mut self.node_mut(index)?.value, successor.value)
+            }
+            (child, None) | (None, child) => {
+                self.transplant(index, child);
+                self.release(index)?.value
+            }
+        };
+        self.count -= 1;
+
+        Some(removed)
+    }
+
+    /// Returns the index of the node holding a value equal to `target`.
+    fn find(&self, target: &T) -> Option<u32> {
+        let mut index = self.root?;
+
+        loop {
+            let node = self.node(index)?;
+            index = match target.cmp(&node.value) {
+                Ordering::Equal => return Some(index),
+                Ordering::Less => node.left?,
+                Ordering::Greater => node.right?,
+            };
+        }
+    }
+}
+
+impl<T: Ord> SortedSet<T> for ArenaTree<T> {
+    type Iter<'a>
+        = InOrder<'a, T>
+    where
+        T: 'a;
+
+    fn insert(&mut self, value: T) -> bool {
+        let count = self.count;
+        ArenaTree::insert(self, value);
+
+        self.count > count
+    }
+
+    fn remove(&mut self, target: &T) -> Option<T> {
+        ArenaTree::remove(self, target)
+    }
+
+    fn contains(&self, target: &T) -> bool {
+        ArenaTree::contains(self, target)
+    }
+
+    fn min(&self) -> Option<&T> {
+        ArenaTree::min(self)
+    }
+
+    fn max(&self) -> Option<&T> {
+        ArenaTree::max(self)
+    }
+
+    fn len(&self) -> usize {
+        self.count
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        self.in_order()
+    }
+}
+
+impl<T: Ord> From<Vec<T>> for ArenaTree<T> {
+    /// Creates an `ArenaTree<T>` from `Vec<T>`, with room for every element in the arena.
+    fn from(vec: Vec<T>) -> Self {
+        let mut tree = Self::with_capacity(vec.len());
+        tree.extend(vec);
+
+        tree
+    }
+}
+
+impl<T> AsRef<ArenaTree<T>> for ArenaTree<T> {
+    /// Returns an immutable reference to the `ArenaTree`.
+    #[inline]
+    fn as_ref(&self) -> &ArenaTree<T> {
+        self
+    }
+}
+
+impl<T> AsMut<ArenaTree<T>> for ArenaTree<T> {
+    /// Returns a mutable reference to the `ArenaTree`.
+    #[inline]
+    fn as_mut(&mut self) -> &mut ArenaTree<T> {
+        self
+    }
+}
+
+impl<T: Ord> FromIterator<T> for ArenaTree<T> {
+    /// Constructs an `ArenaTree<T>` from an iterator for `T`.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut tree = ArenaTree::new();
+        tree.extend(iter);
+
+        tree
+    }
+}
+
+impl<T: Ord> Extend<T> for ArenaTree<T> {
+    /// Extends the `ArenaTree` with the contents of the provided iterator.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<T> IntoIterator for ArenaTree<T> {
+    type Item = T;
+
+    type IntoIter = IntoIter<T>;
+
+    /// Returns a consuming iterator over the `ArenaTree`.
+    ///
+    /// The iterator yields all items in the tree using the **preorder tree traversal technique**.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::arena_tree::ArenaTree;
+    /// let tree = ArenaTree::from(vec![5, 4, 6]);
+    /// let mut tree_iter = tree.into_iter();
+    ///
+    /// assert_eq!(tree_iter.next(), Some(5));
+    /// assert_eq!(tree_iter.next(), Some(4));
+    /// assert_eq!(tree_iter.next(), Some(6));
+    ///
+    /// // the iterator is now empty
+    /// assert_eq!(tree_iter.next(), None);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            stack: self.root.into_iter().collect(),
+            remaining: self.count,
+            tree: self,
+        }
+    }
+}
+
+/// An iterator that moves out of the `ArenaTree`.
+///
+/// This `struct` is created by the `into_iter` method on [`ArenaTree`] (provided by the [`IntoIterator`] trait).
+pub struct IntoIter<T> {
+    tree: ArenaTree<T>,
+    stack: Vec<u32>,
+    remaining: usize,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.tree.release(self.stack.pop()?)?;
+        self.stack.extend(node.right);
+        self.stack.extend(node.left);
+        self.remaining -= 1;
+
+        Some(node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<'a, T> IntoIterator for &'a ArenaTree<T> {
+    type Item = &'a T;
+
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Iter {
+            tree: self,
+            next: self.root,
+            remaining: self.count,
+        }
+    }
+}
+
+/// An iterator that borrows from the `ArenaTree`.
+///
+/// This `struct` is created by the `iter` method on [`ArenaTree`].
+pub struct Iter<'a, T> {
+    tree: &'a ArenaTree<T>,
+    next: Option<u32>,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.next?;
+        let node = self.tree.node(index)?;
+
+        // the next node in preorder is the first child, or else the right sibling of the closest ancestor that has one
+        self.next = node.left.or(node.right).or_else(|| {
+            let mut child = index;
+            let mut parent = node.parent;
+
+            while let Some(ancestor) = parent.and_then(|parent| self.tree.node(parent)) {
+                if ancestor.left == Some(child) && ancestor.right.is_some() {
+                    return ancestor.right;
+                }
+                child = parent?;
+                parent = ancestor.parent;
+            }
+
+            None
+        });
+        self.remaining -= 1;
+
+        Some(&node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+/// An iterator that borrows from the `ArenaTree`, and yields its items in ascending order.
+///
+/// This `struct` is created by the `in_order` method on [`ArenaTree`].
+pub struct InOrder<'a, T> {
+    tree: &'a ArenaTree<T>,
+    next: Option<u32>,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for InOrder<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let index = self.next?;
+        let node = self.tree.node(index)?;
+
+        // the successor is the smallest node on the right, or else the closest ancestor reached from its left
+        self.next = match node.right {
+            Some(right) => self.tree.leftmost(right),
+            None => {
+                let mut child = index;
+                let mut parent = node.parent;
+
+                while let Some(ancestor) = parent.and_then(|parent| self.tree.node(parent)) {
+                    if ancestor.left == Some(child) {
+                        break;
+                    }
+                    child = parent?;
+                    parent = ancestor.parent;
+                }
+
+                parent
+            }
+        };
+        self.remaining -= 1;
+
+        Some(&node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for InOrder<'a, T> {}
+
+#[cfg(any(test, feature = "json"))]
+impl<T: Ord> ArenaTree<T> {
+    /// Checks that every node is reached from the root exactly once and links back to its parent,
+    /// that every other slot is on the free list once, and that the elements are in ascending order.
+    fn check_invariants(&self) -> Result<(), &'static str> {
+        let mut seen = Vec::new();
+        seen.resize(self.slots.len(), false);
+        let mut visit = |index: u32| match seen.get_mut(index as usize) {
+            Some(seen) if !*seen => {
+                *seen = true;
+                true
+            }
+            _ => false,
+        };
+
+        let mut reachable = 0;
+        let mut stack: Vec<_> = self.root.map(|root| (root, None)).into_iter().collect();
+        while let Some((index, parent)) = stack.pop() {
+            if !visit(index) {
+                return Err(
+                    "a link of an arena tree points outside the arena or to a node reached before",
+                );
+            }
+            let Some(node) = self.node(index) else {
+                return Err("a link of an arena tree points to a free slot");
+            };
+            if node.parent != parent {
+                return Err("a node of an arena tree does not link back to its parent");
+            }
+            reachable += 1;
+
+            for child in node.left.into_iter().chain(node.right) {
+                stack.push((child, Some(index)));
+            }
+        }
+
+        let mut free = 0;
+        let mut next = self.free;
+        while let Some(index) = next {
+            if !visit(index) {
+                return Err("the free list of an arena tree points outside the arena or to a slot reached before");
+            }
+            let Some(Slot::Free { next: following }) = self.slots.get(index as usize) else {
+                return Err("the free list of an arena tree points to an occupied slot");
+            };
+            free += 1;
+            next = *following;
+        }
+
+        if reachable != self.count {
+            return Err("the count of an arena tree differs from the number of its nodes");
+        }
+        if reachable + free != self.slots.len() {
+            return Err(
+                "an arena tree has slots that are neither in the tree nor on the free list",
+            );
+        }
+        if !self
+            .in_order()
+            .zip(self.in_order().skip(1))
+            .all(|(a, b)| a < b)
+        {
+            return Err("the elements of an arena tree are not in ascending order");
+        }
+
+        Ok(())
+    }
+
+    #[cfg(test)]
+    fn assert_invariants(&self) {
+        assert_eq!(self.check_invariants(), Ok(()));
+    }
+}
+
+#[cfg(test)]
+mod insert_remove {
+    use super::ArenaTree;
+    use crate::binary_tree::BinaryTree;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    #[test]
+    fn discards_duplicates() {
+        let mut tree = ArenaTree::new();
+        tree.extend([5, 5, 4, 4]);
+
+        assert_eq!(tree.count(), 2);
+        tree.assert_invariants();
+    }
+
+    #[test]
+    fn removes_root_with_two_children() {
+        let mut tree = ArenaTree::from(vec![5, 3, 8, 7, 9, 6]);
+
+        assert_eq!(tree.remove(&5), Some(5));
+        assert_eq!(tree.root(), Some(&6));
+        assert!(tree.iter().eq(&[6, 3, 8, 7, 9]));
+        tree.assert_invariants();
+    }
+
+    #[test]
+    fn reuses_freed_slots() {
+        let mut tree = ArenaTree::from(vec![4, 2, 6, 1, 3, 5, 7]);
+        let slots = tree.slots.len();
+
+        for value in [1, 6, 4] {
+            tree.remove(&value);
+        }
+        tree.extend([10, 11, 12]);
+
+        assert_eq!(tree.slots.len(), slots);
+        tree.assert_invariants();
+    }
+
+    #[test]
+    fn random_operations_match_binary_tree() {
+        let mut rng = StdRng::seed_from_u64(40);
+        let mut tree = ArenaTree::new();
+        let mut expected = BinaryTree::new();
+
+        for _ in 0..3000 {
+            let value = rng.gen_range(0..300);
+            if rng.gen_bool(0.55) {
+                tree.insert(value);
+                expected.insert(value);
+            } else {
+                assert_eq!(tree.remove(&value), expected.remove(&value));
+            }
+
+            assert_eq!(tree.count(), expected.count());
+            assert_eq!(tree.height(), expected.height());
+        }
+
+        tree.assert_invariants();
+        assert!(tree.iter().eq(expected.iter()));
+        assert!(tree.in_order().eq(expected.in_order()));
+    }
+}
+
+#[cfg(test)]
+mod getters {
+    use super::ArenaTree;
+
+    #[test]
+    fn empty_tree() {
+        let tree: ArenaTree<i32> = ArenaTree::new();
+
+        assert_eq!(tree.root(), None);
+        assert_eq!(tree.min(), None);
+        assert_eq!(tree.max(), None);
+        assert_eq!(tree.height(), 0);
+    }
+
+    #[test]
+    fn clear_keeps_capacity() {
+        let mut tree = ArenaTree::from(vec![1, 2, 3]);
+        let capacity = tree.capacity();
+
+        tree.clear();
+        assert!(tree.is_empty());
+        assert_eq!(tree.capacity(), capacity);
+    }
+
+    #[test]
+    fn equality_ignores_arena_layout() {
+        let mut a = ArenaTree::from(vec![2, 1, 9, 3]);
+        a.remove(&9);
+        let b = ArenaTree::from(vec![2, 1, 3]);
+
+        assert_ne!(a.slots.len(), b.slots.len());
+        assert_eq!(a, b);
+        assert_ne!(a, ArenaTree::from(vec![1, 2, 3]));
+    }
+}
+
+#[cfg(test)]
+mod iterator_trait_impls {
+    use super::ArenaTree;
+
+    #[test]
+    fn iterators_report_exact_size() {
+        let tree = ArenaTree::from(vec![8, 4, 6, 16, -5, 25]);
+        let mut in_order = tree.in_order();
+
+        assert_eq!(tree.iter().len(), 6);
+        assert!(in_order.by_ref().copied().take(2).eq([-5, 4]));
+        assert_eq!(in_order.len(), 4);
+        assert!(in_order.copied().eq([6, 8, 16, 25]));
+    }
+
+    #[test]
+    fn into_iter_yields_preorder() {
+        let tree = ArenaTree::from(vec![8, 4, 6, 16, -5, 25]);
+
+        assert!(tree.clone().into_iter().eq(tree.iter().copied()));
+        assert_eq!(tree.into_iter().len(), 6);
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod json {
+    use super::ArenaTree;
+
+    #[test]
+    fn round_trips_through_json() {
+        let mut tree = ArenaTree::from(vec![5, 3, 8, 1, 4]);
+        tree.remove(&3);
+
+        let json = serde_json::to_string(&tree).expect("should parse tree into json");
+        let actual: ArenaTree<i32> =
+            serde_json::from_str(&json).expect("should parse json into tree");
+
+        assert_eq!(actual, tree);
+        actual.assert_invariants();
+    }
+
+    #[test]
+    fn rejects_broken_links() {
+        let tree = ArenaTree::from(vec![5, 3, 8]);
+        let json = serde_json::to_string(&tree).expect("should parse tree into json");
+
+        let parse = |json: &str| {
+            serde_json::from_str::<ArenaTree<i32>>(json).map_err(|error| error.to_string())
+        };
+        assert!(parse(&json).is_ok());
+
+        let cycle = json.replacen(r#""left":null"#, r#""left":0"#, 1);
+        assert!(parse(&cycle).is_err_and(|error| error.contains("reached before")));
+
+        let orphan = json.replacen(r#""parent":0"#, r#""parent":2"#, 1);
+        assert!(parse(&orphan).is_err_and(|error| error.contains("link back to its parent")));
+
+        let count = json.replacen(r#""count":3"#, r#""count":4"#, 1);
+        assert!(parse(&count).is_err_and(|error| error.contains("count")));
+    }
+
+    #[test]
+    fn rejects_broken_free_list() {
+        let mut tree = ArenaTree::from(vec![5, 3, 8]);
+        tree.remove(&3);
+        let json = serde_json::to_string(&tree).expect("should parse tree into json");
+
+        let parse = |json: &str| {
+            serde_json::from_str::<ArenaTree<i32>>(json).map_err(|error| error.to_string())
+        };
+        assert!(parse(&json).is_ok());
+
+        let occupied = json.replacen(r#""free":1"#, r#""free":0"#, 1);
+        assert!(parse(&occupied).is_err_and(|error| error.contains("reached before")));
+
+        let dropped = json.replacen(r#""free":1"#, r#""free":null"#, 1);
+        assert!(parse(&dropped)
+            .is_err_and(|error| error.contains("neither in the tree nor on the free list")));
+    }
+}
@@ -0,0 +1,917 @@
+use std::cmp::Ordering;
+use std::ops::{Bound, RangeBounds};
+
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
+
+pub mod monoid;
+
+use monoid::Measure;
+
+use crate::avl::{self, AvlNode};
+
+/// An ordered set in which every node caches a summary of its subtree.
+///
+/// The summary type `M` describes each element through [`Measure::measure`], and summaries are
+/// combined through [`Monoid::combine`](monoid::Monoid::combine). Because every subtree is
+/// already summarised, [`AugmentedTree::aggregate`] combines the elements of any range in time
+/// proportional to the height of the tree, which is kept balanced.
+///
+/// The [`monoid`] module provides summaries for counts, sums, minimums and maximums.
+///
+/// # Examples
+/// ```
+/// # use ds_rs::augmented_tree::AugmentedTree;
+/// # use ds_rs::augmented_tree::monoid::{Count, Sum};
+/// let tree: AugmentedTree<i32, (Count, Sum<i32>)> = (1..=10).collect();
+///
+/// let (Count(count), Sum(sum)) = tree.aggregate(3..7);
+/// assert_eq!(count, 4);
+/// assert_eq!(sum, 3 + 4 + 5 + 6);
+/// ```
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AugmentedTree<T, M> {
+    root: Link<T, M>,
+    count: usize,
+}
+
+type Link<T, M> = Option<Box<Node<T, M>>>;
+
+impl<T, M> AugmentedTree<T, M> {
+    /// Constructs a new empty `AugmentedTree<T, M>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ds_rs::augmented_tree::AugmentedTree;
+    /// # use ds_rs::augmented_tree::monoid::Sum;
+    /// # #[allow(unused_mut)]
+    /// let mut tree: AugmentedTree<i32, Sum<i32>> = AugmentedTree::new();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            root: None,
+            count: 0,
+        }
+    }
+
+    /// Returns `true` if the tree contains no elements.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::augmented_tree::AugmentedTree;
+    /// # use ds_rs::augmented_tree::monoid::Sum;
+    /// let mut tree: AugmentedTree<i32, Sum<i32>> = AugmentedTree::new();
+    /// assert!(tree.is_empty());
+    ///
+    /// tree.insert(0);
+    /// assert!(!tree.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Clears the tree of all elements.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::augmented_tree::AugmentedTree;
+    /// # use ds_rs::augmented_tree::monoid::Sum;
+    /// let mut tree: AugmentedTree<i32, Sum<i32>> = AugmentedTree::from(vec![1, 2, 3]);
+    ///
+    /// tree.clear();
+    /// assert!(tree.is_empty());
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.root = None;
+        self.count = 0;
+    }
+
+    /// Returns the number of elements in the tree.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::augmented_tree::AugmentedTree;
+    /// # use ds_rs::augmented_tree::monoid::Sum;
+    /// let mut tree: AugmentedTree<i32, Sum<i32>> = AugmentedTree::new();
+    /// tree.insert(5);
+    ///
+    /// // duplicates do not enter the tree
+    /// // and therefore do not affect the count
+    /// tree.insert(5);
+    /// assert_eq!(tree.count(), 1);
+    /// ```
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the height of the tree.
+    ///
+    /// An empty tree has a height of `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::augmented_tree::AugmentedTree;
+    /// # use ds_rs::augmented_tree::monoid::Count;
+    /// let tree: AugmentedTree<i32, Count> = (0..1000).collect();
+    /// assert!(tree.height() <= 15);
+    /// ```
+    #[inline]
+    pub fn height(&self) -> usize {
+        height(&self.root)
+    }
+
+    /// Returns the smallest element in the `AugmentedTree`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::augmented_tree::AugmentedTree;
+    /// # use ds_rs::augmented_tree::monoid::Count;
+    /// let tree: AugmentedTree<i32, Count> = AugmentedTree::from(vec![8, 4, 6, 16, -5, 25]);
+    /// assert_eq!(tree.min(), Some(&-5));
+    /// ```
+    pub fn min(&self) -> Option<&T> {
+        let mut node = self.root.as_deref()?;
+        while let Some(left) = node.left.as_deref() {
+            node = left;
+        }
+
+        Some(&node.value)
+    }
+
+    /// Returns the largest element in the `AugmentedTree`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::augmented_tree::AugmentedTree;
+    /// # use ds_rs::augmented_tree::monoid::Count;
+    /// let tree: AugmentedTree<i32, Count> = AugmentedTree::from(vec![8, 4, 6, 16, -5, 25]);
+    /// assert_eq!(tree.max(), Some(&25));
+    /// ```
+    pub fn max(&self) -> Option<&T> {
+        let mut node = self.root.as_deref()?;
+        while let Some(right) = node.right.as_deref() {
+            node = right;
+        }
+
+        Some(&node.value)
+    }
+
+    /// Returns a non-consuming iterator over the `AugmentedTree`.
+    ///
+    /// The iterator yields all items in ascending order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::augmented_tree::AugmentedTree;
+    /// # use ds_rs::augmented_tree::monoid::Count;
+    /// let tree: AugmentedTree<i32, Count> = AugmentedTree::from(vec![5, 4, 6]);
+    /// let mut tree_iter = tree.iter();
+    ///
+    /// assert_eq!(tree_iter.next(), Some(&4));
+    /// assert_eq!(tree_iter.next(), Some(&5));
+    /// assert_eq!(tree_iter.next(), Some(&6));
+    ///
+    /// // the iterator is now empty
+    /// assert_eq!(tree_iter.next(), None);
+    /// ```
+    #[inline]
+    #[must_use = "iterators are evaluated lazily"]
+    pub fn iter(&self) -> Iter<'_, T, M> {
+        self.into_iter()
+    }
+}
+
+impl<T, M> Default for AugmentedTree<T, M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The methods in this implementation block require trait bounds to correctly apply the logic of the methods.
+///
+/// The trait bound [`Ord`] is used to compare elements in the tree,
+/// and [`Measure`] is used to summarise them.
+impl<T, M> AugmentedTree<T, M>
+where
+    T: Ord,
+    M: Measure<T>,
+{
+    /// Inserts the provided value into the `AugmentedTree`,
+    /// and updates the summaries of the subtrees that contain it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::augmented_tree::AugmentedTree;
+    /// # use ds_rs::augmented_tree::monoid::Sum;
+    /// let mut tree: AugmentedTree<i32, Sum<i32>> = AugmentedTree::new();
+    /// tree.insert(5);
+    /// tree.insert(7);
+    /// assert_eq!(tree.summary(), Sum(12));
+    /// ```
+    pub fn insert(&mut self, value: T) {
+        if insert(&mut self.root, value) {
+            self.count += 1;
+        }
+    }
+
+    /// Returns `true` if the `AugmentedTree` contains an element with the given value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::augmented_tree::AugmentedTree;
+    /// # use ds_rs::augmented_tree::monoid::Count;
+    /// let tree: AugmentedTree<i32, Count> = AugmentedTree::from(vec![8, 4, 6]);
+    /// assert!(tree.contains(&8));
+    /// assert!(!tree.contains(&5));
+    /// ```
+    pub fn contains(&self, target: &T) -> bool {
+        let mut node = self.root.as_deref();
+
+        while let Some(current) = node {
+            node = match target.cmp(&current.value) {
+                Ordering::Equal => return true,
+                Ordering::Less => current.left.as_deref(),
+                Ordering::Greater => current.right.as_deref(),
+            };
+        }
+
+        false
+    }
+
+    /// Removes the element equal to the given value from the `AugmentedTree`, and returns it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::augmented_tree::AugmentedTree;
+    /// # use ds_rs::augmented_tree::monoid::Sum;
+    /// let mut tree: AugmentedTree<i32, Sum<i32>> = AugmentedTree::from(vec![8, 4, 6]);
+    /// assert_eq!(tree.remove(&4), Some(4));
+    /// assert_eq!(tree.remove(&4), None);
+    /// assert_eq!(tree.summary(), Sum(14));
+    /// ```
+    pub fn remove(&mut self, target: &T) -> Option<T> {
+        let removed = remove(&mut self.root, target)?;
+        self.count -= 1;
+
+        Some(removed)
+    }
+
+    /// Returns the summary of every element in the tree.
+    ///
+    /// # Time Complexity
+    ///
+    /// The summary is cached in the root, so this takes constant time.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::augmented_tree::AugmentedTree;
+    /// # use ds_rs::augmented_tree::monoid::Max;
+    /// let tree: AugmentedTree<i32, Max<i32>> = AugmentedTree::from(vec![8, 4, 6]);
+    /// assert_eq!(tree.summary(), Max(Some(8)));
+    /// ```
+    pub fn summary(&self) -> M {
+        summary(&self.root)
+    }
+
+    /// Returns the summary of the elements within the given range, combined in ascending order.
+    ///
+    /// # Time Complexity
+    ///
+    /// Subtrees that lie entirely within the range contribute their cached summary,
+    /// so this takes time proportional to the height of the tree, which is `log(n)`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::augmented_tree::AugmentedTree;
+    /// # use ds_rs::augmented_tree::monoid::{Min, Sum};
+    /// let sums: AugmentedTree<i32, Sum<i32>> = (1..=100).collect();
+    /// assert_eq!(sums.aggregate(..=10), Sum(55));
+    ///
+    /// let mins: AugmentedTree<i32, Min<i32>> = (1..=100).collect();
+    /// assert_eq!(mins.aggregate(50..), Min(Some(50)));
+    /// assert_eq!(mins.aggregate(200..), Min(None));
+    /// ```
+    pub fn aggregate<R: RangeBounds<T>>(&self, range: R) -> M {
+        aggregate(&self.root, range.start_bound(), range.end_bound())
+    }
+
+    /// Returns the smallest element for which the summary of it and every smaller element satisfies the predicate.
+    ///
+    /// The predicate must be monotone: once it holds for the summary of some prefix of the
+    /// elements, it must hold for the summaries of all longer prefixes.
+    ///
+    /// # Time Complexity
+    ///
+    /// The cached summaries guide the search straight to the element, which takes `log(n)` time.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::augmented_tree::AugmentedTree;
+    /// # use ds_rs::augmented_tree::monoid::{Count, Sum};
+    /// // the element at position 3 in ascending order is the first with 4 elements up to it
+    /// let counts: AugmentedTree<i32, Count> = AugmentedTree::from(vec![40, 10, 30, 20, 50]);
+    /// assert_eq!(counts.find_prefix(|Count(count)| *count > 3), Some(&40));
+    ///
+    /// // the first element at which the running total exceeds 10
+    /// let sums: AugmentedTree<i32, Sum<i32>> = (1..=10).collect();
+    /// assert_eq!(sums.find_prefix(|Sum(sum)| *sum > 10), Some(&5));
+    /// ```
+    pub fn find_prefix<P>(&self, mut predicate: P) -> Option<&T>
+    where
+        P: FnMut(&M) -> bool,
+    {
+        let mut prefix = M::identity();
+        let mut node = self.root.as_deref();
+
+        while let Some(current) = node {
+            let with_left = prefix.combine(&summary(&current.left));
+
+            if predicate(&with_left) {
+                node = current.left.as_deref();
+                continue;
+            }
+
+            let with_current = with_left.combine(&M::measure(&current.value));
+            if predicate(&with_current) {
+                return Some(&current.value);
+            }
+
+            prefix = with_current;
+            node = current.right.as_deref();
+        }
+
+        None
+    }
+}
+
+#[inline]
+fn height<T, M>(link: &Link<T, M>) -> usize {
+    link.as_deref().map_or(0, |node| node.height)
+}
+
+#[inline]
+fn summary<T, M: Measure<T>>(link: &Link<T, M>) -> M {
+    link.as_deref()
+        .map_or_else(M::identity, |node| node.summary.clone())
+}
+
+/// Returns `true` if `value` lies after the `start` bound.
+fn after_start<T: Ord>(value: &T, start: Bound<&T>) -> bool {
+    match start {
+        Bound::Included(start) => value >= start,
+        Bound::Excluded(start) => value > start,
+        Bound::Unbounded => true,
+    }
+}
+
+/// Returns `true` if `value` lies before the `end` bound.
+fn before_end<T: Ord>(value: &T, end: Bound<&T>) -> bool {
+    match end {
+        Bound::Included(end) => value <= end,
+        Bound::Excluded(end) => value < end,
+        Bound::Unbounded => true,
+    }
+}
+
+/// Combines the summaries of the elements in the subtree that lie between `start` and `end`.
+///
+/// Once the search splits around an element within the range, each side only has one bound left,
+/// and every subtree on the inner side of that bound lies entirely within the range.
+fn aggregate<T: Ord, M: Measure<T>>(link: &Link<T, M>, start: Bound<&T>, end: Bound<&T>) -> M {
+    let Some(node) = link.as_deref() else {
+        return M::identity();
+    };
+
+    if let (Bound::Unbounded, Bound::Unbounded) = (start, end) {
+        return node.summary.clone();
+    }
+
+    if !after_start(&node.value, start) {
+        aggregate(&node.right, start, end)
+    } else if !before_end(&node.value, end) {
+        aggregate(&node.left, start, end)
+    } else {
+        aggregate(&node.left, start, Bound::Unbounded)
+            .combine(&M::measure(&node.value))
+            .combine(&aggregate(&node.right, Bound::Unbounded, end))
+    }
+}
+
+/// Inserts the value into the subtree in `link`, and returns `true` if it was not already present.
+fn insert<T: Ord, M: Measure<T>>(link: &mut Link<T, M>, value: T) -> bool {
+    let Some(node) = link.as_deref_mut() else {
+        *link = Some(Box::new(Node::new(value)));
+        return true;
+    };
+
+    let inserted = match value.cmp(&node.value) {
+        Ordering::Equal => return false,
+        Ordering::Less => insert(&mut node.left, value),
+        Ordering::Greater => insert(&mut node.right, value),
+    };
+    avl::rebalance(link);
+
+    inserted
+}
+
+fn remove<T: Ord, M: Measure<T>>(link: &mut Link<T, M>, target: &T) -> Option<T> {
+    let node = link.as_deref_mut()?;
+
+    let removed = match target.cmp(&node.value) {
+        Ordering::Less => remove(&mut node.left, target),
+        Ordering::Greater => remove(&mut node.right, target),
+        Ordering::Equal => {
+            let mut node = link.take()?;
+            *link = match (node.left.take(), node.right.take()) {
+                (None, None) => None,
+                (Some(child), None) | (None, Some(child)) => Some(child),
+                (Some(left), Some(right)) => {
+                    let mut right = Some(right);
+                    let mut successor = avl::pop_min(&mut right)?;
+                    successor.left = Some(left);
+                    successor.right = right;
+
+                    Some(successor)
+                }
+            };
+
+            Some(node.value)
+        }
+    };
+    avl::rebalance(link);
+
+    removed
+}
+
+impl<T: Ord, M: Measure<T>> From<Vec<T>> for AugmentedTree<T, M> {
+    /// Creates an `AugmentedTree<T, M>` from `Vec<T>`.
+    fn from(vec: Vec<T>) -> Self {
+        let mut tree = AugmentedTree::new();
+        for v in vec {
+            tree.insert(v);
+        }
+
+        tree
+    }
+}
+
+impl<T: Ord, M: Measure<T>> FromIterator<T> for AugmentedTree<T, M> {
+    /// Constructs an `AugmentedTree<T, M>` from an iterator for `T`.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut tree = AugmentedTree::new();
+
+        for v in iter {
+            tree.insert(v);
+        }
+
+        tree
+    }
+}
+
+impl<T: Ord, M: Measure<T>> Extend<T> for AugmentedTree<T, M> {
+    /// Extends the `AugmentedTree` with the contents of the provided iterator.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for v in iter {
+            self.insert(v);
+        }
+    }
+}
+
+impl<T, M> IntoIterator for AugmentedTree<T, M> {
+    type Item = T;
+
+    type IntoIter = IntoIter<T, M>;
+
+    /// Returns a consuming iterator over the `AugmentedTree`.
+    ///
+    /// The iterator yields all items in ascending order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::augmented_tree::AugmentedTree;
+    /// # use ds_rs::augmented_tree::monoid::Count;
+    /// let tree: AugmentedTree<i32, Count> = AugmentedTree::from(vec![5, 4, 6]);
+    /// let mut tree_iter = tree.into_iter();
+    ///
+    /// assert_eq!(tree_iter.next(), Some(4));
+    /// assert_eq!(tree_iter.next(), Some(5));
+    /// assert_eq!(tree_iter.next(), Some(6));
+    ///
+    /// // the iterator is now empty
+    /// assert_eq!(tree_iter.next(), None);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        let mut iter = IntoIter {
+            stack: Vec::new(),
+            remaining: self.count,
+        };
+        iter.push_left(self.root);
+
+        iter
+    }
+}
+
+/// An iterator that moves out of the `AugmentedTree`.
+///
+/// This `struct` is created by the `into_iter` method on [`AugmentedTree`] (provided by the [`IntoIterator`] trait).
+pub struct IntoIter<T, M> {
+    stack: Vec<(T, Link<T, M>)>,
+    remaining: usize,
+}
+
+impl<T, M> IntoIter<T, M> {
+    /// Pushes the left spine of the subtree, keeping each right subtree for later.
+    fn push_left(&mut self, mut link: Link<T, M>) {
+        while let Some(node) = link {
+            let Node {
+                value, left, right, ..
+            } = *node;
+            self.stack.push((value, right));
+            link = left;
+        }
+    }
+}
+
+impl<T, M> Iterator for IntoIter<T, M> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (value, right) = self.stack.pop()?;
+        self.push_left(right);
+        self.remaining -= 1;
+
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T, M> ExactSizeIterator for IntoIter<T, M> {}
+
+impl<'a, T, M> IntoIterator for &'a AugmentedTree<T, M> {
+    type Item = &'a T;
+
+    type IntoIter = Iter<'a, T, M>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let mut iter = Iter {
+            stack: Vec::new(),
+            remaining: self.count,
+        };
+        iter.push_left(self.root.as_deref());
+
+        iter
+    }
+}
+
+/// An iterator that borrows from the `AugmentedTree`.
+///
+/// This `struct` is created by the `iter` method on [`AugmentedTree`].
+pub struct Iter<'a, T, M> {
+    stack: Vec<&'a Node<T, M>>,
+    remaining: usize,
+}
+
+impl<'a, T, M> Iter<'a, T, M> {
+    /// Pushes the left spine of the subtree.
+    fn push_left(&mut self, mut node: Option<&'a Node<T, M>>) {
+        while let Some(current) = node {
+            self.stack.push(current);
+            node = current.left.as_deref();
+        }
+    }
+}
+
+impl<'a, T, M> Iterator for Iter<'a, T, M> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left(node.right.as_deref());
+        self.remaining -= 1;
+
+        Some(&node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T, M> ExactSizeIterator for Iter<'a, T, M> {}
+
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Node<T, M> {
+    value: T,
+    summary: M,
+    height: usize,
+    left: Link<T, M>,
+    right: Link<T, M>,
+}
+
+impl<T, M: Measure<T>> Node<T, M> {
+    /// Constructs a new `Node<T, M>` without children.
+    fn new(value: T) -> Self {
+        Self {
+            summary: M::measure(&value),
+            value,
+            height: 1,
+            left: None,
+            right: None,
+        }
+    }
+}
+
+impl<T, M: Measure<T>> AvlNode for Node<T, M> {
+    fn left(&self) -> &Link<T, M> {
+        &self.left
+    }
+
+    fn right(&self) -> &Link<T, M> {
+        &self.right
+    }
+
+    fn left_mut(&mut self) -> &mut Link<T, M> {
+        &mut self.left
+    }
+
+    fn right_mut(&mut self) -> &mut Link<T, M> {
+        &mut self.right
+    }
+
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Recomputes the height and summary from the node and its children.
+    fn update(&mut self) {
+        self.height = height(&self.left).max(height(&self.right)) + 1;
+        self.summary = summary(&self.left)
+            .combine(&M::measure(&self.value))
+            .combine(&summary(&self.right));
+    }
+}
+
+#[cfg(test)]
+impl<T, M> AugmentedTree<T, M>
+where
+    T: Ord,
+    M: Measure<T> + PartialEq + std::fmt::Debug,
+{
+    /// Panics if any node breaks the ordering, balance, height or summary invariants of the tree.
+    fn assert_invariants(&self) {
+        fn check<T: Ord, M>(link: &Link<T, M>) -> usize
+        where
+            M: Measure<T> + PartialEq + std::fmt::Debug,
+        {
+            let Some(node) = link.as_deref() else {
+                return 0;
+            };
+
+            assert!(node
+                .left
+                .as_deref()
+                .into_iter()
+                .all(|left| left.value < node.value));
+            assert!(node
+                .right
+                .as_deref()
+                .into_iter()
+                .all(|right| right.value > node.value));
+
+            let (left, right) = (check(&node.left), check(&node.right));
+            assert!(left.abs_diff(right) <= 1);
+            assert_eq!(node.height, left.max(right) + 1);
+
+            let expected = summary(&node.left)
+                .combine(&M::measure(&node.value))
+                .combine(&summary(&node.right));
+            assert_eq!(node.summary, expected);
+
+            node.height
+        }
+
+        assert_eq!(self.iter().count(), self.count);
+        check(&self.root);
+    }
+}
+
+#[cfg(test)]
+mod insert_remove {
+    use super::monoid::Sum;
+    use super::{AugmentedTree, Node};
+
+    #[test]
+    fn insert_one_element_that_becomes_root() {
+        let mut tree: AugmentedTree<i32, Sum<i32>> = AugmentedTree::new();
+        let expected = AugmentedTree {
+            root: Some(Box::new(Node {
+                value: 5,
+                summary: Sum(5),
+                height: 1,
+                left: None,
+                right: None,
+            })),
+            count: 1,
+        };
+
+        tree.insert(5);
+        assert_eq!(tree, expected);
+    }
+
+    #[test]
+    fn ascending_inserts_rotate_and_resummarise() {
+        let mut tree: AugmentedTree<i32, Sum<i32>> = AugmentedTree::new();
+        let expected = AugmentedTree {
+            root: Some(Box::new(Node {
+                value: 2,
+                summary: Sum(6),
+                height: 2,
+                left: Some(Box::new(Node {
+                    value: 1,
+                    summary: Sum(1),
+                    height: 1,
+                    left: None,
+                    right: None,
+                })),
+                right: Some(Box::new(Node {
+                    value: 3,
+                    summary: Sum(3),
+                    height: 1,
+                    left: None,
+                    right: None,
+                })),
+            })),
+            count: 3,
+        };
+
+        tree.extend([1, 2, 3, 2]);
+        assert_eq!(tree, expected);
+    }
+
+    #[test]
+    fn sorted_inserts_and_removes_keep_invariants() {
+        let mut tree: AugmentedTree<i32, Sum<i32>> = AugmentedTree::new();
+
+        for v in 0..300 {
+            tree.insert(v);
+            tree.assert_invariants();
+        }
+        assert!(tree.height() <= 10);
+
+        for v in (0..300).map(|v| (v * 7) % 300) {
+            assert_eq!(tree.remove(&v), Some(v));
+            assert_eq!(tree.remove(&v), None);
+            tree.assert_invariants();
+        }
+        assert!(tree.is_empty());
+        assert_eq!(tree.summary(), Sum(0));
+    }
+}
+
+#[cfg(test)]
+mod aggregate {
+    use super::monoid::{Count, Max, Min, Monoid, Sum};
+    use super::AugmentedTree;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use std::ops::Bound;
+
+    /// Concatenates the elements it describes, which checks that summaries keep their order.
+    #[derive(Debug, Clone, PartialEq)]
+    struct Concat(Vec<i32>);
+
+    impl Monoid for Concat {
+        fn identity() -> Self {
+            Concat(Vec::new())
+        }
+
+        fn combine(&self, other: &Self) -> Self {
+            Concat(self.0.iter().chain(&other.0).copied().collect())
+        }
+    }
+
+    impl super::Measure<i32> for Concat {
+        fn measure(value: &i32) -> Self {
+            Concat(vec![*value])
+        }
+    }
+
+    fn bounds(rng: &mut StdRng) -> (Bound<i32>, Bound<i32>) {
+        let mut bound = || match rng.gen_range(0..3) {
+            0 => Bound::Included(rng.gen_range(-10..510)),
+            1 => Bound::Excluded(rng.gen_range(-10..510)),
+            _ => Bound::Unbounded,
+        };
+
+        (bound(), bound())
+    }
+
+    #[test]
+    fn ranges_match_brute_force() {
+        let mut rng = StdRng::seed_from_u64(32);
+        let values: Vec<i32> = (0..300).map(|_| rng.gen_range(0..500)).collect();
+
+        let sums: AugmentedTree<i32, Sum<i32>> = values.iter().copied().collect();
+        let counts: AugmentedTree<i32, Count> = values.iter().copied().collect();
+        let mins: AugmentedTree<i32, Min<i32>> = values.iter().copied().collect();
+        let maxes: AugmentedTree<i32, Max<i32>> = values.iter().copied().collect();
+        let concat: AugmentedTree<i32, Concat> = values.iter().copied().collect();
+        sums.assert_invariants();
+        concat.assert_invariants();
+
+        for _ in 0..500 {
+            let range = bounds(&mut rng);
+            let expected: Vec<i32> = sums
+                .iter()
+                .copied()
+                .filter(|v| std::ops::RangeBounds::contains(&range, v))
+                .collect();
+
+            assert_eq!(sums.aggregate(range), Sum(expected.iter().sum()));
+            assert_eq!(counts.aggregate(range), Count(expected.len()));
+            assert_eq!(mins.aggregate(range), Min(expected.first().copied()));
+            assert_eq!(maxes.aggregate(range), Max(expected.last().copied()));
+            assert_eq!(concat.aggregate(range), Concat(expected));
+        }
+    }
+
+    #[test]
+    fn empty_tree_aggregates_to_identity() {
+        let tree: AugmentedTree<i32, Sum<i32>> = AugmentedTree::new();
+
+        assert_eq!(tree.aggregate(..), Sum(0));
+        assert_eq!(tree.summary(), Sum(0));
+    }
+
+    #[test]
+    fn find_prefix_selects_by_position() {
+        let tree: AugmentedTree<i32, Count> = (0..100).rev().map(|v| v * 3).collect();
+
+        for index in 0..100 {
+            let found = tree.find_prefix(|Count(count)| *count > index);
+            assert_eq!(found, Some(&(index as i32 * 3)));
+        }
+        assert_eq!(tree.find_prefix(|Count(count)| *count > 100), None);
+    }
+
+    #[test]
+    fn find_prefix_by_running_sum() {
+        let tree: AugmentedTree<i32, Sum<i32>> = AugmentedTree::from(vec![4, 1, 3, 2]);
+
+        assert_eq!(tree.find_prefix(|Sum(sum)| *sum >= 1), Some(&1));
+        assert_eq!(tree.find_prefix(|Sum(sum)| *sum >= 4), Some(&3));
+        assert_eq!(tree.find_prefix(|Sum(sum)| *sum >= 10), Some(&4));
+        assert_eq!(tree.find_prefix(|Sum(sum)| *sum > 10), None);
+    }
+}
+
+#[cfg(test)]
+mod iterator_trait_impls {
+    use super::monoid::Count;
+    use super::AugmentedTree;
+
+    #[test]
+    fn iter_yields_ascending_order() {
+        let tree: AugmentedTree<i32, Count> = [5, 3, 8, 1, 4].into_iter().collect();
+        let mut iter = tree.iter();
+
+        assert_eq!(iter.len(), 5);
+        assert!(iter.by_ref().copied().take(2).eq([1, 3]));
+        assert_eq!(iter.len(), 3);
+        assert!(iter.copied().eq([4, 5, 8]));
+    }
+
+    #[test]
+    fn into_iter_yields_ascending_order() {
+        let tree: AugmentedTree<i32, Count> = (0..50).rev().collect();
+
+        assert!(tree.into_iter().eq(0..50));
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod json {
+    use super::monoid::{Count, Sum};
+    use super::AugmentedTree;
+
+    #[test]
+    fn round_trips_through_json() {
+        let tree: AugmentedTree<i32, (Count, Sum<i32>)> = (0..20).collect();
+
+        let json = serde_json::to_string(&tree).expect("should parse tree into json");
+        let actual: AugmentedTree<i32, (Count, Sum<i32>)> =
+            serde_json::from_str(&json).expect("should parse json into tree");
+
+        assert_eq!(actual, tree);
+    }
+}
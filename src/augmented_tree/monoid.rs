@@ -0,0 +1,189 @@
+use std::ops::Add;
+
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
+
+/// A summary that can be combined with other summaries of its kind.
+///
+/// The combination must be associative, and [`Monoid::identity`] must leave any summary unchanged
+/// when combined with it from either side. The combination does not need to be commutative,
+/// summaries are always combined in the order of the elements they describe.
+pub trait Monoid: Clone {
+    /// Returns the summary of no elements.
+    fn identity() -> Self;
+
+    /// Returns the summary of the elements described by `self`, followed by those described by `other`.
+    #[must_use]
+    fn combine(&self, other: &Self) -> Self;
+}
+
+/// A summary that can describe a single element of type `T`.
+pub trait Measure<T>: Monoid {
+    /// Returns the summary of the single element `value`.
+    fn measure(value: &T) -> Self;
+}
+
+/// Counts the elements it describes.
+///
+/// # Examples
+/// ```
+/// # use ds_rs::augmented_tree::monoid::{Count, Measure, Monoid};
+/// let count = Count::measure(&"a").combine(&Count::measure(&"b"));
+/// assert_eq!(count, Count(2));
+/// ```
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Count(pub usize);
+
+impl Monoid for Count {
+    #[inline]
+    fn identity() -> Self {
+        Count(0)
+    }
+
+    #[inline]
+    fn combine(&self, other: &Self) -> Self {
+        Count(self.0 + other.0)
+    }
+}
+
+impl<T> Measure<T> for Count {
+    #[inline]
+    fn measure(_: &T) -> Self {
+        Count(1)
+    }
+}
+
+/// Adds up the elements it describes.
+///
+/// The default value of `T` is used as zero.
+///
+/// # Examples
+/// ```
+/// # use ds_rs::augmented_tree::monoid::{Measure, Monoid, Sum};
+/// let sum = Sum::measure(&3).combine(&Sum::measure(&4));
+/// assert_eq!(sum, Sum(7));
+/// ```
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Sum<T>(pub T);
+
+impl<T> Monoid for Sum<T>
+where
+    T: Default + Clone + Add<Output = T>,
+{
+    #[inline]
+    fn identity() -> Self {
+        Sum(T::default())
+    }
+
+    #[inline]
+    fn combine(&self, other: &Self) -> Self {
+        Sum(self.0.clone() + other.0.clone())
+    }
+}
+
+impl<T> Measure<T> for Sum<T>
+where
+    T: Default + Clone + Add<Output = T>,
+{
+    #[inline]
+    fn measure(value: &T) -> Self {
+        Sum(value.clone())
+    }
+}
+
+/// Keeps the smallest element it describes, or `None` if it describes no elements.
+///
+/// # Examples
+/// ```
+/// # use ds_rs::augmented_tree::monoid::{Measure, Min, Monoid};
+/// let min = Min::measure(&3).combine(&Min::measure(&4));
+/// assert_eq!(min, Min(Some(3)));
+/// ```
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Min<T>(pub Option<T>);
+
+impl<T: Ord + Clone> Monoid for Min<T> {
+    #[inline]
+    fn identity() -> Self {
+        Min(None)
+    }
+
+    fn combine(&self, other: &Self) -> Self {
+        match (&self.0, &other.0) {
+            (Some(a), Some(b)) => Min(Some(a.min(b).clone())),
+            (Some(value), None) | (None, Some(value)) => Min(Some(value.clone())),
+            (None, None) => Min(None),
+        }
+    }
+}
+
+impl<T: Ord + Clone> Measure<T> for Min<T> {
+    #[inline]
+    fn measure(value: &T) -> Self {
+        Min(Some(value.clone()))
+    }
+}
+
+/// Keeps the largest element it describes, or `None` if it describes no elements.
+///
+/// # Examples
+/// ```
+/// # use ds_rs::augmented_tree::monoid::{Max, Measure, Monoid};
+/// let max = Max::measure(&3).combine(&Max::measure(&4));
+/// assert_eq!(max, Max(Some(4)));
+/// ```
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Max<T>(pub Option<T>);
+
+impl<T: Ord + Clone> Monoid for Max<T> {
+    #[inline]
+    fn identity() -> Self {
+        Max(None)
+    }
+
+    fn combine(&self, other: &Self) -> Self {
+        match (&self.0, &other.0) {
+            (Some(a), Some(b)) => Max(Some(a.max(b).clone())),
+            (Some(value), None) | (None, Some(value)) => Max(Some(value.clone())),
+            (None, None) => Max(None),
+        }
+    }
+}
+
+impl<T: Ord + Clone> Measure<T> for Max<T> {
+    #[inline]
+    fn measure(value: &T) -> Self {
+        Max(Some(value.clone()))
+    }
+}
+
+/// Combines two summaries of the same elements, such as a [`Count`] and a [`Sum`] to compute averages.
+///
+/// # Examples
+/// ```
+/// # use ds_rs::augmented_tree::monoid::{Count, Measure, Monoid, Sum};
+/// let both = <(Count, Sum<i32>)>::measure(&3).combine(&<(Count, Sum<i32>)>::measure(&4));
+/// assert_eq!(both, (Count(2), Sum(7)));
+/// ```
+impl<A: Monoid, B: Monoid> Monoid for (A, B) {
+    #[inline]
+    fn identity() -> Self {
+        (A::identity(), B::identity())
+    }
+
+    #[inline]
+    fn combine(&self, other: &Self) -> Self {
+        (self.0.combine(&other.0), self.1.combine(&other.1))
+    }
+}
+
+impl<T, A: Measure<T>, B: Measure<T>> Measure<T> for (A, B) {
+    #[inline]
+    fn measure(value: &T) -> Self {
+        (A::measure(value), B::measure(value))
+    }
+}
@@ -0,0 +1,115 @@
+//! The rotations shared by the height balanced trees, which cache data derived from their subtrees in every node.
+
+use alloc::boxed::Box;
+
+/// A link to a node of a height balanced tree, which is `None` where the tree ends.
+pub(crate) type Link<N> = Option<Box<N>>;
+
+/// A node of a height balanced tree, which caches its height and any other data derived from its subtree.
+pub(crate) trait AvlNode: Sized {
+    /// Returns the link to the left child of the node.
+    fn left(&self) -> &Link<Self>;
+
+    /// Returns the link to the right child of the node.
+    fn right(&self) -> &Link<Self>;
+
+    /// Returns a mutable link to the left child of the node.
+    fn left_mut(&mut self) -> &mut Link<Self>;
+
+    /// Returns a mutable link to the right child of the node.
+    fn right_mut(&mut self) -> &mut Link<Self>;
+
+    /// Returns the cached height of the subtree rooted at the node.
+    fn height(&self) -> usize;
+
+    /// Recomputes the height and the other cached data of the node from the node and its children.
+    ///
+    /// This is called bottom up whenever the children of the node change, so the children are up to date.
+    fn update(&mut self);
+}
+
+/// Returns the height of the subtree in `link`, where an empty subtree has a height of `0`.
+#[inline]
+pub(crate) fn height<N: AvlNode>(link: &Link<N>) -> usize {
+    link.as_deref().map_or(0, N::height)
+}
+
+/// Detaches the leftmost node from the subtree in `link`, rebalancing the nodes above it, and returns it.
+pub(crate) fn pop_min<N: AvlNode>(link: &mut Link<N>) -> Option<Box<N>> {
+    if link.as_deref()?.left().is_some() {
+        let min = pop_min(link.as_deref_mut()?.left_mut());
+        rebalance(link);
+
+        min
+    } else {
+        let mut node = link.take()?;
+        *link = node.right_mut().take();
+
+        Some(node)
+    }
+}
+
+/// Updates the node in `link`, and rotates it if its children differ in height by more than one.
+pub(crate) fn rebalance<N: AvlNode>(link: &mut Link<N>) {
+    let Some(node) = link.as_deref_mut() else {
+        return;
+    };
+    node.update();
+
+    let (left, right) = (height(node.left()), height(node.right()));
+    if left > right + 1 {
+        if node
+            .left()
+            .as_deref()
+            .is_some_and(|left| height(left.left()) < height(left.right()))
+        {
+            rotate_left(node.left_mut());
+        }
+        rotate_right(link);
+    } else if right > left + 1 {
+        if node
+            .right()
+            .as_deref()
+            .is_some_and(|right| height(right.right()) < height(right.left()))
+        {
+            rotate_right(node.right_mut());
+        }
+        rotate_left(link);
+    }
+}
+
+/// Makes the right child of the node in `link` its parent.
+fn rotate_left<N: AvlNode>(link: &mut Link<N>) {
+    let Some(mut node) = link.take() else {
+        return;
+    };
+    let Some(mut right) = node.right_mut().take() else {
+        *link = Some(node);
+        return;
+    };
+
+    *node.right_mut() = right.left_mut().take();
+    node.update();
+    *right.left_mut() = Some(node);
+    right.update();
+
+    *link = Some(right);
+}
+
+/// Makes the left child of the node in `link` its parent.
+fn rotate_right<N: AvlNode>(link: &mut Link<N>) {
+    let Some(mut node) = link.take() else {
+        return;
+    };
+    let Some(mut left) = node.left_mut().take() else {
+        *link = Some(node);
+        return;
+    };
+
+    *node.left_mut() = left.right_mut().take();
+    node.update();
+    *left.right_mut() = Some(node);
+    left.update();
+
+    *link = Some(left);
+}
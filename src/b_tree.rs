@@ -0,0 +1,465 @@
+use std::ops::RangeBounds;
+
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
+
+pub mod map;
+
+use crate::sorted_set::SortedSet;
+use map::BTreeMap;
+
+/// An ordered set that stores many elements per node.
+///
+/// The set is a [`BTreeMap`] without values, see it for details on the structure of the tree.
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BTree<T, const B: usize = 6> {
+    map: BTreeMap<T, (), B>,
+}
+
+impl<T, const B: usize> BTree<T, B> {
+    /// Constructs a new empty `BTree<T, B>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ds_rs::b_tree::BTree;
+    /// # #[allow(unused_mut)]
+    /// let mut tree: BTree<i32> = BTree::new();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            map: BTreeMap::new(),
+        }
+    }
+
+    /// Returns `true` if the b-tree contains no elements.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::b_tree::BTree;
+    /// let mut tree: BTree<i32> = BTree::new();
+    /// assert!(tree.is_empty());
+    ///
+    /// tree.insert(0);
+    /// assert!(!tree.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Clears the b-tree of all elements.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::b_tree::BTree;
+    /// let mut tree: BTree<i32> = BTree::new();
+    /// tree.insert(0);
+    ///
+    /// tree.clear();
+    /// assert!(tree.is_empty());
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.map.clear();
+    }
+
+    /// Returns the height of the b-tree.
+    ///
+    /// An empty tree has a height of `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::b_tree::BTree;
+    /// let tree: BTree<i32, 2> = (0..7).collect();
+    /// assert_eq!(tree.height(), 2);
+    /// ```
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.map.height()
+    }
+
+    /// Returns the number of elements in the b-tree.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::b_tree::BTree;
+    /// let mut tree: BTree<i32> = BTree::new();
+    /// tree.insert(5);
+    ///
+    /// // duplicates do not enter the tree
+    /// // and therefore do not affect the count
+    /// tree.insert(5);
+    /// assert_eq!(tree.count(), 1);
+    /// ```
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.map.count()
+    }
+
+    /// Returns the smallest element in the `BTree`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::b_tree::BTree;
+    /// let tree: BTree<i32> = BTree::from(vec![8, 4, 6, 16, -5, 25]);
+    /// assert_eq!(tree.min(), Some(&-5));
+    /// ```
+    pub fn min(&self) -> Option<&T> {
+        self.map.min().map(|(value, _)| value)
+    }
+
+    /// Returns the largest element in the `BTree`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::b_tree::BTree;
+    /// let tree: BTree<i32> = BTree::from(vec![8, 4, 6, 16, -5, 25]);
+    /// assert_eq!(tree.max(), Some(&25));
+    /// ```
+    pub fn max(&self) -> Option<&T> {
+        self.map.max().map(|(value, _)| value)
+    }
+
+    /// Returns a non-consuming iterator over the `BTree`.
+    ///
+    /// The iterator yields all items in ascending order, and can be consumed from both ends.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::b_tree::BTree;
+    /// let tree: BTree<i32> = BTree::from(vec![5, 4, 6]);
+    /// let mut tree_iter = tree.iter();
+    ///
+    /// assert_eq!(tree_iter.next(), Some(&4));
+    /// assert_eq!(tree_iter.next_back(), Some(&6));
+    /// assert_eq!(tree_iter.next(), Some(&5));
+    ///
+    /// // the iterator is now empty
+    /// assert_eq!(tree_iter.next(), None);
+    /// ```
+    #[inline]
+    #[must_use = "iterators are evaluated lazily"]
+    pub fn iter(&self) -> Iter<'_, T, B> {
+        Iter {
+            iter: self.map.iter(),
+        }
+    }
+}
+
+impl<T, const B: usize> Default for BTree<T, B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The methods in this implementation block require trait bounds to correctly apply the logic of the methods.
+///
+/// The trait bound in question is [`Ord`], which is used to compare elements in the tree.
+impl<T, const B: usize> BTree<T, B>
+where
+    T: Ord,
+{
+    /// Inserts the provided value into the `BTree`,
+    /// and preserves the properties of the b-tree.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::b_tree::BTree;
+    /// let mut tree: BTree<i32> = BTree::new();
+    /// tree.insert(5);
+    /// assert_eq!(tree.min(), Some(&5));
+    /// assert_eq!(tree.count(), 1);
+    /// ```
+    pub fn insert(&mut self, value: T) {
+        // an existing element keeps its place, and only the unit value is replaced
+        self.map.insert(value, ());
+    }
+
+    /// Returns `true` if the `BTree` contains an element with the given value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::b_tree::BTree;
+    /// let tree: BTree<i32> = BTree::from(vec![8, 4, 6, 16, -5, 25]);
+    /// assert!(tree.contains(&8));
+    /// assert!(!tree.contains(&5));
+    /// ```
+    pub fn contains(&self, target: &T) -> bool {
+        self.map.contains_key(target)
+    }
+
+    /// Removes the element equal to the given value from the `BTree`, and returns it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::b_tree::BTree;
+    /// let mut tree: BTree<i32> = BTree::from(vec![8, 4, 6]);
+    /// assert_eq!(tree.remove(&4), Some(4));
+    /// assert_eq!(tree.remove(&4), None);
+    /// assert_eq!(tree.count(), 2);
+    /// ```
+    pub fn remove(&mut self, target: &T) -> Option<T> {
+        self.map.remove_entry(target).map(|(value, _)| value)
+    }
+
+    /// Returns a non-consuming iterator over the elements of the `BTree` within the range.
+    ///
+    /// The iterator yields the elements in ascending order, and can be consumed from both ends.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::b_tree::BTree;
+    /// let tree: BTree<i32> = (0..10).collect();
+    /// let mut range = tree.range(3..6);
+    ///
+    /// assert_eq!(range.next(), Some(&3));
+    /// assert_eq!(range.next_back(), Some(&5));
+    /// assert_eq!(range.next(), Some(&4));
+    /// assert_eq!(range.next(), None);
+    /// ```
+    #[must_use = "iterators are evaluated lazily"]
+    pub fn range<R>(&self, range: R) -> Range<'_, T, B>
+    where
+        R: RangeBounds<T>,
+    {
+        Range {
+            range: self.map.range(range),
+        }
+    }
+}
+
+impl<T: Ord, const B: usize> SortedSet<T> for BTree<T, B> {
+    type Iter<'a>
+        = Iter<'a, T, B>
+    where
+        Self: 'a,
+        T: 'a;
+
+    fn insert(&mut self, value: T) -> bool {
+        let count = self.map.count();
+        BTree::insert(self, value);
+
+        self.map.count() > count
+    }
+
+    fn remove(&mut self, target: &T) -> Option<T> {
+        BTree::remove(self, target)
+    }
+
+    fn contains(&self, target: &T) -> bool {
+        BTree::contains(self, target)
+    }
+
+    fn min(&self) -> Option<&T> {
+        BTree::min(self)
+    }
+
+    fn max(&self) -> Option<&T> {
+        BTree::max(self)
+    }
+
+    fn len(&self) -> usize {
+        self.map.count()
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        BTree::iter(self)
+    }
+}
+
+impl<T: Ord, const B: usize> From<Vec<T>> for BTree<T, B> {
+    /// Creates a `BTree<T, B>` from `Vec<T>`.
+    fn from(vec: Vec<T>) -> Self {
+        vec.into_iter().collect()
+    }
+}
+
+impl<T: Ord, const B: usize> FromIterator<T> for BTree<T, B> {
+    /// Constructs a `BTree<T, B>` from an iterator for `T`.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut tree = BTree::new();
+        tree.extend(iter);
+
+        tree
+    }
+}
+
+impl<T: Ord, const B: usize> Extend<T> for BTree<T, B> {
+    /// Extends the `BTree` with the contents of the provided iterator.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for v in iter {
+            self.insert(v);
+        }
+    }
+}
+
+impl<T, const B: usize> IntoIterator for BTree<T, B> {
+    type Item = T;
+
+    type IntoIter = IntoIter<T>;
+
+    /// Returns a consuming iterator over the `BTree`.
+    ///
+    /// The iterator yields all items in ascending order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::b_tree::BTree;
+    /// let tree: BTree<i32> = BTree::from(vec![5, 4, 6]);
+    /// let mut tree_iter = tree.into_iter();
+    ///
+    /// assert_eq!(tree_iter.next(), Some(4));
+    /// assert_eq!(tree_iter.next(), Some(5));
+    /// assert_eq!(tree_iter.next(), Some(6));
+    ///
+    /// // the iterator is now empty
+    /// assert_eq!(tree_iter.next(), None);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            iter: self.map.into_iter(),
+        }
+    }
+}
+
+/// An iterator that moves out of the `BTree`.
+///
+/// This `struct` is created by the `into_iter` method on [`BTree`] (provided by the [`IntoIterator`] trait).
+pub struct IntoIter<T> {
+    iter: map::IntoIter<T, ()>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(value, _)| value)
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(value, _)| value)
+    }
+}
+
+impl<'a, T, const B: usize> IntoIterator for &'a BTree<T, B> {
+    type Item = &'a T;
+
+    type IntoIter = Iter<'a, T, B>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator that borrows from the `BTree`.
+///
+/// This `struct` is created by the `iter` method on [`BTree`].
+pub struct Iter<'a, T, const B: usize> {
+    iter: map::Iter<'a, T, (), B>,
+}
+
+impl<'a, T, const B: usize> Iterator for Iter<'a, T, B> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(value, _)| value)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, T, const B: usize> DoubleEndedIterator for Iter<'a, T, B> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(value, _)| value)
+    }
+}
+
+impl<'a, T, const B: usize> ExactSizeIterator for Iter<'a, T, B> {}
+
+/// An iterator that borrows the elements of a `BTree` within a range.
+///
+/// This `struct` is created by the `range` method on [`BTree`].
+pub struct Range<'a, T, const B: usize> {
+    range: map::Range<'a, T, (), B>,
+}
+
+impl<'a, T, const B: usize> Iterator for Range<'a, T, B> {
+    type Item = &'a T;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.range.next().map(|(value, _)| value)
+    }
+}
+
+impl<'a, T, const B: usize> DoubleEndedIterator for Range<'a, T, B> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.range.next_back().map(|(value, _)| value)
+    }
+}
+
+#[cfg(test)]
+mod set {
+    use super::BTree;
+
+    #[test]
+    fn discards_duplicates() {
+        let tree: BTree<i32, 2> = BTree::from(vec![3, 1, 3, 2, 1]);
+
+        assert_eq!(tree.count(), 3);
+        assert!(tree.iter().eq([1, 2, 3].iter()));
+    }
+
+    #[test]
+    fn contains_and_remove() {
+        let mut tree: BTree<i32, 2> = (0..100).collect();
+
+        assert!(tree.contains(&42));
+        assert_eq!(tree.remove(&42), Some(42));
+        assert!(!tree.contains(&42));
+        assert_eq!(tree.count(), 99);
+    }
+
+    #[test]
+    fn min_and_max() {
+        let tree: BTree<i32, 2> = (-50..50).rev().collect();
+        let empty: BTree<i32> = BTree::new();
+
+        assert_eq!(tree.min(), Some(&-50));
+        assert_eq!(tree.max(), Some(&49));
+        assert_eq!(empty.min(), None);
+        assert_eq!(empty.max(), None);
+    }
+
+    #[test]
+    fn equality_ignores_shape() {
+        let ascending: BTree<i32, 2> = (0..30).collect();
+        let descending: BTree<i32, 2> = (0..30).rev().collect();
+
+        assert_eq!(ascending, descending);
+    }
+
+    #[test]
+    fn iterates_from_both_ends() {
+        let tree: BTree<i32, 2> = (0..20).collect();
+
+        assert!(tree.iter().copied().eq(0..20));
+        assert!(tree.iter().rev().copied().eq((0..20).rev()));
+        assert!(tree.clone().into_iter().rev().eq((0..20).rev()));
+        assert!(tree.range(5..8).copied().eq(5..8));
+    }
+}
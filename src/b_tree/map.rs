@@ -0,0 +1,1342 @@
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use std::mem;
+use std::ops::{Bound, RangeBounds};
+
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
+
+/// An ordered map that stores many entries per node.
+///
+/// Every node other than the root holds between `B - 1` and `2 * B - 1` entries in sorted
+/// order, and every leaf sits at the same depth. Keeping the entries of a node next to each other
+/// means a lookup touches far fewer cache lines than in a tree with one element per node.
+///
+/// Two maps are equal when they hold equal entries, regardless of how their nodes are shaped.
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone)]
+pub struct BTreeMap<K, V, const B: usize = 6> {
+    root: Node<K, V, B>,
+    count: usize,
+}
+
+impl<K, V, const B: usize> BTreeMap<K, V, B> {
+    /// The largest number of entries a node holds.
+    const MAX_KEYS: usize = 2 * B - 1;
+
+    /// Rejects orders that cannot split a full node into two valid nodes.
+    const VALID_ORDER: () = assert!(B >= 2, "the order of a b-tree must be at least 2");
+
+    /// Constructs a new empty `BTreeMap<K, V, B>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ds_rs::b_tree::map::BTreeMap;
+    /// # #[allow(unused_mut)]
+    /// let mut map: BTreeMap<i32, &str> = BTreeMap::new();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_ORDER;
+
+        Self {
+            root: Node::new(),
+            count: 0,
+        }
+    }
+
+    /// Returns `true` if the map contains no entries.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::b_tree::map::BTreeMap;
+    /// let mut map: BTreeMap<i32, &str> = BTreeMap::new();
+    /// assert!(map.is_empty());
+    ///
+    /// map.insert(0, "zero");
+    /// assert!(!map.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Clears the map of all entries.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::b_tree::map::BTreeMap;
+    /// let mut map: BTreeMap<i32, &str> = BTreeMap::new();
+    /// map.insert(0, "zero");
+    ///
+    /// map.clear();
+    /// assert!(map.is_empty());
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.root = Node::new();
+        self.count = 0;
+    }
+
+    /// Returns the number of entries in the map.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::b_tree::map::BTreeMap;
+    /// let mut map: BTreeMap<i32, &str> = BTreeMap::new();
+    /// map.insert(0, "zero");
+    ///
+    /// // inserting an existing key replaces its value
+    /// map.insert(0, "nil");
+    /// assert_eq!(map.count(), 1);
+    /// ```
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the height of the map, which is the same along every path as all leaves sit at
+    /// the same depth.
+    ///
+    /// An empty map has a height of `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::b_tree::map::BTreeMap;
+    /// let mut map: BTreeMap<i32, (), 2> = BTreeMap::new();
+    /// assert_eq!(map.height(), 0);
+    ///
+    /// // a node of order 2 holds up to 3 entries
+    /// map.extend([(1, ()), (2, ()), (3, ())]);
+    /// assert_eq!(map.height(), 1);
+    ///
+    /// map.insert(4, ());
+    /// assert_eq!(map.height(), 2);
+    /// ```
+    pub fn height(&self) -> usize {
+        if self.is_empty() {
+            return 0;
+        }
+
+        let mut height = 1;
+        let mut node = &self.root;
+        while let Some(child) = node.children.first() {
+            node = child;
+            height += 1;
+        }
+
+        height
+    }
+
+    /// Returns the entry with the smallest key in the map.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::b_tree::map::BTreeMap;
+    /// let map: BTreeMap<i32, &str> = BTreeMap::from(vec![(2, "b"), (1, "a"), (3, "c")]);
+    /// assert_eq!(map.min(), Some((&1, &"a")));
+    /// ```
+    pub fn min(&self) -> Option<(&K, &V)> {
+        let mut node = &self.root;
+        while let Some(child) = node.children.first() {
+            node = child;
+        }
+
+        node.keys.first().zip(node.values.first())
+    }
+
+    /// Returns the entry with the largest key in the map.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::b_tree::map::BTreeMap;
+    /// let map: BTreeMap<i32, &str> = BTreeMap::from(vec![(2, "b"), (1, "a"), (3, "c")]);
+    /// assert_eq!(map.max(), Some((&3, &"c")));
+    /// ```
+    pub fn max(&self) -> Option<(&K, &V)> {
+        let mut node = &self.root;
+        while let Some(child) = node.children.last() {
+            node = child;
+        }
+
+        node.keys.last().zip(node.values.last())
+    }
+
+    /// Returns a non-consuming iterator over the entries of the map, sorted by key.
+    ///
+    /// The iterator can be consumed from both ends.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::b_tree::map::BTreeMap;
+    /// let map: BTreeMap<i32, &str> = BTreeMap::from(vec![(2, "b"), (1, "a"), (3, "c")]);
+    /// let mut iter = map.iter();
+    ///
+    /// assert_eq!(iter.next(), Some((&1, &"a")));
+    /// assert_eq!(iter.next_back(), Some((&3, &"c")));
+    /// assert_eq!(iter.next(), Some((&2, &"b")));
+    ///
+    /// // the iterator is now empty
+    /// assert_eq!(iter.next(), None);
+    /// assert_eq!(iter.next_back(), None);
+    /// ```
+    #[inline]
+    #[must_use = "iterators are evaluated lazily"]
+    pub fn iter(&self) -> Iter<'_, K, V, B> {
+        Iter {
+            range: Range {
+                front: Cursor::first(&self.root),
+                back: Cursor::last(&self.root),
+            },
+            remaining: self.count,
+        }
+    }
+}
+
+impl<K, V, const B: usize> Default for BTreeMap<K, V, B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: PartialEq, V: PartialEq, const B: usize> PartialEq for BTreeMap<K, V, B> {
+    fn eq(&self, other: &Self) -> bool {
+        self.count == other.count && self.iter().eq(other.iter())
+    }
+}
+
+impl<K: Eq, V: Eq, const B: usize> Eq for BTreeMap<K, V, B> {}
+
+impl<K: Hash, V: Hash, const B: usize> Hash for BTreeMap<K, V, B> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.count.hash(state);
+        for entry in self {
+            entry.hash(state);
+        }
+    }
+}
+
+/// The methods in this implementation block require trait bounds to correctly apply the logic of the methods.
+///
+/// The trait bound in question is [`Ord`], which is used to compare keys in the map.
+impl<K, V, const B: usize> BTreeMap<K, V, B>
+where
+    K: Ord,
+{
+    /// Inserts the key and value into the map, and returns the value previously stored under
+    /// the key.
+    ///
+    /// # Time Complexity
+    ///
+    /// Full nodes are split on the way down, so an insert visits `log(n)` nodes in base `B`,
+    /// and searches each of them with a binary search.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::b_tree::map::BTreeMap;
+    /// let mut map: BTreeMap<i32, &str> = BTreeMap::new();
+    /// assert_eq!(map.insert(1, "a"), None);
+    /// assert_eq!(map.insert(1, "b"), Some("a"));
+    /// assert_eq!(map.get(&1), Some(&"b"));
+    /// ```
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        if self.root.keys.len() == Self::MAX_KEYS {
+            let old_root = mem::replace(&mut self.root, Node::new());
+            self.root.children.push(old_root);
+            self.root.split_child(0);
+        }
+
+        let previous = self.root.insert(key, value);
+        if previous.is_none() {
+            self.count += 1;
+        }
+
+        previous
+    }
+
+    /// Returns a reference to the value stored under the key.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::b_tree::map::BTreeMap;
+    /// let map: BTreeMap<i32, &str> = BTreeMap::from(vec![(1, "a")]);
+    /// assert_eq!(map.get(&1), Some(&"a"));
+    /// assert_eq!(map.get(&2), None);
+    /// ```
+    pub fn get(&self, key: &K) -> Option<&V> {
+        let mut node = &self.root;
+
+        loop {
+            match node.keys.binary_search(key) {
+                Ok(i) => return node.values.get(i),
+                Err(i) => node = node.children.get(i)?,
+            }
+        }
+    }
+
+    /// Returns a mutable reference to the value stored under the key.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::b_tree::map::BTreeMap;
+    /// let mut map: BTreeMap<i32, i32> = BTreeMap::from(vec![(1, 10)]);
+    /// if let Some(value) = map.get_mut(&1) {
+    ///     *value += 1;
+    /// }
+    /// assert_eq!(map.get(&1), Some(&11));
+    /// ```
+    pub fn get_mut(&mut self, key: &K) -> Option<&mut V> {
+        let mut node = &mut self.root;
+
+        loop {
+            match node.keys.binary_search(key) {
+                Ok(i) => return node.values.get_mut(i),
+                Err(i) => node = node.children.get_mut(i)?,
+            }
+        }
+    }
+
+    /// Returns `true` if the map contains an entry with the key.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::b_tree::map::BTreeMap;
+    /// let map: BTreeMap<i32, &str> = BTreeMap::from(vec![(1, "a")]);
+    /// assert!(map.contains_key(&1));
+    /// assert!(!map.contains_key(&2));
+    /// ```
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Removes the entry with the key from the map, and returns its value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::b_tree::map::BTreeMap;
+    /// let mut map: BTreeMap<i32, &str> = BTreeMap::from(vec![(1, "a")]);
+    /// assert_eq!(map.remove(&1), Some("a"));
+    /// assert_eq!(map.remove(&1), None);
+    /// ```
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        self.remove_entry(key).map(|(_, value)| value)
+    }
+
+    /// Removes the entry with the key from the map, and returns the stored key and value.
+    ///
+    /// # Time Complexity
+    ///
+    /// Nodes that would fall below `B - 1` entries borrow from or merge with a sibling on the way
+    /// down, so a removal visits `log(n)` nodes in base `B`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::b_tree::map::BTreeMap;
+    /// let mut map: BTreeMap<i32, &str> = BTreeMap::from(vec![(1, "a")]);
+    /// assert_eq!(map.remove_entry(&1), Some((1, "a")));
+    /// ```
+    pub fn remove_entry(&mut self, key: &K) -> Option<(K, V)> {
+        let entry = self.root.remove(key);
+
+        if self.root.keys.is_empty() {
+            if let Some(child) = self.root.children.pop() {
+                self.root = child;
+            }
+        }
+
+        if entry.is_some() {
+            self.count -= 1;
+        }
+
+        entry
+    }
+
+    /// Returns a non-consuming iterator over the entries of the map whose keys are within the
+    /// range, sorted by key.
+    ///
+    /// The iterator can be consumed from both ends.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::b_tree::map::BTreeMap;
+    /// let map: BTreeMap<i32, i32> = (0..10).map(|v| (v, v * v)).collect();
+    /// let mut range = map.range(3..6);
+    ///
+    /// assert_eq!(range.next(), Some((&3, &9)));
+    /// assert_eq!(range.next_back(), Some((&5, &25)));
+    /// assert_eq!(range.next(), Some((&4, &16)));
+    /// assert_eq!(range.next(), None);
+    /// ```
+    #[must_use = "iterators are evaluated lazily"]
+    pub fn range<R>(&self, range: R) -> Range<'_, K, V, B>
+    where
+        R: RangeBounds<K>,
+    {
+        let mut range = Range {
+            front: Cursor::lower_bound(&self.root, range.start_bound()),
+            back: Cursor::upper_bound(&self.root, range.end_bound()),
+        };
+
+        let ordered = match (range.front.current(), range.back.current()) {
+            (Some((front, _)), Some((back, _))) => front <= back,
+            _ => false,
+        };
+        if !ordered {
+            range.clear();
+        }
+
+        range
+    }
+}
+
+impl<K: Ord, V, const B: usize> From<Vec<(K, V)>> for BTreeMap<K, V, B> {
+    /// Creates a `BTreeMap<K, V, B>` from `Vec<(K, V)>`.
+    fn from(vec: Vec<(K, V)>) -> Self {
+        vec.into_iter().collect()
+    }
+}
+
+impl<K: Ord, V, const B: usize> FromIterator<(K, V)> for BTreeMap<K, V, B> {
+    /// Constructs a `BTreeMap<K, V, B>` from an iterator of key and value pairs.
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = BTreeMap::new();
+        map.extend(iter);
+
+        map
+    }
+}
+
+impl<K: Ord, V, const B: usize> Extend<(K, V)> for BTreeMap<K, V, B> {
+    /// Extends the `BTreeMap` with the contents of the provided iterator.
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K, V, const B: usize> IntoIterator for BTreeMap<K, V, B> {
+    type Item = (K, V);
+
+    type IntoIter = IntoIter<K, V>;
+
+    /// Returns a consuming iterator over the entries of the map, sorted by key.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::b_tree::map::BTreeMap;
+    /// let map: BTreeMap<i32, &str> = BTreeMap::from(vec![(2, "b"), (1, "a")]);
+    /// let mut iter = map.into_iter();
+    ///
+    /// assert_eq!(iter.next(), Some((1, "a")));
+    /// assert_eq!(iter.next(), Some((2, "b")));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        let mut entries = Vec::with_capacity(self.count);
+        self.root.drain_into(&mut entries);
+
+        IntoIter {
+            vec: entries.into_iter(),
+        }
+    }
+}
+
+/// An iterator that moves out of the `BTreeMap`.
+///
+/// This `struct` is created by the `into_iter` method on [`BTreeMap`] (provided by the [`IntoIterator`] trait).
+pub struct IntoIter<K, V> {
+    vec: std::vec::IntoIter<(K, V)>,
+}
+
+impl<K, V> Iterator for IntoIter<K, V> {
+    type Item = (K, V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.vec.next()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for IntoIter<K, V> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.vec.next_back()
+    }
+}
+
+impl<'a, K, V, const B: usize> IntoIterator for &'a BTreeMap<K, V, B> {
+    type Item = (&'a K, &'a V);
+
+    type IntoIter = Iter<'a, K, V, B>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator that borrows the entries of a `BTreeMap`.
+///
+/// This `struct` is created by the `iter` method on [`BTreeMap`].
+pub struct Iter<'a, K, V, const B: usize> {
+    range: Range<'a, K, V, B>,
+    remaining: usize,
+}
+
+impl<'a, K, V, const B: usize> Iterator for Iter<'a, K, V, B> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry = self.range.next()?;
+        self.remaining -= 1;
+
+        Some(entry)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, K, V, const B: usize> DoubleEndedIterator for Iter<'a, K, V, B> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let entry = self.range.next_back()?;
+        self.remaining -= 1;
+
+        Some(entry)
+    }
+}
+
+impl<'a, K, V, const B: usize> ExactSizeIterator for Iter<'a, K, V, B> {}
+
+/// An iterator that borrows the entries of a `BTreeMap` within a range of keys.
+///
+/// This `struct` is created by the `range` method on [`BTreeMap`].
+pub struct Range<'a, K, V, const B: usize> {
+    front: Cursor<'a, K, V, B>,
+    back: Cursor<'a, K, V, B>,
+}
+
+impl<'a, K, V, const B: usize> Range<'a, K, V, B> {
+    /// Returns `true` if both ends of the range point to the same entry.
+    fn ends_meet(&self) -> bool {
+        match (self.front.stack.last(), self.back.stack.last()) {
+            (Some((front, i)), Some((back, j))) => std::ptr::eq(*front, *back) && i == j,
+            _ => true,
+        }
+    }
+
+    /// Exhausts the range from both ends.
+    fn clear(&mut self) {
+        self.front.stack.clear();
+        self.back.stack.clear();
+    }
+}
+
+impl<'a, K, V, const B: usize> Iterator for Range<'a, K, V, B> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let entry = self.front.current()?;
+
+        if self.ends_meet() {
+            self.clear();
+        } else {
+            self.front.move_next();
+        }
+
+        Some(entry)
+    }
+}
+
+impl<'a, K, V, const B: usize> DoubleEndedIterator for Range<'a, K, V, B> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let entry = self.back.current()?;
+
+        if self.ends_meet() {
+            self.clear();
+        } else {
+            self.back.move_prev();
+        }
+
+        Some(entry)
+    }
+}
+
+/// A position on an entry of the tree, along with the path that leads to it.
+///
+/// The top of the stack holds the current node and the index of the current entry within it.
+/// Every other element holds an ancestor, and the index of the entry that follows the
+/// subtree being walked (when moving forward) or that precedes it (when moving backward).
+/// Ancestors without such an entry are left off the stack.
+struct Cursor<'a, K, V, const B: usize> {
+    stack: Vec<(&'a Node<K, V, B>, usize)>,
+}
+
+impl<'a, K, V, const B: usize> Cursor<'a, K, V, B> {
+    /// Returns a cursor on the smallest entry of the tree.
+    fn first(root: &'a Node<K, V, B>) -> Self {
+        let mut cursor = Self { stack: Vec::new() };
+        cursor.descend_first(root);
+
+        cursor
+    }
+
+    /// Returns a cursor on the largest entry of the tree.
+    fn last(root: &'a Node<K, V, B>) -> Self {
+        let mut cursor = Self { stack: Vec::new() };
+        cursor.descend_last(root);
+
+        cursor
+    }
+
+    /// Returns the entry the cursor is on.
+    fn current(&self) -> Option<(&'a K, &'a V)> {
+        let (node, i) = self.stack.last()?;
+        node.keys.get(*i).zip(node.values.get(*i))
+    }
+
+    /// Moves the cursor to the next entry, or empties it if there is none.
+    fn move_next(&mut self) {
+        let Some((node, i)) = self.stack.pop() else {
+            return;
+        };
+
+        self.stack.push((node, i + 1));
+        match node.children.get(i + 1) {
+            Some(child) => self.descend_first(child),
+            None => self.pop_exhausted_forward(),
+        }
+    }
+
+    /// Moves the cursor to the previous entry, or empties it if there is none.
+    fn move_prev(&mut self) {
+        let Some((node, i)) = self.stack.pop() else {
+            return;
+        };
+
+        match node.children.get(i) {
+            Some(child) => {
+                if let Some(prev) = i.checked_sub(1) {
+                    self.stack.push((node, prev));
+                }
+                self.descend_last(child);
+            }
+            None => {
+                // ancestors are only kept while an entry precedes the subtree being walked,
+                // so once the first entry of a leaf is visited the next ancestor is current
+                if let Some(prev) = i.checked_sub(1) {
+                    self.stack.push((node, prev));
+                }
+            }
+        }
+    }
+
+    /// Walks down the leftmost path of `node`, ending on its smallest entry.
+    fn descend_first(&mut self, mut node: &'a Node<K, V, B>) {
+        loop {
+            self.stack.push((node, 0));
+            match node.children.first() {
+                Some(child) => node = child,
+                None => break,
+            }
+        }
+
+        self.pop_exhausted_forward();
+    }
+
+    /// Walks down the rightmost path of `node`, ending on its largest entry.
+    fn descend_last(&mut self, mut node: &'a Node<K, V, B>) {
+        loop {
+            match node.children.last() {
+                Some(child) => {
+                    if let Some(last) = node.keys.len().checked_sub(1) {
+                        self.stack.push((node, last));
+                    }
+                    node = child;
+                }
+                None => {
+                    if let Some(last) = node.keys.len().checked_sub(1) {
+                        self.stack.push((node, last));
+                    }
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Pops every node whose entries have all been visited when moving forward.
+    fn pop_exhausted_forward(&mut self) {
+        while let Some((node, i)) = self.stack.last() {
+            if *i < node.keys.len() {
+                break;
+            }
+            self.stack.pop();
+        }
+    }
+}
+
+impl<'a, K: Ord, V, const B: usize> Cursor<'a, K, V, B> {
+    /// Returns a cursor on the smallest entry whose key is within the lower bound.
+    fn lower_bound(root: &'a Node<K, V, B>, bound: Bound<&K>) -> Self {
+        let mut cursor = Self { stack: Vec::new() };
+        let mut node = root;
+
+        loop {
+            let i = match bound {
+                Bound::Included(key) => node.keys.partition_point(|k| k < key),
+                Bound::Excluded(key) => node.keys.partition_point(|k| k <= key),
+                Bound::Unbounded => 0,
+            };
+
+            cursor.stack.push((node, i));
+            match node.children.get(i) {
+                Some(child) => node = child,
+                None => break,
+            }
+        }
+
+        cursor.pop_exhausted_forward();
+        cursor
+    }
+
+    /// Returns a cursor on the largest entry whose key is within the upper bound.
+    fn upper_bound(root: &'a Node<K, V, B>, bound: Bound<&K>) -> Self {
+        let mut cursor = Self { stack: Vec::new() };
+        let mut node = root;
+
+        loop {
+            let i = match bound {
+                Bound::Included(key) => node.keys.partition_point(|k| k <= key),
+                Bound::Excluded(key) => node.keys.partition_point(|k| k < key),
+                Bound::Unbounded => node.keys.len(),
+            };
+
+            if let Some(prev) = i.checked_sub(1) {
+                cursor.stack.push((node, prev));
+            }
+            match node.children.get(i) {
+                Some(child) => node = child,
+                None => break,
+            }
+        }
+
+        cursor
+    }
+}
+
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Node<K, V, const B: usize> {
+    keys: Vec<K>,
+    values: Vec<V>,
+    /// Empty for leaves, and one longer than `keys` otherwise.
+    children: Vec<Node<K, V, B>>,
+}
+
+impl<K, V, const B: usize> Node<K, V, B> {
+    /// Constructs a new empty leaf.
+    fn new() -> Self {
+        Self {
+            keys: Vec::new(),
+            values: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    #[inline]
+    fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    /// Consumes the subtree, and appends its entries to `entries` in ascending order.
+    fn drain_into(self, entries: &mut Vec<(K, V)>) {
+        let mut children = self.children.into_iter();
+
+        for entry in self.keys.into_iter().zip(self.values) {
+            if let Some(child) = children.next() {
+                child.drain_into(entries);
+            }
+            entries.push(entry);
+        }
+
+        if let Some(child) = children.next() {
+            child.drain_into(entries);
+        }
+    }
+
+    /// Splits the full child at index `i` in two, and moves its median entry into `self`.
+    fn split_child(&mut self, i: usize) {
+        let Some(child) = self.children.get_mut(i) else {
+            return;
+        };
+
+        let keys = child.keys.split_off(B);
+        let values = child.values.split_off(B);
+        let children = match child.is_leaf() {
+            true => Vec::new(),
+            false => child.children.split_off(B),
+        };
+
+        if let (Some(key), Some(value)) = (child.keys.pop(), child.values.pop()) {
+            self.keys.insert(i, key);
+            self.values.insert(i, value);
+            self.children.insert(
+                i + 1,
+                Node {
+                    keys,
+                    values,
+                    children,
+                },
+            );
+        }
+    }
+
+    /// Moves the entry at index `i` and the child to its right into the child to its left.
+    fn merge_children(&mut self, i: usize) {
+        if i + 1 >= self.children.len() {
+            return;
+        }
+
+        let right = self.children.remove(i + 1);
+        let key = self.keys.remove(i);
+        let value = self.values.remove(i);
+
+        if let Some(left) = self.children.get_mut(i) {
+            left.keys.push(key);
+            left.keys.extend(right.keys);
+            left.values.push(value);
+            left.values.extend(right.values);
+            left.children.extend(right.children);
+        }
+    }
+
+    /// Moves the last entry of the child at index `i` up into `self`, and the entry it replaces
+    /// down into the front of the child to its right.
+    fn rotate_right(&mut self, i: usize) {
+        let Some([left, right]) = self.children.get_mut(i..=i + 1) else {
+            return;
+        };
+        let (Some(key), Some(value)) = (self.keys.get_mut(i), self.values.get_mut(i)) else {
+            return;
+        };
+        let (Some(last_key), Some(last_value)) = (left.keys.pop(), left.values.pop()) else {
+            return;
+        };
+
+        right.keys.insert(0, mem::replace(key, last_key));
+        right.values.insert(0, mem::replace(value, last_value));
+        if let Some(child) = left.children.pop() {
+            right.children.insert(0, child);
+        }
+    }
+
+    /// Moves the first entry of the child at index `i + 1` up into `self`, and the entry it
+    /// replaces down into the back of the child to its left.
+    fn rotate_left(&mut self, i: usize) {
+        let Some([left, right]) = self.children.get_mut(i..=i + 1) else {
+            return;
+        };
+        let (Some(key), Some(value)) = (self.keys.get_mut(i), self.values.get_mut(i)) else {
+            return;
+        };
+        if right.keys.is_empty() {
+            return;
+        }
+
+        left.keys.push(mem::replace(key, right.keys.remove(0)));
+        left.values
+            .push(mem::replace(value, right.values.remove(0)));
+        if !right.is_leaf() {
+            left.children.push(right.children.remove(0));
+        }
+    }
+
+    /// Makes sure the child at index `i` holds at least `B` entries before descending into it,
+    /// and returns the index of the child that now covers the same keys.
+    fn fill_child(&mut self, i: usize) -> usize {
+        let len = |child: Option<&Self>| child.map_or(0, |child| child.keys.len());
+
+        if len(self.children.get(i)) >= B {
+            return i;
+        }
+
+        match i.checked_sub(1) {
+            Some(prev) if len(self.children.get(prev)) >= B => {
+                self.rotate_right(prev);
+                i
+            }
+            _ if len(self.children.get(i + 1)) >= B => {
+                self.rotate_left(i);
+                i
+            }
+            _ if i + 1 < self.children.len() => {
+                self.merge_children(i);
+                i
+            }
+            Some(prev) => {
+                self.merge_children(prev);
+                prev
+            }
+            None => i,
+        }
+    }
+
+    /// Removes the largest entry of the subtree.
+    fn pop_last(&mut self) -> Option<(K, V)> {
+        if self.is_leaf() {
+            return self.keys.pop().zip(self.values.pop());
+        }
+
+        let i = self.fill_child(self.children.len() - 1);
+        self.children.get_mut(i)?.pop_last()
+    }
+
+    /// Removes the smallest entry of the subtree.
+    fn pop_first(&mut self) -> Option<(K, V)> {
+        if self.is_leaf() {
+            if self.keys.is_empty() {
+                return None;
+            }
+            return Some((self.keys.remove(0), self.values.remove(0)));
+        }
+
+        let i = self.fill_child(0);
+        self.children.get_mut(i)?.pop_first()
+    }
+}
+
+impl<K: Ord, V, const B: usize> Node<K, V, B> {
+    /// Inserts the entry into the subtree, whose root must not be full.
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let mut i = match self.keys.binary_search(&key) {
+            Ok(i) => return self.values.get_mut(i).map(|old| mem::replace(old, value)),
+            Err(i) => i,
+        };
+
+        if self.is_leaf() {
+            self.keys.insert(i, key);
+            self.values.insert(i, value);
+            return None;
+        }
+
+        let full = 2 * B - 1;
+        if self
+            .children
+            .get(i)
+            .is_some_and(|child| child.keys.len() == full)
+        {
+            self.split_child(i);
+
+            match self.keys.get(i).map(|median| key.cmp(median)) {
+                Some(Ordering::Equal) => {
+                    return self.values.get_mut(i).map(|old| mem::replace(old, value));
+                }
+                Some(Ordering::Greater) => i += 1,
+                Some(Ordering::Less) | None => (),
+            }
+        }
+
+        self.children.get_mut(i)?.insert(key, value)
+    }
+
+    /// Removes the entry with the key from the subtree, whose root must hold at least `B`
+    /// entries unless it is the root of the tree.
+    fn remove(&mut self, key: &K) -> Option<(K, V)> {
+        match self.keys.binary_search(key) {
+            Ok(i) if self.is_leaf() => Some((self.keys.remove(i), self.values.remove(i))),
+            Ok(i) => {
+                let len = |child: Option<&Self>| child.map_or(0, |child| child.keys.len());
+
+                if len(self.children.get(i)) >= B {
+                    let (key, value) = self.children.get_mut(i)?.pop_last()?;
+                    self.replace_entry(i, key, value)
+                } else if len(self.children.get(i + 1)) >= B {
+                    let (key, value) = self.children.get_mut(i + 1)?.pop_first()?;
+                    self.replace_entry(i, key, value)
+                } else {
+                    self.merge_children(i);
+                    self.children.get_mut(i)?.remove(key)
+                }
+            }
+            Err(_) if self.is_leaf() => None,
+            Err(i) => {
+                let i = self.fill_child(i);
+                self.children.get_mut(i)?.remove(key)
+            }
+        }
+    }
+
+    /// Replaces the entry at index `i`, and returns the old one.
+    fn replace_entry(&mut self, i: usize, key: K, value: V) -> Option<(K, V)> {
+        let old_key = mem::replace(self.keys.get_mut(i)?, key);
+        let old_value = mem::replace(self.values.get_mut(i)?, value);
+
+        Some((old_key, old_value))
+    }
+}
+
+#[cfg(test)]
+impl<K: Ord, V, const B: usize> BTreeMap<K, V, B> {
+    /// Panics if any node breaks the size, ordering or depth invariants of the tree.
+    fn assert_invariants(&self) {
+        fn check<K: Ord, V, const B: usize>(
+            node: &Node<K, V, B>,
+            is_root: bool,
+            depth: usize,
+            leaf_depth: &mut Option<usize>,
+        ) -> usize {
+            assert!(node.keys.len() < 2 * B);
+            assert!(is_root || node.keys.len() >= B - 1);
+            assert_eq!(node.keys.len(), node.values.len());
+            assert!(node
+                .keys
+                .windows(2)
+                .all(|pair| matches!(pair, [a, b] if a < b)));
+
+            if node.is_leaf() {
+                assert_eq!(*leaf_depth.get_or_insert(depth), depth);
+                return node.keys.len();
+            }
+
+            assert_eq!(node.children.len(), node.keys.len() + 1);
+            for (i, child) in node.children.iter().enumerate() {
+                if let (Some(key), Some(max)) = (node.keys.get(i), child.keys.last()) {
+                    assert!(max < key);
+                }
+                if let (Some(key), Some(min)) = (
+                    i.checked_sub(1).and_then(|i| node.keys.get(i)),
+                    child.keys.first(),
+                ) {
+                    assert!(min > key);
+                }
+            }
+
+            node.keys.len()
+                + node
+                    .children
+                    .iter()
+                    .map(|child| check(child, false, depth + 1, leaf_depth))
+                    .sum::<usize>()
+        }
+
+        assert_eq!(check(&self.root, true, 0, &mut None), self.count);
+    }
+}
+
+#[cfg(test)]
+mod insert {
+    use super::{BTreeMap, Node};
+
+    fn leaf(keys: Vec<i32>) -> Node<i32, (), 2> {
+        Node {
+            values: vec![(); keys.len()],
+            keys,
+            children: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn fills_root_leaf() {
+        let mut map: BTreeMap<i32, (), 2> = BTreeMap::new();
+        map.extend([(2, ()), (3, ()), (1, ())]);
+
+        assert_eq!(map.root, leaf(vec![1, 2, 3]));
+        assert_eq!(map.height(), 1);
+    }
+
+    #[test]
+    fn splits_full_root() {
+        let mut map: BTreeMap<i32, (), 2> = BTreeMap::new();
+        let expected = Node {
+            keys: vec![2],
+            values: vec![()],
+            children: vec![leaf(vec![1]), leaf(vec![3, 4])],
+        };
+
+        map.extend([(1, ()), (2, ()), (3, ()), (4, ())]);
+
+        assert_eq!(map.root, expected);
+        map.assert_invariants();
+    }
+
+    #[test]
+    fn splits_full_child_on_the_way_down() {
+        let mut map: BTreeMap<i32, (), 2> = BTreeMap::new();
+        let expected = Node {
+            keys: vec![2, 4],
+            values: vec![(), ()],
+            children: vec![leaf(vec![1]), leaf(vec![3]), leaf(vec![5, 6])],
+        };
+
+        map.extend((1..=6).map(|key| (key, ())));
+
+        assert_eq!(map.root, expected);
+        map.assert_invariants();
+    }
+
+    #[test]
+    fn replaces_value_of_existing_key() {
+        let mut map: BTreeMap<i32, &str, 2> = (0..10).map(|key| (key, "old")).collect();
+
+        for key in 0..10 {
+            assert_eq!(map.insert(key, "new"), Some("old"));
+        }
+
+        assert_eq!(map.count(), 10);
+        assert!(map.iter().all(|(_, value)| *value == "new"));
+    }
+
+    #[test]
+    fn many_inserts_keep_invariants() {
+        let mut map: BTreeMap<u32, u32, 3> = BTreeMap::new();
+
+        for key in (0..500).map(|v| (v * 7919) % 500) {
+            map.insert(key, key * 2);
+            map.assert_invariants();
+        }
+
+        assert_eq!(map.count(), 500);
+        assert!((0..500).all(|key| map.get(&key) == Some(&(key * 2))));
+    }
+}
+
+#[cfg(test)]
+mod remove {
+    use super::BTreeMap;
+
+    #[test]
+    fn empty_map_returns_none() {
+        let mut map: BTreeMap<i32, i32> = BTreeMap::new();
+        assert_eq!(map.remove(&0), None);
+    }
+
+    #[test]
+    fn missing_key_is_not_removed() {
+        let mut map: BTreeMap<i32, i32, 2> = (0..20).map(|key| (key * 2, key)).collect();
+
+        assert_eq!(map.remove(&7), None);
+        assert_eq!(map.count(), 20);
+        map.assert_invariants();
+    }
+
+    #[test]
+    fn removes_from_root_leaf() {
+        let mut map: BTreeMap<i32, &str, 2> = BTreeMap::from(vec![(1, "a"), (2, "b")]);
+
+        assert_eq!(map.remove_entry(&1), Some((1, "a")));
+        assert_eq!(map.remove_entry(&2), Some((2, "b")));
+        assert!(map.is_empty());
+        assert_eq!(map.height(), 0);
+    }
+
+    #[test]
+    fn removes_internal_keys() {
+        let mut map: BTreeMap<i32, i32, 2> = (0..50).map(|key| (key, -key)).collect();
+        let internal: Vec<i32> = map.root.keys.clone();
+
+        for key in internal {
+            assert_eq!(map.remove(&key), Some(-key));
+            map.assert_invariants();
+        }
+    }
+
+    #[test]
+    fn removes_every_key_in_ascending_order() {
+        let mut map: BTreeMap<i32, i32, 2> = (0..200).map(|key| (key, key)).collect();
+
+        for key in 0..200 {
+            assert_eq!(map.remove(&key), Some(key));
+            map.assert_invariants();
+        }
+
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn removes_every_key_in_descending_order() {
+        let mut map: BTreeMap<i32, i32, 3> = (0..200).map(|key| (key, key)).collect();
+
+        for key in (0..200).rev() {
+            assert_eq!(map.remove(&key), Some(key));
+            map.assert_invariants();
+        }
+
+        assert!(map.is_empty());
+    }
+
+    #[test]
+    fn matches_std_map_under_random_operations() {
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+
+        let mut rng = StdRng::seed_from_u64(28);
+        let mut map: BTreeMap<u16, u32, 2> = BTreeMap::new();
+        let mut model = std::collections::BTreeMap::new();
+
+        for step in 0..5000 {
+            let key = rng.gen_range(0..300);
+            match rng.gen_bool(0.6) {
+                true => assert_eq!(map.insert(key, step), model.insert(key, step)),
+                false => assert_eq!(map.remove(&key), model.remove(&key)),
+            }
+        }
+
+        map.assert_invariants();
+        assert!(map.iter().eq(model.iter()));
+    }
+}
+
+#[cfg(test)]
+mod iter {
+    use super::BTreeMap;
+
+    #[test]
+    fn empty_map_yields_nothing() {
+        let map: BTreeMap<i32, i32> = BTreeMap::new();
+        let mut iter = map.iter();
+
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn yields_entries_in_order_from_both_ends() {
+        let map: BTreeMap<i32, i32, 2> = (0..100).rev().map(|key| (key, key)).collect();
+
+        assert!(map.iter().map(|(key, _)| *key).eq(0..100));
+        assert!(map.iter().rev().map(|(key, _)| *key).eq((0..100).rev()));
+    }
+
+    #[test]
+    fn alternating_ends_meet_in_the_middle() {
+        let map: BTreeMap<i32, (), 2> = (0..51).map(|key| (key, ())).collect();
+        let mut iter = map.iter();
+        let mut seen = Vec::new();
+
+        while let Some((front, _)) = iter.next() {
+            seen.push(*front);
+            if let Some((back, _)) = iter.next_back() {
+                seen.push(*back);
+            }
+        }
+
+        seen.sort();
+        assert!(seen.into_iter().eq(0..51));
+    }
+
+    #[test]
+    fn reports_exact_length() {
+        let map: BTreeMap<i32, i32, 2> = (0..10).map(|key| (key, key)).collect();
+        let mut iter = map.iter();
+        iter.next();
+        iter.next_back();
+
+        assert_eq!(iter.len(), 8);
+    }
+
+    #[test]
+    fn into_iter_yields_owned_entries_in_order() {
+        let map: BTreeMap<i32, String, 2> =
+            (0..30).rev().map(|key| (key, key.to_string())).collect();
+        let entries: Vec<(i32, String)> = map.into_iter().collect();
+
+        assert!(entries.iter().map(|(key, _)| *key).eq(0..30));
+        assert!(entries.iter().all(|(key, value)| key.to_string() == *value));
+    }
+}
+
+#[cfg(test)]
+mod range {
+    use super::BTreeMap;
+    use std::ops::Bound;
+
+    fn keys<'a>(iter: impl Iterator<Item = (&'a i32, &'a ())>) -> Vec<i32> {
+        iter.map(|(key, _)| *key).collect()
+    }
+
+    fn map() -> BTreeMap<i32, (), 2> {
+        (0..50).map(|key| (key * 2, ())).collect()
+    }
+
+    #[test]
+    fn half_open_range() {
+        assert_eq!(keys(map().range(10..20)), vec![10, 12, 14, 16, 18]);
+    }
+
+    #[test]
+    fn inclusive_range_between_keys() {
+        assert_eq!(keys(map().range(9..=15)), vec![10, 12, 14]);
+    }
+
+    #[test]
+    fn excluded_lower_bound() {
+        let map = map();
+        let range = map.range((Bound::Excluded(10), Bound::Included(14)));
+
+        assert_eq!(keys(range), vec![12, 14]);
+    }
+
+    #[test]
+    fn unbounded_ends() {
+        assert_eq!(keys(map().range(..5)), vec![0, 2, 4]);
+        assert_eq!(keys(map().range(95..)), vec![96, 98]);
+        assert_eq!(map().range(..).count(), 50);
+    }
+
+    #[test]
+    fn empty_ranges() {
+        assert!(keys(map().range(11..12)).is_empty());
+        assert!(keys(map().range(200..)).is_empty());
+        assert!(keys(map().range(..0)).is_empty());
+    }
+
+    #[test]
+    fn single_element_range_from_back() {
+        let map = map();
+        let mut range = map.range(20..=20);
+
+        assert_eq!(range.next_back(), Some((&20, &())));
+        assert_eq!(range.next(), None);
+    }
+
+    #[test]
+    fn matches_std_range_from_both_ends() {
+        let map: BTreeMap<i32, (), 3> = (0..300).map(|key| (key * 3, ())).collect();
+        let model: std::collections::BTreeMap<i32, ()> =
+            (0..300).map(|key| (key * 3, ())).collect();
+
+        for (start, end) in [
+            (0, 900),
+            (1, 2),
+            (5, 500),
+            (299, 301),
+            (450, 451),
+            (-10, 10),
+        ] {
+            assert!(map.range(start..end).eq(model.range(start..end)));
+            assert!(map
+                .range(start..end)
+                .rev()
+                .eq(model.range(start..end).rev()));
+        }
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod json {
+    use super::BTreeMap;
+
+    #[test]
+    fn round_trips_through_json() {
+        let map: BTreeMap<i32, String, 2> = (0..20).map(|key| (key, key.to_string())).collect();
+
+        let json = serde_json::to_string(&map).expect("should parse map into json");
+        let actual: BTreeMap<i32, String, 2> =
+            serde_json::from_str(&json).expect("should parse json into map");
+
+        assert_eq!(actual, map);
+    }
+}
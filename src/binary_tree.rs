@@ -1,13 +1,34 @@
-use std::collections::VecDeque;
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::fmt;
+use core::hash::{Hash, Hasher};
+#[cfg(feature = "std")]
+use core::num::NonZeroUsize;
+use core::ops::{Deref, DerefMut};
+
+use crate::sorted_set::SortedSet;
 
 #[cfg(feature = "json")]
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+pub mod cursor;
+pub mod diff;
+pub mod fallible;
+pub mod node_ref;
+pub mod set_ops;
+pub mod stats;
 
 #[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
-#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
+#[derive(Default, Debug, Clone)]
 pub struct BinaryTree<T> {
-    root: Option<Box<Node<T>>>,
+    root: Option<NodeBox<T>>,
     count: usize,
+    #[cfg_attr(
+        feature = "json",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    max_len: Option<usize>,
 }
 
 impl<T> BinaryTree<T> {
@@ -26,9 +47,46 @@ impl<T> BinaryTree<T> {
         Self {
             root: None,
             count: 0,
+            max_len: None,
         }
     }
 
+    /// Constructs a new empty `BinaryTree<T>` that holds at most `max_len` elements.
+    ///
+    /// Inserting beyond `max_len` makes [`BinaryTree::try_insert`] return an error,
+    /// and [`BinaryTree::insert`] panic.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// let mut tree = BinaryTree::with_max_len(2);
+    /// assert!(tree.try_insert(1).is_ok());
+    /// assert!(tree.try_insert(2).is_ok());
+    /// assert!(tree.try_insert(3).is_err());
+    /// assert_eq!(tree.max_len(), Some(2));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_max_len(max_len: usize) -> Self {
+        Self {
+            root: None,
+            count: 0,
+            max_len: Some(max_len),
+        }
+    }
+
+    /// Returns the largest number of elements the tree may hold, or `None` if it is unbounded.
+    #[inline]
+    pub fn max_len(&self) -> Option<usize> {
+        self.max_len
+    }
+
+    /// Returns `true` if the tree holds as many elements as its `max_len` allows.
+    fn is_full(&self) -> bool {
+        self.max_len.is_some_and(|max_len| self.count >= max_len)
+    }
+
     /// Returns `true` if the binary tree contains no elements.
     ///
     /// # Examples
@@ -91,25 +149,38 @@ impl<T> BinaryTree<T> {
     #[inline]
     pub fn height(&self) -> usize {
         let mut height = 0;
+        self.walk(|node_height, _, _| height = height.max(node_height));
+
+        height
+    }
+
+    /// Visits every node of the tree in preorder, along with the level it is on, counting the root as level `1`,
+    /// and its position when the tree is numbered like a binary heap from `1` at the root.
+    ///
+    /// The position is `None` if it does not fit in a `usize`.
+    fn walk<'a>(&'a self, mut visit: impl FnMut(usize, Option<usize>, &'a Node<T>)) {
         let mut queue = Vec::new();
 
         if let Some(root) = self.root.as_deref() {
-            queue.push((1, root));
+            queue.push((1, Some(1), root));
         }
 
-        while let Some((node_height, node)) = queue.pop() {
-            height = height.max(node_height);
+        while let Some((node_height, position, node)) = queue.pop() {
+            visit(node_height, position, node);
+            let left = position.and_then(|position| position.checked_mul(2));
 
-            if let Some(left) = node.left.as_deref() {
-                queue.push((node_height + 1, left));
+            if let Some(right) = node.right.as_deref() {
+                queue.push((
+                    node_height + 1,
+                    left.and_then(|left| left.checked_add(1)),
+                    right,
+                ))
             }
 
-            if let Some(right) = node.right.as_deref() {
-                queue.push((node_height + 1, right))
+            if let Some(left_node) = node.left.as_deref() {
+                queue.push((node_height + 1, left, left_node));
             }
         }
-
-        height
     }
 
     /// Returns the value contained within the root element.
@@ -176,6 +247,66 @@ impl<T> BinaryTree<T> {
         self.as_ref().into_iter()
     }
 
+    /// Returns a non-consuming iterator over the `BinaryTree` that yields all items in ascending order.
+    ///
+    /// Unlike [`BinaryTree::iter`], the traversal is lazy, and only keeps the path to the next item.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// let tree = BinaryTree::from(vec![5, 4, 6]);
+    /// let mut tree_iter = tree.in_order();
+    ///
+    /// assert_eq!(tree_iter.next(), Some(&4));
+    /// assert_eq!(tree_iter.next(), Some(&5));
+    /// assert_eq!(tree_iter.next(), Some(&6));
+    ///
+    /// // the iterator is now empty
+    /// assert_eq!(tree_iter.next(), None);
+    /// ```
+    #[inline]
+    #[must_use = "iterators are evaluated lazily"]
+    pub fn in_order(&self) -> InOrder<'_, T> {
+        InOrder::new(self.root.as_deref(), self.count)
+    }
+
+    /// Reduces the elements of the `BinaryTree` in ascending order, folding subtrees on up to `threads` threads.
+    ///
+    /// Every thread starts a fold from `identity()` and folds its elements with `fold`, and the
+    /// partial results are joined with `combine` in the order of their elements. `combine` must be
+    /// associative, and `identity()` must leave any result unchanged when combined with it, then
+    /// the result is the same as a sequential fold regardless of the thread count.
+    ///
+    /// Subtrees are handed to threads from the root down, so an unbalanced tree spreads the work unevenly.
+    ///
+    /// This method spawns threads, so it is only available with the **std** feature.
+    /// No more threads are used than [`std::thread::available_parallelism`] reports.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// # use std::num::NonZeroUsize;
+    /// let tree = BinaryTree::par_from_vec((1..=100).collect(), NonZeroUsize::MIN);
+    /// let threads = NonZeroUsize::new(4).unwrap_or(NonZeroUsize::MIN);
+    ///
+    /// let sum = tree.par_fold(threads, || 0, |sum, value| sum + value, |a, b| a + b);
+    /// assert_eq!(sum, 5050);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn par_fold<A, I, F, C>(&self, threads: NonZeroUsize, identity: I, fold: F, combine: C) -> A
+    where
+        T: Sync,
+        A: Send,
+        I: Fn() -> A + Sync,
+        F: Fn(A, &T) -> A + Sync,
+        C: Fn(A, A) -> A + Sync,
+    {
+        match self.root.as_deref() {
+            Some(root) => par_fold(root, thread_limit(threads), &identity, &fold, &combine),
+            None => identity(),
+        }
+    }
+
     /// Returns the smallest element in the `BinaryTree`.
     ///
     /// # Time Complexity
@@ -233,6 +364,40 @@ impl<T> BinaryTree<T> {
             None
         }
     }
+
+    /// Removes the smallest element from the `BinaryTree`, and returns it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// let mut tree = BinaryTree::from(vec![8, 4, 6]);
+    /// assert_eq!(tree.pop_min(), Some(4));
+    /// assert_eq!(tree.min(), Some(&6));
+    /// assert_eq!(tree.count(), 2);
+    /// ```
+    pub fn pop_min(&mut self) -> Option<T> {
+        let min = Node::pop_min(&mut self.root)?;
+        self.count -= 1;
+
+        Some(min)
+    }
+
+    /// Removes the largest element from the `BinaryTree`, and returns it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// let mut tree = BinaryTree::from(vec![8, 4, 6]);
+    /// assert_eq!(tree.pop_max(), Some(8));
+    /// assert_eq!(tree.max(), Some(&6));
+    /// assert_eq!(tree.count(), 2);
+    /// ```
+    pub fn pop_max(&mut self) -> Option<T> {
+        let max = Node::pop_max(&mut self.root)?;
+        self.count -= 1;
+
+        Some(max)
+    }
 }
 
 /// The methods in this implementation block require trait bounds to correctly apply the logic of the methods.
@@ -245,6 +410,11 @@ where
     /// Inserts the provided value into the `BinaryTree`,
     /// and preserves the properties of the binary tree.
     ///
+    /// # Panics
+    ///
+    /// Panics if the value is not in the tree, and the tree already holds [`BinaryTree::max_len`] elements.
+    /// Use [`BinaryTree::try_insert`] to handle a full tree instead.
+    ///
     /// # Examples
     /// ```
     /// # use ds_rs::binary_tree::BinaryTree;
@@ -255,7 +425,12 @@ where
     /// assert_eq!(tree.count(), 1);
     /// ```
     pub fn insert(&mut self, value: T) {
-        use std::cmp::Ordering as Ord;
+        use core::cmp::Ordering as Ord;
+
+        assert!(
+            !self.is_full() || self.contains(&value),
+            "the binary tree has reached its max_len"
+        );
 
         if let Some(mut root) = self.root.as_deref_mut() {
             loop {
@@ -295,7 +470,7 @@ where
                 }
             }
         } else {
-            self.root = Some(Box::new(Node::new(value)));
+            self.root = Some(NodeBox::new(Node::new(value)));
             self.count = 1;
         }
     }
@@ -319,7 +494,7 @@ where
     /// assert!(!tree.contains(&5));
     /// ```
     pub fn contains(&self, target: &T) -> bool {
-        use std::cmp::Ordering as Ord;
+        use core::cmp::Ordering as Ord;
 
         if let Some(mut node) = self.root.as_deref() {
             loop {
@@ -345,6 +520,231 @@ where
 
         false
     }
+
+    /// Removes the element equal to the given value from the `BinaryTree`, and returns it.
+    ///
+    /// A removed element with two children is replaced by the smallest element of its right subtree.
+    ///
+    /// # Time Complexity
+    ///
+    /// Like [`BinaryTree::contains`], a balanced tree will be near `log(n)`,
+    /// while an unbalanced tree will be closer to linear time.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// let mut tree = BinaryTree::from(vec![8, 4, 6, 16, -5, 25]);
+    /// assert_eq!(tree.remove(&8), Some(8));
+    /// assert_eq!(tree.remove(&8), None);
+    /// assert_eq!(tree.count(), 5);
+    /// ```
+    pub fn remove(&mut self, target: &T) -> Option<T> {
+        let removed = Node::remove(&mut self.root, target)?;
+        self.count -= 1;
+
+        Some(removed)
+    }
+
+    /// Builds a balanced `BinaryTree` from the values, sorting them and building subtrees on up to `threads` threads.
+    ///
+    /// Duplicate values are discarded, keeping the first of them like [`BinaryTree::insert`] does.
+    /// The sort is stable, so the resulting tree is identical regardless of the thread count.
+    ///
+    /// This method spawns threads, so it is only available with the **std** feature.
+    /// No more threads are used than [`std::thread::available_parallelism`] reports.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// # use std::num::NonZeroUsize;
+    /// let threads = NonZeroUsize::new(4).unwrap_or(NonZeroUsize::MIN);
+    /// let tree = BinaryTree::par_from_vec(vec![3, 1, 2, 3, 5, 4, 7, 6], threads);
+    ///
+    /// assert_eq!(tree.count(), 7);
+    /// assert_eq!(tree.height(), 3);
+    /// assert_eq!(tree.root(), Some(&4));
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn par_from_vec(vec: Vec<T>, threads: NonZeroUsize) -> Self
+    where
+        T: Send,
+    {
+        let threads = thread_limit(threads);
+        let mut values = par_sort(vec, threads);
+        values.dedup_by(|value, kept| (*value).cmp(kept).is_eq());
+
+        Self {
+            count: values.len(),
+            root: par_build(values, threads),
+            max_len: None,
+        }
+    }
+}
+
+/// Caps the requested number of threads at the parallelism the system offers, if it is known.
+#[cfg(feature = "std")]
+fn thread_limit(threads: NonZeroUsize) -> usize {
+    std::thread::available_parallelism()
+        .map_or(threads, |available| threads.min(available))
+        .get()
+}
+
+/// Sorts the values stably, splitting the work over up to `threads` threads.
+#[cfg(feature = "std")]
+fn par_sort<T: Ord + Send>(mut values: Vec<T>, threads: usize) -> Vec<T> {
+    if threads <= 1 || values.len() < 2 {
+        values.sort();
+        return values;
+    }
+
+    let right = values.split_off(values.len() / 2);
+    let right_threads = threads / 2;
+    let (left, right) = std::thread::scope(|scope| {
+        let right = scope.spawn(move || par_sort(right, right_threads));
+        let left = par_sort(values, threads - right_threads);
+
+        (left, right.join())
+    });
+
+    match right {
+        Ok(right) => merge(left, right),
+        Err(panic) => std::panic::resume_unwind(panic),
+    }
+}
+
+/// Merges two sorted vectors, taking from `left` first between equal values.
+#[cfg(feature = "std")]
+fn merge<T: Ord>(left: Vec<T>, right: Vec<T>) -> Vec<T> {
+    let mut merged = Vec::with_capacity(left.len() + right.len());
+    let mut left = left.into_iter().peekable();
+    let mut right = right.into_iter().peekable();
+
+    loop {
+        let take_left = match (left.peek(), right.peek()) {
+            (Some(l), Some(r)) => l <= r,
+            (Some(_), None) => true,
+            (None, Some(_)) => false,
+            (None, None) => break,
+        };
+        merged.extend(if take_left { left.next() } else { right.next() });
+    }
+
+    merged
+}
+
+/// Builds a balanced tree from sorted values, building the right subtrees on other threads.
+///
+/// The tree has the shape built by [`Node::from_sorted`], regardless of the thread count.
+#[cfg(feature = "std")]
+fn par_build<T: Send>(mut values: Vec<T>, threads: usize) -> Option<NodeBox<T>> {
+    if threads <= 1 {
+        return Node::from_sorted(values);
+    }
+
+    let mut right = values.split_off(values.len() / 2);
+    let right_threads = threads / 2;
+    let rest = right.split_off(1.min(right.len()));
+    let value = right.pop()?;
+
+    let (left, right) = std::thread::scope(|scope| {
+        let right = scope.spawn(move || par_build(rest, right_threads));
+        let left = par_build(values, threads - right_threads);
+
+        (left, right.join())
+    });
+
+    match right {
+        Ok(right) => Some(NodeBox::new(Node { value, left, right })),
+        Err(panic) => std::panic::resume_unwind(panic),
+    }
+}
+
+/// Folds the subtree rooted at `node` in ascending order, on up to `threads` threads.
+#[cfg(feature = "std")]
+fn par_fold<T, A, I, F, C>(node: &Node<T>, threads: usize, identity: &I, fold: &F, combine: &C) -> A
+where
+    T: Sync,
+    A: Send,
+    I: Fn() -> A + Sync,
+    F: Fn(A, &T) -> A + Sync,
+    C: Fn(A, A) -> A + Sync,
+{
+    if threads <= 1 {
+        let mut folded = identity();
+        let mut stack = Vec::new();
+        let mut current = Some(node);
+
+        loop {
+            while let Some(node) = current {
+                stack.push(node);
+                current = node.left();
+            }
+
+            let Some(node) = stack.pop() else {
+                return folded;
+            };
+            folded = fold(folded, &node.value);
+            current = node.right();
+        }
+    }
+
+    let right_threads = threads / 2;
+    let (left, right) = std::thread::scope(|scope| {
+        let right = node.right().map(|right| {
+            scope.spawn(move || par_fold(right, right_threads, identity, fold, combine))
+        });
+        let left = node.left().map_or_else(identity, |left| {
+            par_fold(left, threads - right_threads, identity, fold, combine)
+        });
+
+        (left, right.map(|right| right.join()))
+    });
+
+    let middle = combine(left, fold(identity(), &node.value));
+    match right {
+        Some(Ok(right)) => combine(middle, right),
+        Some(Err(panic)) => std::panic::resume_unwind(panic),
+        None => middle,
+    }
+}
+
+impl<T: Ord> SortedSet<T> for BinaryTree<T> {
+    type Iter<'a>
+        = InOrder<'a, T>
+    where
+        T: 'a;
+
+    fn insert(&mut self, value: T) -> bool {
+        let count = self.count;
+        BinaryTree::insert(self, value);
+
+        self.count > count
+    }
+
+    fn remove(&mut self, target: &T) -> Option<T> {
+        BinaryTree::remove(self, target)
+    }
+
+    fn contains(&self, target: &T) -> bool {
+        BinaryTree::contains(self, target)
+    }
+
+    fn min(&self) -> Option<&T> {
+        BinaryTree::min(self)
+    }
+
+    fn max(&self) -> Option<&T> {
+        BinaryTree::max(self)
+    }
+
+    fn len(&self) -> usize {
+        self.count
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        self.in_order()
+    }
 }
 
 impl<T: Ord> From<Vec<T>> for BinaryTree<T> {
@@ -375,6 +775,23 @@ impl<T> AsMut<BinaryTree<T>> for BinaryTree<T> {
     }
 }
 
+impl<T: PartialEq> PartialEq for BinaryTree<T> {
+    /// Returns `true` if both trees hold equal elements laid out in the same shape, regardless of their `max_len`.
+    fn eq(&self, other: &Self) -> bool {
+        self.count == other.count && self.root == other.root
+    }
+}
+
+impl<T: Eq> Eq for BinaryTree<T> {}
+
+impl<T: Hash> Hash for BinaryTree<T> {
+    /// Hashes the elements and shape of the tree, leaving out its `max_len` like [`PartialEq`] does.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.root.hash(state);
+        self.count.hash(state);
+    }
+}
+
 impl<T: Ord> FromIterator<T> for BinaryTree<T> {
     /// Constructs a `BinaryTree<T>` from an iterator for `T`.
     fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
@@ -390,6 +807,11 @@ impl<T: Ord> FromIterator<T> for BinaryTree<T> {
 
 impl<T: Ord> Extend<T> for BinaryTree<T> {
     /// Extends the `BinaryTree` with the contents of the provided iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a new value does not fit within [`BinaryTree::max_len`], leaving the values before it in the tree.
+    /// Use [`BinaryTree::try_extend`] to get the value back instead.
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
         for v in iter {
             self.insert(v);
@@ -419,15 +841,28 @@ impl<T> IntoIterator for BinaryTree<T> {
     /// // the iterator is now empty
     /// assert_eq!(tree_iter.next(), None);
     /// ```
-    #[must_use = "iterators are evaluated lazily"]
     fn into_iter(self) -> Self::IntoIter {
-        let mut values = Vec::with_capacity(self.count);
+        IntoIter::new(self.root, self.count)
+    }
+}
+
+/// An iterator that moves out of the `BinaryTree`.
+///
+/// This `struct` is created by the `into_iter` method on [`BinaryTree`] (provided by the [`IntoIterator`] trait).
+pub struct IntoIter<T> {
+    vec: alloc::vec::IntoIter<T>,
+}
+
+impl<T> IntoIter<T> {
+    /// Consumes the tree rooted at `root` in preorder, with room for `count` elements.
+    pub(crate) fn new(root: Option<NodeBox<T>>, count: usize) -> Self {
+        let mut values = Vec::with_capacity(count);
         let mut queue = VecDeque::new();
 
-        if let Some(root) = self.root {
+        if let Some(root) = root {
             queue.push_front(root);
 
-            while let Some(node) = queue.pop_front() {
+            while let Some(node) = queue.pop_front().map(NodeBox::into_inner) {
                 values.push(node.value);
 
                 if let Some(right) = node.right {
@@ -446,13 +881,6 @@ impl<T> IntoIterator for BinaryTree<T> {
     }
 }
 
-/// An iterator that moves out of the `BinaryTree`.
-///
-/// This `struct` is created by the `into_iter` method on [`BinaryTree`] (provided by the [`IntoIterator`] trait).
-pub struct IntoIter<T> {
-    vec: std::vec::IntoIter<T>,
-}
-
 impl<T> Iterator for IntoIter<T> {
     type Item = T;
 
@@ -468,20 +896,35 @@ impl<'a, T> IntoIterator for &'a BinaryTree<T> {
     type IntoIter = Iter<'a, T>;
 
     fn into_iter(self) -> Self::IntoIter {
-        let mut values = Vec::with_capacity(self.count);
+        Iter::new(self.root.as_deref(), self.count)
+    }
+}
+
+/// An iterator that borrows from the `BinaryTree`.
+///
+/// This `struct` is created by the `iter` method on [`BinaryTree`].
+pub struct Iter<'a, T> {
+    vec: Vec<&'a T>,
+    index: usize,
+}
+
+impl<'a, T> Iter<'a, T> {
+    /// Walks the tree rooted at `root` in preorder, with room for `count` elements.
+    pub(crate) fn new(root: Option<&'a Node<T>>, count: usize) -> Self {
+        let mut values = Vec::with_capacity(count);
         let mut queue = VecDeque::new();
 
-        if let Some(root) = &self.root {
+        if let Some(root) = root {
             queue.push_front(root);
 
             while let Some(node) = queue.pop_front() {
                 values.push(&node.value);
 
-                if let Some(right) = &node.right {
+                if let Some(right) = node.right() {
                     queue.push_front(right);
                 }
 
-                if let Some(left) = &node.left {
+                if let Some(left) = node.left() {
                     queue.push_front(left);
                 }
             }
@@ -494,14 +937,6 @@ impl<'a, T> IntoIterator for &'a BinaryTree<T> {
     }
 }
 
-/// An iterator that borrows from the `BinaryTree`.
-///
-/// This `struct` is created by the `iter` method on [`BinaryTree`].
-pub struct Iter<'a, T> {
-    vec: Vec<&'a T>,
-    index: usize,
-}
-
 impl<'a, T> Iterator for Iter<'a, T> {
     type Item = &'a T;
 
@@ -518,72 +953,326 @@ impl<'a, T> Iterator for Iter<'a, T> {
     }
 }
 
+/// An iterator that borrows from the `BinaryTree`, and yields its items in ascending order.
+///
+/// This `struct` is created by the `in_order` method on [`BinaryTree`].
+pub struct InOrder<'a, T> {
+    stack: Vec<&'a Node<T>>,
+    remaining: usize,
+}
+
+impl<'a, T> InOrder<'a, T> {
+    /// Walks the tree rooted at `root` in order, which holds `count` elements.
+    pub(crate) fn new(root: Option<&'a Node<T>>, count: usize) -> Self {
+        let mut iter = InOrder {
+            stack: Vec::new(),
+            remaining: count,
+        };
+        iter.push_left(root);
+
+        iter
+    }
+
+    /// Pushes the left spine of the subtree rooted at `node`.
+    fn push_left(&mut self, mut node: Option<&'a Node<T>>) {
+        while let Some(current) = node {
+            self.stack.push(current);
+            node = current.left();
+        }
+    }
+}
+
+impl<'a, T> Iterator for InOrder<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left(node.right());
+        self.remaining = self.remaining.saturating_sub(1);
+
+        Some(&node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for InOrder<'a, T> {}
+
 #[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
-struct Node<T> {
-    value: T,
-    left: Option<Box<Node<T>>>,
-    right: Option<Box<Node<T>>>,
+pub(crate) struct Node<T> {
+    pub(crate) value: T,
+    pub(crate) left: Option<NodeBox<T>>,
+    pub(crate) right: Option<NodeBox<T>>,
 }
 
-impl<T> Node<T> {
-    /// Constructs a new empty `Node<T>`.
-    ///
-    /// An node has no left or right child.
-    pub fn new(value: T) -> Self {
-        Self {
-            value,
-            left: None,
-            right: None,
+impl<T> Node<T> {
+    /// Constructs a new empty `Node<T>`.
+    ///
+    /// An node has no left or right child.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            left: None,
+            right: None,
+        }
+    }
+
+    /// Returns a reference to the value of the node.
+    #[inline]
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns an `Option` containing a reference to the left child of the node.
+    #[inline]
+    pub fn left(&self) -> Option<&Self> {
+        self.left.as_deref()
+    }
+
+    /// Returns an `Option` containing a reference to the right child of the node.
+    #[inline]
+    pub fn right(&self) -> Option<&Self> {
+        self.right.as_deref()
+    }
+
+    /// Returns an `Option` containing a mutable reference to the left child of the node.
+    #[inline]
+    pub fn left_mut(&mut self) -> Option<&mut Self> {
+        self.left.as_deref_mut()
+    }
+
+    /// Returns an `Option` containing a mutable reference to the right child of the node.
+    #[inline]
+    pub fn right_mut(&mut self) -> Option<&mut Self> {
+        self.right.as_deref_mut()
+    }
+
+    /// Creates a new `Node` from the provided value, and set it as the left child of `self`.
+    #[inline]
+    pub fn set_left(&mut self, value: T) {
+        self.left = Some(NodeBox::new(Node::new(value)));
+    }
+
+    /// Creates a new `Node` from the provided value, and set it as the right child of `self`.
+    #[inline]
+    pub fn set_right(&mut self, value: T) {
+        self.right = Some(NodeBox::new(Node::new(value)));
+    }
+
+    /// Builds a balanced tree from values that are sorted in ascending order, and contain no duplicates.
+    ///
+    /// The middle value becomes the root, and both halves are built the same way,
+    /// which takes linear time.
+    pub(crate) fn from_sorted(values: Vec<T>) -> Option<NodeBox<T>> {
+        let count = values.len();
+        Self::build_balanced(&mut values.into_iter(), count)
+    }
+
+    /// Builds a balanced tree from the next `count` values of the sorted iterator.
+    fn build_balanced<I>(values: &mut I, count: usize) -> Option<NodeBox<T>>
+    where
+        I: Iterator<Item = T>,
+    {
+        if count == 0 {
+            return None;
+        }
+
+        let left_count = count / 2;
+        let left = Self::build_balanced(values, left_count);
+        let value = values.next()?;
+        let right = Self::build_balanced(values, count - left_count - 1);
+
+        Some(NodeBox::new(Node { value, left, right }))
+    }
+
+    /// Consumes the tree rooted at `node`, and appends its values to `values` in ascending order.
+    pub(crate) fn drain_sorted(node: Option<NodeBox<T>>, values: &mut Vec<T>) {
+        let mut stack = Vec::new();
+        let mut current = node;
+
+        loop {
+            while let Some(mut node) = current {
+                current = node.left.take();
+                stack.push(node);
+            }
+
+            let Some(node) = stack.pop().map(NodeBox::into_inner) else {
+                break;
+            };
+            values.push(node.value);
+            current = node.right;
+        }
+    }
+
+    /// Removes the smallest value from the tree rooted in `slot`, and returns it.
+    fn pop_min(mut slot: &mut Option<NodeBox<T>>) -> Option<T> {
+        while slot.as_deref().is_some_and(|node| node.left.is_some()) {
+            slot = &mut slot.as_mut()?.left;
+        }
+
+        let mut node = slot.take()?.into_inner();
+        *slot = node.right.take();
+
+        Some(node.value)
+    }
+
+    /// Removes the largest value from the tree rooted in `slot`, and returns it.
+    fn pop_max(mut slot: &mut Option<NodeBox<T>>) -> Option<T> {
+        while slot.as_deref().is_some_and(|node| node.right.is_some()) {
+            slot = &mut slot.as_mut()?.right;
+        }
+
+        let mut node = slot.take()?.into_inner();
+        *slot = node.left.take();
+
+        Some(node.value)
+    }
+}
+
+impl<T: Ord> Node<T> {
+    /// Removes the value equal to `target` from the tree rooted in `slot`, and returns it.
+    ///
+    /// A node with two children is replaced by the smallest value in its right subtree.
+    pub(crate) fn remove(mut slot: &mut Option<NodeBox<T>>, target: &T) -> Option<T> {
+        use core::cmp::Ordering as Ord;
+
+        loop {
+            match target.cmp(&slot.as_deref()?.value) {
+                Ord::Equal => break,
+                Ord::Less => slot = &mut slot.as_mut()?.left,
+                Ord::Greater => slot = &mut slot.as_mut()?.right,
+            }
+        }
+
+        let mut node = slot.take()?.into_inner();
+        *slot = match (node.left.take(), node.right.take()) {
+            (None, None) => None,
+            (Some(child), None) | (None, Some(child)) => Some(child),
+            (Some(left), Some(right)) => {
+                let mut right = Some(right);
+                let successor = Self::pop_min(&mut right)?;
+
+                Some(NodeBox::new(Node {
+                    value: successor,
+                    left: Some(left),
+                    right,
+                }))
+            }
+        };
+
+        Some(node.value)
+    }
+}
+
+/// The heap allocation holding a `Node`, which unlike a `Box` can be made without aborting when memory runs out.
+///
+/// The node is kept in a one element array, because a `Vec` can reserve its memory fallibly and hand it over as a
+/// boxed array. It dereferences to the node, and compares, hashes, formats and serializes like it.
+pub(crate) struct NodeBox<T>(Box<[Node<T>; 1]>);
+
+impl<T> NodeBox<T> {
+    /// Moves the node to the heap, aborting if memory runs out like [`Box::new`].
+    #[inline]
+    pub(crate) fn new(node: Node<T>) -> Self {
+        Self(Box::new([node]))
+    }
+
+    /// Moves the node to the heap, or hands it back if the allocator cannot provide the memory.
+    pub(crate) fn try_new(node: Node<T>) -> Result<Self, Node<T>> {
+        let mut nodes = Vec::new();
+        if nodes.try_reserve_exact(1).is_err() {
+            return Err(node);
+        }
+        nodes.push(node);
+
+        match Box::try_from(nodes) {
+            Ok(nodes) => Ok(Self(nodes)),
+            Err(_) => {
+                unreachable!("a vec holding a single node converts into a boxed array of one node")
+            }
         }
     }
 
-    /// Returns a reference to the value of the node.
+    /// Moves the node back off the heap.
     #[inline]
-    pub fn value(&self) -> &T {
-        &self.value
+    pub(crate) fn into_inner(self) -> Node<T> {
+        let [node] = *self.0;
+        node
     }
+}
+
+impl<T> Deref for NodeBox<T> {
+    type Target = Node<T>;
 
-    /// Returns an `Option` containing a reference to the left child of the node.
     #[inline]
-    pub fn left(&self) -> Option<&Self> {
-        self.left.as_deref()
+    fn deref(&self) -> &Node<T> {
+        let [node] = &*self.0;
+        node
     }
+}
 
-    /// Returns an `Option` containing a reference to the right child of the node.
+impl<T> DerefMut for NodeBox<T> {
     #[inline]
-    pub fn right(&self) -> Option<&Self> {
-        self.right.as_deref()
+    fn deref_mut(&mut self) -> &mut Node<T> {
+        let [node] = &mut *self.0;
+        node
     }
+}
 
-    /// Returns an `Option` containing a mutable reference to the left child of the node.
-    #[inline]
-    pub fn left_mut(&mut self) -> Option<&mut Self> {
-        self.left.as_deref_mut()
+impl<T: fmt::Debug> fmt::Debug for NodeBox<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Node::fmt(self, f)
     }
+}
 
-    /// Returns an `Option` containing a mutable reference to the right child of the node.
-    #[inline]
-    pub fn right_mut(&mut self) -> Option<&mut Self> {
-        self.right.as_deref_mut()
+impl<T: Clone> Clone for NodeBox<T> {
+    fn clone(&self) -> Self {
+        Self::new(Node::clone(self))
     }
+}
 
-    /// Creates a new `Node` from the provided value, and set it as the left child of `self`.
-    #[inline]
-    pub fn set_left(&mut self, value: T) {
-        self.left = Some(Box::new(Node::new(value)));
+impl<T: PartialEq> PartialEq for NodeBox<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Node::eq(self, other)
     }
+}
 
-    /// Creates a new `Node` from the provided value, and set it as the right child of `self`.
-    #[inline]
-    pub fn set_right(&mut self, value: T) {
-        self.right = Some(Box::new(Node::new(value)));
+impl<T: Eq> Eq for NodeBox<T> {}
+
+impl<T: Hash> Hash for NodeBox<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Node::hash(self, state);
+    }
+}
+
+#[cfg(feature = "json")]
+impl<T: Serialize> Serialize for NodeBox<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Node::serialize(self, serializer)
+    }
+}
+
+#[cfg(feature = "json")]
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for NodeBox<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Node::deserialize(deserializer).map(Self::new)
     }
 }
 
 #[cfg(test)]
 mod node {
-    use super::Node;
+    use super::{Node, NodeBox};
+
+    #[test]
+    fn boxes_the_node_fallibly() {
+        let node = NodeBox::try_new(Node::new(5)).unwrap_or_else(NodeBox::new);
+
+        assert_eq!(*node, Node::new(5));
+        assert_eq!(node.into_inner().value, 5);
+    }
 
     #[test]
     fn gets_the_value() {
@@ -611,7 +1300,7 @@ mod node {
     fn gets_left_child_that_is_some() {
         let node = Node {
             value: 0,
-            left: Some(Box::new(Node {
+            left: Some(NodeBox::new(Node {
                 value: 5,
                 left: None,
                 right: None,
@@ -644,7 +1333,7 @@ mod node {
         let node = Node {
             value: 0,
             left: None,
-            right: Some(Box::new(Node {
+            right: Some(NodeBox::new(Node {
                 value: 5,
                 left: None,
                 right: None,
@@ -675,7 +1364,7 @@ mod node {
     fn gets_mut_left_child_that_is_some() {
         let mut node = Node {
             value: 0,
-            left: Some(Box::new(Node {
+            left: Some(NodeBox::new(Node {
                 value: -1,
                 left: None,
                 right: None,
@@ -708,7 +1397,7 @@ mod node {
         let mut node = Node {
             value: 0,
             left: None,
-            right: Some(Box::new(Node {
+            right: Some(NodeBox::new(Node {
                 value: 1,
                 left: None,
                 right: None,
@@ -732,7 +1421,7 @@ mod node {
         };
         let expected = Node {
             value: 0,
-            left: Some(Box::new(Node {
+            left: Some(NodeBox::new(Node {
                 value: -1,
                 left: None,
                 right: None,
@@ -754,7 +1443,7 @@ mod node {
         let expected = Node {
             value: 0,
             left: None,
-            right: Some(Box::new(Node {
+            right: Some(NodeBox::new(Node {
                 value: 1,
                 left: None,
                 right: None,
@@ -764,17 +1453,174 @@ mod node {
         node.set_right(1);
         assert_eq!(node, expected);
     }
+
+    #[test]
+    fn builds_balanced_tree_from_sorted_values() {
+        let expected = Node {
+            value: 4,
+            left: Some(NodeBox::new(Node {
+                value: 2,
+                left: Some(NodeBox::new(Node {
+                    value: 1,
+                    left: None,
+                    right: None,
+                })),
+                right: Some(NodeBox::new(Node {
+                    value: 3,
+                    left: None,
+                    right: None,
+                })),
+            })),
+            right: Some(NodeBox::new(Node {
+                value: 6,
+                left: Some(NodeBox::new(Node {
+                    value: 5,
+                    left: None,
+                    right: None,
+                })),
+                right: None,
+            })),
+        };
+
+        assert_eq!(
+            Node::from_sorted(vec![1, 2, 3, 4, 5, 6]),
+            Some(NodeBox::new(expected))
+        );
+        assert_eq!(Node::<i32>::from_sorted(Vec::new()), None);
+    }
+
+    #[test]
+    fn drains_values_in_ascending_order() {
+        let node = Node {
+            value: 10,
+            left: Some(NodeBox::new(Node {
+                value: 0,
+                left: None,
+                right: Some(NodeBox::new(Node {
+                    value: 5,
+                    left: Some(NodeBox::new(Node {
+                        value: 3,
+                        left: None,
+                        right: None,
+                    })),
+                    right: None,
+                })),
+            })),
+            right: Some(NodeBox::new(Node {
+                value: 15,
+                left: None,
+                right: None,
+            })),
+        };
+        let mut values = Vec::new();
+
+        Node::drain_sorted(Some(NodeBox::new(node)), &mut values);
+        assert_eq!(values, vec![0, 3, 5, 10, 15]);
+    }
+
+    #[test]
+    fn removes_leaf() {
+        let mut root = Node::from_sorted(vec![1, 2, 3]);
+        let expected = Some(NodeBox::new(Node {
+            value: 2,
+            left: Some(NodeBox::new(Node {
+                value: 1,
+                left: None,
+                right: None,
+            })),
+            right: None,
+        }));
+
+        assert_eq!(Node::remove(&mut root, &3), Some(3));
+        assert_eq!(root, expected);
+    }
+
+    #[test]
+    fn removes_node_with_one_child() {
+        let mut root = Some(NodeBox::new(Node {
+            value: 1,
+            left: None,
+            right: Some(NodeBox::new(Node {
+                value: 2,
+                left: None,
+                right: None,
+            })),
+        }));
+        let expected = Some(NodeBox::new(Node {
+            value: 2,
+            left: None,
+            right: None,
+        }));
+
+        assert_eq!(Node::remove(&mut root, &1), Some(1));
+        assert_eq!(root, expected);
+    }
+
+    #[test]
+    fn removes_node_with_two_children_using_successor() {
+        let mut root = Some(NodeBox::new(Node {
+            value: 5,
+            left: Some(NodeBox::new(Node {
+                value: 2,
+                left: None,
+                right: None,
+            })),
+            right: Some(NodeBox::new(Node {
+                value: 9,
+                left: Some(NodeBox::new(Node {
+                    value: 7,
+                    left: None,
+                    right: Some(NodeBox::new(Node {
+                        value: 8,
+                        left: None,
+                        right: None,
+                    })),
+                })),
+                right: None,
+            })),
+        }));
+        let expected = Some(NodeBox::new(Node {
+            value: 7,
+            left: Some(NodeBox::new(Node {
+                value: 2,
+                left: None,
+                right: None,
+            })),
+            right: Some(NodeBox::new(Node {
+                value: 9,
+                left: Some(NodeBox::new(Node {
+                    value: 8,
+                    left: None,
+                    right: None,
+                })),
+                right: None,
+            })),
+        }));
+
+        assert_eq!(Node::remove(&mut root, &5), Some(5));
+        assert_eq!(root, expected);
+    }
+
+    #[test]
+    fn remove_of_missing_value_returns_none() {
+        let mut root = Node::from_sorted(vec![1, 2, 3]);
+        let expected = root.clone();
+
+        assert_eq!(Node::remove(&mut root, &4), None);
+        assert_eq!(root, expected);
+    }
 }
 
 #[cfg(test)]
 mod getters {
-    use super::{BinaryTree, Node};
+    use super::{BinaryTree, Node, NodeBox};
 
     #[test]
     fn count() {
         let tree: BinaryTree<()> = BinaryTree {
             root: None,
             count: 5,
+            max_len: None,
         };
         let expected = 5;
         assert_eq!(tree.count(), expected);
@@ -783,16 +1629,18 @@ mod getters {
     #[test]
     fn is_empty() {
         let tree = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 5,
                 left: None,
                 right: None,
             })),
             count: 1,
+            max_len: None,
         };
         let empty_tree: BinaryTree<()> = BinaryTree {
             root: None,
             count: 0,
+            max_len: None,
         };
 
         assert!(!tree.is_empty());
@@ -802,16 +1650,18 @@ mod getters {
     #[test]
     fn clear() {
         let mut tree = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 5,
                 left: None,
                 right: None,
             })),
             count: 1,
+            max_len: None,
         };
         let expected: BinaryTree<i32> = BinaryTree {
             root: None,
             count: 0,
+            max_len: None,
         };
 
         assert_ne!(tree, expected);
@@ -824,16 +1674,18 @@ mod getters {
     #[test]
     fn root() {
         let tree = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 5,
                 left: None,
                 right: None,
             })),
             count: 1,
+            max_len: None,
         };
         let empty_tree: BinaryTree<()> = BinaryTree {
             root: None,
             count: 0,
+            max_len: None,
         };
 
         assert_eq!(tree.root(), Some(&5));
@@ -843,13 +1695,14 @@ mod getters {
 
 #[cfg(test)]
 mod height {
-    use super::{BinaryTree, Node};
+    use super::{BinaryTree, Node, NodeBox};
 
     #[test]
     fn empty_tree_is_0() {
         let tree: BinaryTree<()> = BinaryTree {
             root: None,
             count: 0,
+            max_len: None,
         };
         let expected = 0;
         assert_eq!(tree.height(), expected);
@@ -858,20 +1711,21 @@ mod height {
     #[test]
     fn three_element_balanced_tree_is_2() {
         let tree = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 2,
-                left: Some(Box::new(Node {
+                left: Some(NodeBox::new(Node {
                     value: 1,
                     left: None,
                     right: None,
                 })),
-                right: Some(Box::new(Node {
+                right: Some(NodeBox::new(Node {
                     value: 3,
                     left: None,
                     right: None,
                 })),
             })),
             count: 3,
+            max_len: None,
         };
         let expected = 2;
         let actual = tree.height();
@@ -881,19 +1735,19 @@ mod height {
     #[test]
     fn unbalanced_right_tree_is_5() {
         let tree = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 1,
                 left: None,
-                right: Some(Box::new(Node {
+                right: Some(NodeBox::new(Node {
                     value: 2,
                     left: None,
-                    right: Some(Box::new(Node {
+                    right: Some(NodeBox::new(Node {
                         value: 3,
                         left: None,
-                        right: Some(Box::new(Node {
+                        right: Some(NodeBox::new(Node {
                             value: 4,
                             left: None,
-                            right: Some(Box::new(Node {
+                            right: Some(NodeBox::new(Node {
                                 value: 5,
                                 left: None,
                                 right: None,
@@ -903,6 +1757,7 @@ mod height {
                 })),
             })),
             count: 5,
+            max_len: None,
         };
         let expected = 5;
         let actual = tree.height();
@@ -912,15 +1767,15 @@ mod height {
     #[test]
     fn unbalanced_left_tree_is_5() {
         let tree = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 5,
-                left: Some(Box::new(Node {
+                left: Some(NodeBox::new(Node {
                     value: 4,
-                    left: Some(Box::new(Node {
+                    left: Some(NodeBox::new(Node {
                         value: 3,
-                        left: Some(Box::new(Node {
+                        left: Some(NodeBox::new(Node {
                             value: 2,
-                            left: Some(Box::new(Node {
+                            left: Some(NodeBox::new(Node {
                                 value: 1,
                                 left: None,
                                 right: None,
@@ -934,6 +1789,7 @@ mod height {
                 right: None,
             })),
             count: 5,
+            max_len: None,
         };
         let expected = 5;
         let actual = tree.height();
@@ -943,29 +1799,29 @@ mod height {
     #[test]
     fn seven_element_balanced_tree_is_3() {
         let tree = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 50,
-                left: Some(Box::new(Node {
+                left: Some(NodeBox::new(Node {
                     value: 25,
-                    left: Some(Box::new(Node {
+                    left: Some(NodeBox::new(Node {
                         value: 13,
                         left: None,
                         right: None,
                     })),
-                    right: Some(Box::new(Node {
+                    right: Some(NodeBox::new(Node {
                         value: 37,
                         left: None,
                         right: None,
                     })),
                 })),
-                right: Some(Box::new(Node {
+                right: Some(NodeBox::new(Node {
                     value: 75,
-                    left: Some(Box::new(Node {
+                    left: Some(NodeBox::new(Node {
                         value: 63,
                         left: None,
                         right: None,
                     })),
-                    right: Some(Box::new(Node {
+                    right: Some(NodeBox::new(Node {
                         value: 87,
                         left: None,
                         right: None,
@@ -973,6 +1829,7 @@ mod height {
                 })),
             })),
             count: 7,
+            max_len: None,
         };
         let expected = 3;
         let actual = tree.height();
@@ -982,18 +1839,19 @@ mod height {
 
 #[cfg(test)]
 mod insert {
-    use super::{BinaryTree, Node};
+    use super::{BinaryTree, Node, NodeBox};
 
     #[test]
     fn insert_one_element_that_becomes_root() {
         let mut tree = BinaryTree::new();
         let expected = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 5,
                 left: None,
                 right: None,
             })),
             count: 1,
+            max_len: None,
         };
         tree.insert(5);
         assert_eq!(tree, expected);
@@ -1003,9 +1861,9 @@ mod insert {
     fn inserts_two_elements_second_is_left_child() {
         let mut tree = BinaryTree::new();
         let expected = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 5,
-                left: Some(Box::new(Node {
+                left: Some(NodeBox::new(Node {
                     value: 4,
                     left: None,
                     right: None,
@@ -1013,6 +1871,7 @@ mod insert {
                 right: None,
             })),
             count: 2,
+            max_len: None,
         };
         tree.insert(5);
         tree.insert(4);
@@ -1023,16 +1882,17 @@ mod insert {
     fn inserts_two_elements_second_is_right_child() {
         let mut tree = BinaryTree::new();
         let expected = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 5,
                 left: None,
-                right: Some(Box::new(Node {
+                right: Some(NodeBox::new(Node {
                     value: 6,
                     left: None,
                     right: None,
                 })),
             })),
             count: 2,
+            max_len: None,
         };
         tree.insert(5);
         tree.insert(6);
@@ -1043,12 +1903,13 @@ mod insert {
     fn discards_duplicate_inserts_of_root() {
         let mut tree = BinaryTree::new();
         let expected = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 5,
                 left: None,
                 right: None,
             })),
             count: 1,
+            max_len: None,
         };
         tree.insert(5);
         tree.insert(5);
@@ -1059,13 +1920,13 @@ mod insert {
     fn inserts_three_elements_second_and_third_are_right_children() {
         let mut tree = BinaryTree::new();
         let expected = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 1,
                 left: None,
-                right: Some(Box::new(Node {
+                right: Some(NodeBox::new(Node {
                     value: 2,
                     left: None,
-                    right: Some(Box::new(Node {
+                    right: Some(NodeBox::new(Node {
                         value: 3,
                         left: None,
                         right: None,
@@ -1073,6 +1934,7 @@ mod insert {
                 })),
             })),
             count: 3,
+            max_len: None,
         };
         tree.insert(1);
         tree.insert(2);
@@ -1084,13 +1946,13 @@ mod insert {
     fn discards_duplicates_of_right_children() {
         let mut tree = BinaryTree::new();
         let expected = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 1,
                 left: None,
-                right: Some(Box::new(Node {
+                right: Some(NodeBox::new(Node {
                     value: 2,
                     left: None,
-                    right: Some(Box::new(Node {
+                    right: Some(NodeBox::new(Node {
                         value: 3,
                         left: None,
                         right: None,
@@ -1098,6 +1960,7 @@ mod insert {
                 })),
             })),
             count: 3,
+            max_len: None,
         };
         tree.insert(1);
         tree.insert(2);
@@ -1112,11 +1975,11 @@ mod insert {
     fn inserts_three_elements_second_and_third_are_left_children() {
         let mut tree = BinaryTree::new();
         let expected = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 3,
-                left: Some(Box::new(Node {
+                left: Some(NodeBox::new(Node {
                     value: 2,
-                    left: Some(Box::new(Node {
+                    left: Some(NodeBox::new(Node {
                         value: 1,
                         left: None,
                         right: None,
@@ -1126,6 +1989,7 @@ mod insert {
                 right: None,
             })),
             count: 3,
+            max_len: None,
         };
         tree.insert(3);
         tree.insert(2);
@@ -1137,11 +2001,11 @@ mod insert {
     fn discards_duplicates_of_left_children() {
         let mut tree = BinaryTree::new();
         let expected = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 3,
-                left: Some(Box::new(Node {
+                left: Some(NodeBox::new(Node {
                     value: 2,
-                    left: Some(Box::new(Node {
+                    left: Some(NodeBox::new(Node {
                         value: 1,
                         left: None,
                         right: None,
@@ -1151,6 +2015,7 @@ mod insert {
                 right: None,
             })),
             count: 3,
+            max_len: None,
         };
         tree.insert(3);
         tree.insert(2);
@@ -1165,14 +2030,14 @@ mod insert {
     fn inserts_four_elements_zig_zag_starting_left() {
         let mut tree = BinaryTree::new();
         let expected = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 10,
-                left: Some(Box::new(Node {
+                left: Some(NodeBox::new(Node {
                     value: 0,
                     left: None,
-                    right: Some(Box::new(Node {
+                    right: Some(NodeBox::new(Node {
                         value: 5,
-                        left: Some(Box::new(Node {
+                        left: Some(NodeBox::new(Node {
                             value: 3,
                             left: None,
                             right: None,
@@ -1183,6 +2048,7 @@ mod insert {
                 right: None,
             })),
             count: 4,
+            max_len: None,
         };
         tree.insert(10);
         tree.insert(0);
@@ -1195,15 +2061,15 @@ mod insert {
     fn inserts_four_elements_zig_zag_starting_right() {
         let mut tree = BinaryTree::new();
         let expected = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 0,
                 left: None,
-                right: Some(Box::new(Node {
+                right: Some(NodeBox::new(Node {
                     value: 10,
-                    left: Some(Box::new(Node {
+                    left: Some(NodeBox::new(Node {
                         value: 3,
                         left: None,
-                        right: Some(Box::new(Node {
+                        right: Some(NodeBox::new(Node {
                             value: 5,
                             left: None,
                             right: None,
@@ -1213,6 +2079,7 @@ mod insert {
                 })),
             })),
             count: 4,
+            max_len: None,
         };
         tree.insert(0);
         tree.insert(10);
@@ -1226,20 +2093,21 @@ mod insert {
         let mut tree1 = BinaryTree::new();
         let mut tree2 = BinaryTree::new();
         let expected = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 2,
-                left: Some(Box::new(Node {
+                left: Some(NodeBox::new(Node {
                     value: 1,
                     left: None,
                     right: None,
                 })),
-                right: Some(Box::new(Node {
+                right: Some(NodeBox::new(Node {
                     value: 3,
                     left: None,
                     right: None,
                 })),
             })),
             count: 3,
+            max_len: None,
         };
 
         // Creating left child first.
@@ -1260,29 +2128,29 @@ mod insert {
     fn creates_three_layer_tree_one_layer_at_a_time() {
         let mut tree = BinaryTree::new();
         let expected = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 50,
-                left: Some(Box::new(Node {
+                left: Some(NodeBox::new(Node {
                     value: 25,
-                    left: Some(Box::new(Node {
+                    left: Some(NodeBox::new(Node {
                         value: 13,
                         left: None,
                         right: None,
                     })),
-                    right: Some(Box::new(Node {
+                    right: Some(NodeBox::new(Node {
                         value: 37,
                         left: None,
                         right: None,
                     })),
                 })),
-                right: Some(Box::new(Node {
+                right: Some(NodeBox::new(Node {
                     value: 75,
-                    left: Some(Box::new(Node {
+                    left: Some(NodeBox::new(Node {
                         value: 63,
                         left: None,
                         right: None,
                     })),
-                    right: Some(Box::new(Node {
+                    right: Some(NodeBox::new(Node {
                         value: 87,
                         left: None,
                         right: None,
@@ -1290,6 +2158,7 @@ mod insert {
                 })),
             })),
             count: 7,
+            max_len: None,
         };
 
         tree.insert(50);
@@ -1319,13 +2188,14 @@ mod insert {
 
 #[cfg(test)]
 mod min {
-    use super::{BinaryTree, Node};
+    use super::{BinaryTree, Node, NodeBox};
 
     #[test]
     fn empty_tree_returns_none() {
         let tree: BinaryTree<i32> = BinaryTree {
             root: None,
             count: 0,
+            max_len: None,
         };
 
         assert_eq!(tree.min(), None);
@@ -1334,12 +2204,13 @@ mod min {
     #[test]
     fn tree_with_only_root_returns_root() {
         let tree = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 5,
                 left: None,
                 right: None,
             })),
             count: 0,
+            max_len: None,
         };
 
         assert_eq!(tree.min(), Some(&5));
@@ -1348,16 +2219,17 @@ mod min {
     #[test]
     fn tree_with_root_and_right_child_returns_root() {
         let tree = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 5,
                 left: None,
-                right: Some(Box::new(Node {
+                right: Some(NodeBox::new(Node {
                     value: 6,
                     left: None,
                     right: None,
                 })),
             })),
             count: 0,
+            max_len: None,
         };
 
         assert_eq!(tree.min(), Some(&5));
@@ -1366,9 +2238,9 @@ mod min {
     #[test]
     fn tree_with_root_and_left_child_returns_left() {
         let tree = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 5,
-                left: Some(Box::new(Node {
+                left: Some(NodeBox::new(Node {
                     value: 4,
                     left: None,
                     right: None,
@@ -1376,6 +2248,7 @@ mod min {
                 right: None,
             })),
             count: 0,
+            max_len: None,
         };
 
         assert_eq!(tree.min(), Some(&4));
@@ -1384,20 +2257,21 @@ mod min {
     #[test]
     fn tree_with_root_and_both_childen_returns_left() {
         let tree = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 5,
-                left: Some(Box::new(Node {
+                left: Some(NodeBox::new(Node {
                     value: 4,
                     left: None,
                     right: None,
                 })),
-                right: Some(Box::new(Node {
+                right: Some(NodeBox::new(Node {
                     value: 6,
                     left: None,
                     right: None,
                 })),
             })),
             count: 0,
+            max_len: None,
         };
 
         assert_eq!(tree.min(), Some(&4));
@@ -1406,16 +2280,16 @@ mod min {
     #[test]
     fn tree_with_root_and_multiple_right_returns_root() {
         let tree = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 5,
                 left: None,
-                right: Some(Box::new(Node {
+                right: Some(NodeBox::new(Node {
                     value: 6,
                     left: None,
-                    right: Some(Box::new(Node {
+                    right: Some(NodeBox::new(Node {
                         value: 7,
                         left: None,
-                        right: Some(Box::new(Node {
+                        right: Some(NodeBox::new(Node {
                             value: 8,
                             left: None,
                             right: None,
@@ -1424,6 +2298,7 @@ mod min {
                 })),
             })),
             count: 0,
+            max_len: None,
         };
 
         assert_eq!(tree.min(), Some(&5));
@@ -1432,13 +2307,13 @@ mod min {
     #[test]
     fn tree_with_root_and_multiple_left_returns_left_most_child() {
         let tree = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 5,
-                left: Some(Box::new(Node {
+                left: Some(NodeBox::new(Node {
                     value: 4,
-                    left: Some(Box::new(Node {
+                    left: Some(NodeBox::new(Node {
                         value: 3,
-                        left: Some(Box::new(Node {
+                        left: Some(NodeBox::new(Node {
                             value: 2,
                             left: None,
                             right: None,
@@ -1450,6 +2325,7 @@ mod min {
                 right: None,
             })),
             count: 0,
+            max_len: None,
         };
 
         assert_eq!(tree.min(), Some(&2));
@@ -1458,29 +2334,29 @@ mod min {
     #[test]
     fn balanced_tree_returns_left_most_child() {
         let tree = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 50,
-                left: Some(Box::new(Node {
+                left: Some(NodeBox::new(Node {
                     value: 25,
-                    left: Some(Box::new(Node {
+                    left: Some(NodeBox::new(Node {
                         value: 13,
                         left: None,
                         right: None,
                     })),
-                    right: Some(Box::new(Node {
+                    right: Some(NodeBox::new(Node {
                         value: 37,
                         left: None,
                         right: None,
                     })),
                 })),
-                right: Some(Box::new(Node {
+                right: Some(NodeBox::new(Node {
                     value: 75,
-                    left: Some(Box::new(Node {
+                    left: Some(NodeBox::new(Node {
                         value: 63,
                         left: None,
                         right: None,
                     })),
-                    right: Some(Box::new(Node {
+                    right: Some(NodeBox::new(Node {
                         value: 87,
                         left: None,
                         right: None,
@@ -1488,6 +2364,7 @@ mod min {
                 })),
             })),
             count: 7,
+            max_len: None,
         };
 
         assert_eq!(tree.min(), Some(&13));
@@ -1496,13 +2373,14 @@ mod min {
 
 #[cfg(test)]
 mod max {
-    use super::{BinaryTree, Node};
+    use super::{BinaryTree, Node, NodeBox};
 
     #[test]
     fn empty_tree_returns_none() {
         let tree: BinaryTree<i32> = BinaryTree {
             root: None,
             count: 0,
+            max_len: None,
         };
 
         assert_eq!(tree.max(), None);
@@ -1511,12 +2389,13 @@ mod max {
     #[test]
     fn tree_with_only_root_returns_root() {
         let tree = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 5,
                 left: None,
                 right: None,
             })),
             count: 0,
+            max_len: None,
         };
 
         assert_eq!(tree.max(), Some(&5));
@@ -1525,16 +2404,17 @@ mod max {
     #[test]
     fn tree_with_root_and_right_child_returns_right() {
         let tree = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 5,
                 left: None,
-                right: Some(Box::new(Node {
+                right: Some(NodeBox::new(Node {
                     value: 6,
                     left: None,
                     right: None,
                 })),
             })),
             count: 0,
+            max_len: None,
         };
 
         assert_eq!(tree.max(), Some(&6));
@@ -1543,9 +2423,9 @@ mod max {
     #[test]
     fn tree_with_root_and_left_child_returns_root() {
         let tree = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 5,
-                left: Some(Box::new(Node {
+                left: Some(NodeBox::new(Node {
                     value: 4,
                     left: None,
                     right: None,
@@ -1553,6 +2433,7 @@ mod max {
                 right: None,
             })),
             count: 0,
+            max_len: None,
         };
 
         assert_eq!(tree.max(), Some(&5));
@@ -1561,20 +2442,21 @@ mod max {
     #[test]
     fn tree_with_root_and_both_childen_returns_right() {
         let tree = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 5,
-                left: Some(Box::new(Node {
+                left: Some(NodeBox::new(Node {
                     value: 4,
                     left: None,
                     right: None,
                 })),
-                right: Some(Box::new(Node {
+                right: Some(NodeBox::new(Node {
                     value: 6,
                     left: None,
                     right: None,
                 })),
             })),
             count: 0,
+            max_len: None,
         };
 
         assert_eq!(tree.max(), Some(&6));
@@ -1583,16 +2465,16 @@ mod max {
     #[test]
     fn tree_with_root_and_multiple_right_returns_right_most_child() {
         let tree = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 5,
                 left: None,
-                right: Some(Box::new(Node {
+                right: Some(NodeBox::new(Node {
                     value: 6,
                     left: None,
-                    right: Some(Box::new(Node {
+                    right: Some(NodeBox::new(Node {
                         value: 7,
                         left: None,
-                        right: Some(Box::new(Node {
+                        right: Some(NodeBox::new(Node {
                             value: 8,
                             left: None,
                             right: None,
@@ -1601,6 +2483,7 @@ mod max {
                 })),
             })),
             count: 0,
+            max_len: None,
         };
 
         assert_eq!(tree.max(), Some(&8));
@@ -1609,13 +2492,13 @@ mod max {
     #[test]
     fn tree_with_root_and_multiple_left_returns_root() {
         let tree = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 5,
-                left: Some(Box::new(Node {
+                left: Some(NodeBox::new(Node {
                     value: 4,
-                    left: Some(Box::new(Node {
+                    left: Some(NodeBox::new(Node {
                         value: 3,
-                        left: Some(Box::new(Node {
+                        left: Some(NodeBox::new(Node {
                             value: 2,
                             left: None,
                             right: None,
@@ -1627,6 +2510,7 @@ mod max {
                 right: None,
             })),
             count: 0,
+            max_len: None,
         };
 
         assert_eq!(tree.max(), Some(&5));
@@ -1635,29 +2519,29 @@ mod max {
     #[test]
     fn balanced_tree_returns_right_most_child() {
         let tree = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 50,
-                left: Some(Box::new(Node {
+                left: Some(NodeBox::new(Node {
                     value: 25,
-                    left: Some(Box::new(Node {
+                    left: Some(NodeBox::new(Node {
                         value: 13,
                         left: None,
                         right: None,
                     })),
-                    right: Some(Box::new(Node {
+                    right: Some(NodeBox::new(Node {
                         value: 37,
                         left: None,
                         right: None,
                     })),
                 })),
-                right: Some(Box::new(Node {
+                right: Some(NodeBox::new(Node {
                     value: 75,
-                    left: Some(Box::new(Node {
+                    left: Some(NodeBox::new(Node {
                         value: 63,
                         left: None,
                         right: None,
                     })),
-                    right: Some(Box::new(Node {
+                    right: Some(NodeBox::new(Node {
                         value: 87,
                         left: None,
                         right: None,
@@ -1665,21 +2549,61 @@ mod max {
                 })),
             })),
             count: 7,
+            max_len: None,
         };
 
         assert_eq!(tree.max(), Some(&87));
     }
 }
 
+#[cfg(test)]
+mod pop {
+    use super::BinaryTree;
+
+    #[test]
+    fn empty_tree_returns_none() {
+        let mut tree: BinaryTree<i32> = BinaryTree::new();
+
+        assert_eq!(tree.pop_min(), None);
+        assert_eq!(tree.pop_max(), None);
+        assert_eq!(tree.count(), 0);
+    }
+
+    #[test]
+    fn keeps_the_subtree_of_the_popped_element() {
+        let mut tree = BinaryTree::from(vec![8, 2, 12, 4, 3, 10, 9]);
+
+        assert_eq!(tree.pop_min(), Some(2));
+        assert_eq!(tree.pop_max(), Some(12));
+        assert!(tree.in_order().eq(&[3, 4, 8, 9, 10]));
+        assert_eq!(tree.count(), 5);
+    }
+
+    #[test]
+    fn drains_in_order_from_both_ends() {
+        let mut tree = BinaryTree::from(vec![5, 3, 8, 1, 4, 7, 9]);
+        let mut drained = Vec::new();
+
+        while let Some(min) = tree.pop_min() {
+            drained.push(min);
+            drained.extend(tree.pop_max());
+        }
+
+        assert_eq!(drained, vec![1, 9, 3, 8, 4, 7, 5]);
+        assert!(tree.is_empty());
+    }
+}
+
 #[cfg(test)]
 mod contains {
-    use super::{BinaryTree, Node};
+    use super::{BinaryTree, Node, NodeBox};
 
     #[test]
     fn empty_tree_returns_false() {
         let tree: BinaryTree<i32> = BinaryTree {
             root: None,
             count: 0,
+            max_len: None,
         };
 
         assert!(!tree.contains(&0));
@@ -1688,12 +2612,13 @@ mod contains {
     #[test]
     fn root_is_target_returns_true() {
         let tree = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 0,
                 left: None,
                 right: None,
             })),
             count: 0,
+            max_len: None,
         };
 
         assert!(tree.contains(&0));
@@ -1702,12 +2627,13 @@ mod contains {
     #[test]
     fn root_is_not_target_returns_false() {
         let tree = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 0,
                 left: None,
                 right: None,
             })),
             count: 0,
+            max_len: None,
         };
 
         assert!(!tree.contains(&1));
@@ -1716,13 +2642,13 @@ mod contains {
     #[test]
     fn left_most_is_target_returns_true() {
         let tree = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 5,
-                left: Some(Box::new(Node {
+                left: Some(NodeBox::new(Node {
                     value: 4,
-                    left: Some(Box::new(Node {
+                    left: Some(NodeBox::new(Node {
                         value: 3,
-                        left: Some(Box::new(Node {
+                        left: Some(NodeBox::new(Node {
                             value: 2,
                             left: None,
                             right: None,
@@ -1734,6 +2660,7 @@ mod contains {
                 right: None,
             })),
             count: 0,
+            max_len: None,
         };
 
         assert!(tree.contains(&2));
@@ -1742,16 +2669,16 @@ mod contains {
     #[test]
     fn right_most_is_target_returns_true() {
         let tree = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 5,
                 left: None,
-                right: Some(Box::new(Node {
+                right: Some(NodeBox::new(Node {
                     value: 6,
                     left: None,
-                    right: Some(Box::new(Node {
+                    right: Some(NodeBox::new(Node {
                         value: 7,
                         left: None,
-                        right: Some(Box::new(Node {
+                        right: Some(NodeBox::new(Node {
                             value: 8,
                             left: None,
                             right: None,
@@ -1760,34 +2687,92 @@ mod contains {
                 })),
             })),
             count: 0,
+            max_len: None,
         };
 
         assert!(tree.contains(&8));
     }
 }
 
+#[cfg(test)]
+mod remove {
+    use super::{BinaryTree, Node, NodeBox};
+
+    #[test]
+    fn empty_tree_returns_none() {
+        let mut tree: BinaryTree<i32> = BinaryTree::new();
+
+        assert_eq!(tree.remove(&0), None);
+        assert_eq!(tree.count(), 0);
+    }
+
+    #[test]
+    fn missing_target_leaves_tree_unchanged() {
+        let mut tree = BinaryTree::from(vec![5, 4, 6]);
+        let expected = tree.clone();
+
+        assert_eq!(tree.remove(&7), None);
+        assert_eq!(tree, expected);
+    }
+
+    #[test]
+    fn root_with_two_children_is_replaced_by_successor() {
+        let mut tree = BinaryTree::from(vec![5, 4, 7, 6]);
+        let expected = BinaryTree {
+            root: Some(NodeBox::new(Node {
+                value: 6,
+                left: Some(NodeBox::new(Node {
+                    value: 4,
+                    left: None,
+                    right: None,
+                })),
+                right: Some(NodeBox::new(Node {
+                    value: 7,
+                    left: None,
+                    right: None,
+                })),
+            })),
+            count: 3,
+            max_len: None,
+        };
+
+        assert_eq!(tree.remove(&5), Some(5));
+        assert_eq!(tree, expected);
+    }
+
+    #[test]
+    fn removing_last_element_empties_tree() {
+        let mut tree = BinaryTree::from(vec![5]);
+
+        assert_eq!(tree.remove(&5), Some(5));
+        assert!(tree.is_empty());
+        assert_eq!(tree.count(), 0);
+    }
+}
+
 #[cfg(test)]
 mod iterator_trait_impls {
-    use super::{BinaryTree, Node};
+    use super::{BinaryTree, Node, NodeBox};
 
     #[test]
     fn creates_tree_from_vec() {
         let values = vec![5, 4, 6];
         let expected = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 5,
-                left: Some(Box::new(Node {
+                left: Some(NodeBox::new(Node {
                     value: 4,
                     left: None,
                     right: None,
                 })),
-                right: Some(Box::new(Node {
+                right: Some(NodeBox::new(Node {
                     value: 6,
                     left: None,
                     right: None,
                 })),
             })),
             count: 3,
+            max_len: None,
         };
 
         let tree = BinaryTree::from(values);
@@ -1797,20 +2782,21 @@ mod iterator_trait_impls {
     #[test]
     fn into_iter_from_small_tree() {
         let tree = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 5,
-                left: Some(Box::new(Node {
+                left: Some(NodeBox::new(Node {
                     value: 4,
                     left: None,
                     right: None,
                 })),
-                right: Some(Box::new(Node {
+                right: Some(NodeBox::new(Node {
                     value: 6,
                     left: None,
                     right: None,
                 })),
             })),
             count: 3,
+            max_len: None,
         };
 
         let mut tree_iter = tree.into_iter();
@@ -1824,29 +2810,29 @@ mod iterator_trait_impls {
     #[test]
     fn into_iter_from_large_tree() {
         let tree = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 50,
-                left: Some(Box::new(Node {
+                left: Some(NodeBox::new(Node {
                     value: 25,
-                    left: Some(Box::new(Node {
+                    left: Some(NodeBox::new(Node {
                         value: 13,
                         left: None,
                         right: None,
                     })),
-                    right: Some(Box::new(Node {
+                    right: Some(NodeBox::new(Node {
                         value: 37,
                         left: None,
                         right: None,
                     })),
                 })),
-                right: Some(Box::new(Node {
+                right: Some(NodeBox::new(Node {
                     value: 75,
-                    left: Some(Box::new(Node {
+                    left: Some(NodeBox::new(Node {
                         value: 63,
                         left: None,
                         right: None,
                     })),
-                    right: Some(Box::new(Node {
+                    right: Some(NodeBox::new(Node {
                         value: 87,
                         left: None,
                         right: None,
@@ -1854,6 +2840,7 @@ mod iterator_trait_impls {
                 })),
             })),
             count: 7,
+            max_len: None,
         };
 
         let mut iter = tree.into_iter();
@@ -1872,20 +2859,21 @@ mod iterator_trait_impls {
     fn creates_tree_from_iterator() {
         let tree = BinaryTree::from_iter(vec![5, 4, 6]);
         let expected = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 5,
-                left: Some(Box::new(Node {
+                left: Some(NodeBox::new(Node {
                     value: 4,
                     left: None,
                     right: None,
                 })),
-                right: Some(Box::new(Node {
+                right: Some(NodeBox::new(Node {
                     value: 6,
                     left: None,
                     right: None,
                 })),
             })),
             count: 3,
+            max_len: None,
         };
 
         assert_eq!(tree, expected)
@@ -1894,20 +2882,21 @@ mod iterator_trait_impls {
     #[test]
     fn iter_from_tree() {
         let tree = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 5,
-                left: Some(Box::new(Node {
+                left: Some(NodeBox::new(Node {
                     value: 4,
                     left: None,
                     right: None,
                 })),
-                right: Some(Box::new(Node {
+                right: Some(NodeBox::new(Node {
                     value: 6,
                     left: None,
                     right: None,
                 })),
             })),
             count: 3,
+            max_len: None,
         };
 
         let mut iter = tree.iter();
@@ -1921,29 +2910,29 @@ mod iterator_trait_impls {
     #[test]
     fn iter_from_large_tree() {
         let tree = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 50,
-                left: Some(Box::new(Node {
+                left: Some(NodeBox::new(Node {
                     value: 25,
-                    left: Some(Box::new(Node {
+                    left: Some(NodeBox::new(Node {
                         value: 13,
                         left: None,
                         right: None,
                     })),
-                    right: Some(Box::new(Node {
+                    right: Some(NodeBox::new(Node {
                         value: 37,
                         left: None,
                         right: None,
                     })),
                 })),
-                right: Some(Box::new(Node {
+                right: Some(NodeBox::new(Node {
                     value: 75,
-                    left: Some(Box::new(Node {
+                    left: Some(NodeBox::new(Node {
                         value: 63,
                         left: None,
                         right: None,
                     })),
-                    right: Some(Box::new(Node {
+                    right: Some(NodeBox::new(Node {
                         value: 87,
                         left: None,
                         right: None,
@@ -1951,6 +2940,7 @@ mod iterator_trait_impls {
                 })),
             })),
             count: 7,
+            max_len: None,
         };
 
         let mut iter = tree.iter();
@@ -1968,45 +2958,46 @@ mod iterator_trait_impls {
     #[test]
     fn extends_collection_with_iterator() {
         let mut tree = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 50,
-                left: Some(Box::new(Node {
+                left: Some(NodeBox::new(Node {
                     value: 25,
                     left: None,
                     right: None,
                 })),
-                right: Some(Box::new(Node {
+                right: Some(NodeBox::new(Node {
                     value: 75,
                     left: None,
                     right: None,
                 })),
             })),
             count: 3,
+            max_len: None,
         };
         let expected = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 50,
-                left: Some(Box::new(Node {
+                left: Some(NodeBox::new(Node {
                     value: 25,
-                    left: Some(Box::new(Node {
+                    left: Some(NodeBox::new(Node {
                         value: 13,
                         left: None,
                         right: None,
                     })),
-                    right: Some(Box::new(Node {
+                    right: Some(NodeBox::new(Node {
                         value: 37,
                         left: None,
                         right: None,
                     })),
                 })),
-                right: Some(Box::new(Node {
+                right: Some(NodeBox::new(Node {
                     value: 75,
-                    left: Some(Box::new(Node {
+                    left: Some(NodeBox::new(Node {
                         value: 63,
                         left: None,
                         right: None,
                     })),
-                    right: Some(Box::new(Node {
+                    right: Some(NodeBox::new(Node {
                         value: 87,
                         left: None,
                         right: None,
@@ -2014,17 +3005,173 @@ mod iterator_trait_impls {
                 })),
             })),
             count: 7,
+            max_len: None,
         };
 
         tree.extend([13, 37, 63, 87]);
 
         assert_eq!(tree, expected);
     }
+
+    #[test]
+    fn in_order_yields_ascending_order() {
+        let tree = BinaryTree::from(vec![8, 4, 6, 16, -5, 25]);
+        let mut tree_iter = tree.in_order();
+
+        assert_eq!(tree_iter.len(), 6);
+        assert!(tree_iter.by_ref().copied().take(2).eq([-5, 4]));
+        assert_eq!(tree_iter.len(), 4);
+        assert!(tree_iter.copied().eq([6, 8, 16, 25]));
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod parallel {
+    use super::{BinaryTree, Node};
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use std::cmp::Ordering;
+    use std::num::NonZeroUsize;
+
+    fn threads(count: usize) -> NonZeroUsize {
+        NonZeroUsize::new(count).unwrap_or(NonZeroUsize::MIN)
+    }
+
+    /// Orders by the key alone, so equal values can be told apart by their tag.
+    #[derive(Debug, Clone, Copy)]
+    struct Tagged {
+        key: u8,
+        tag: usize,
+    }
+
+    impl PartialEq for Tagged {
+        fn eq(&self, other: &Self) -> bool {
+            self.key == other.key
+        }
+    }
+
+    impl Eq for Tagged {}
+
+    impl PartialOrd for Tagged {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Tagged {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.key.cmp(&other.key)
+        }
+    }
+
+    #[test]
+    fn builds_same_tree_for_every_thread_count() {
+        let mut rng = StdRng::seed_from_u64(39);
+        let values: Vec<i32> = (0..5000).map(|_| rng.gen_range(-2000..2000)).collect();
+
+        let mut sorted = values.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+        let expected = BinaryTree {
+            count: sorted.len(),
+            root: Node::from_sorted(sorted),
+            max_len: None,
+        };
+
+        for count in 1..=9 {
+            assert_eq!(
+                BinaryTree::par_from_vec(values.clone(), threads(count)),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn keeps_first_of_equal_values() {
+        let values: Vec<_> = (0..1000)
+            .map(|tag| Tagged {
+                key: (tag % 17) as u8,
+                tag,
+            })
+            .collect();
+
+        for count in [1, 2, 3, 8] {
+            let tree = BinaryTree::par_from_vec(values.clone(), threads(count));
+            let tags: Vec<_> = tree.in_order().map(|value| value.tag).collect();
+
+            assert_eq!(tags, (0..17).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn empty_and_tiny_inputs() {
+        for count in [1, 4] {
+            assert!(BinaryTree::<i32>::par_from_vec(Vec::new(), threads(count)).is_empty());
+            assert_eq!(
+                BinaryTree::par_from_vec(vec![1], threads(count)).root(),
+                Some(&1)
+            );
+        }
+    }
+
+    #[test]
+    fn fold_combines_subtrees_in_order() {
+        let tree = BinaryTree::par_from_vec((0..1000).collect(), threads(4));
+        // concatenation is associative but not commutative, so any reordering would show
+        let expected: Vec<i32> = (0..1000).collect();
+
+        for count in 1..=9 {
+            let folded = tree.par_fold(
+                threads(count),
+                Vec::new,
+                |mut values, &value| {
+                    values.push(value);
+                    values
+                },
+                |mut left, right| {
+                    left.extend(right);
+                    left
+                },
+            );
+
+            assert_eq!(folded, expected);
+        }
+    }
+
+    #[test]
+    fn fold_over_unbalanced_tree() {
+        let tree: BinaryTree<u64> = (0..2000).collect();
+
+        let sum = tree.par_fold(threads(8), || 0, |sum, value| sum + value, |a, b| a + b);
+        assert_eq!(sum, 1999 * 2000 / 2);
+        assert_eq!(
+            BinaryTree::<u64>::new().par_fold(threads(8), || 7, |a, _| a, |a, _| a),
+            7
+        );
+    }
+
+    #[test]
+    fn caps_threads_at_available_parallelism() {
+        let available = std::thread::available_parallelism().map_or(1, NonZeroUsize::get);
+        assert_eq!(super::thread_limit(NonZeroUsize::MAX), available);
+        assert_eq!(super::thread_limit(NonZeroUsize::MIN), 1);
+
+        let tree = BinaryTree::par_from_vec((0..10_000).rev().collect(), NonZeroUsize::MAX);
+        assert_eq!(tree.count(), 10_000);
+        assert_eq!(
+            tree.par_fold(
+                NonZeroUsize::MAX,
+                || 0,
+                |sum, value| sum + value,
+                |a, b| a + b
+            ),
+            9999 * 10_000 / 2
+        );
+    }
 }
 
 #[cfg(all(test, feature = "json"))]
 mod json {
-    use super::{BinaryTree, Node};
+    use super::{BinaryTree, Node, NodeBox};
     use rstest::{fixture, rstest};
 
     #[fixture]
@@ -2037,20 +3184,21 @@ mod json {
         let tree: BinaryTree<i32> =
             serde_json::from_str(json_tree).expect("should parse json into tree");
         let expected = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 5,
-                left: Some(Box::new(Node {
+                left: Some(NodeBox::new(Node {
                     value: 4,
                     left: None,
                     right: None,
                 })),
-                right: Some(Box::new(Node {
+                right: Some(NodeBox::new(Node {
                     value: 6,
                     left: None,
                     right: None,
                 })),
             })),
             count: 3,
+            max_len: None,
         };
 
         assert_eq!(tree, expected);
@@ -2059,23 +3207,37 @@ mod json {
     #[rstest]
     fn serialize_tree_into_json(json_tree: &'static str) {
         let tree = BinaryTree {
-            root: Some(Box::new(Node {
+            root: Some(NodeBox::new(Node {
                 value: 5,
-                left: Some(Box::new(Node {
+                left: Some(NodeBox::new(Node {
                     value: 4,
                     left: None,
                     right: None,
                 })),
-                right: Some(Box::new(Node {
+                right: Some(NodeBox::new(Node {
                     value: 6,
                     left: None,
                     right: None,
                 })),
             })),
             count: 3,
+            max_len: None,
         };
         let actual = serde_json::to_string(&tree).expect("should parse tree into json");
 
         assert_eq!(actual, json_tree);
     }
+
+    #[test]
+    fn max_len_round_trips_through_json() {
+        let mut tree = BinaryTree::with_max_len(2);
+        tree.insert(1);
+
+        let json = serde_json::to_string(&tree).expect("should parse tree into json");
+        assert!(json.ends_with(r#""count":1,"max_len":2}"#));
+
+        let actual: BinaryTree<i32> =
+            serde_json::from_str(&json).expect("should parse json into tree");
+        assert_eq!(actual, tree);
+    }
 }
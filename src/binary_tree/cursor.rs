@@ -0,0 +1,714 @@
+use alloc::vec::Vec;
+use core::{mem, ptr};
+
+use super::{BinaryTree, Node, NodeBox};
+
+impl<T> BinaryTree<T> {
+    /// Returns a cursor pointing at the root of the `BinaryTree`.
+    ///
+    /// The cursor points at nothing if the tree is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// let tree = BinaryTree::from(vec![5, 3, 8, 4]);
+    /// let mut cursor = tree.cursor();
+    ///
+    /// assert!(cursor.move_left());
+    /// assert!(cursor.move_right());
+    /// assert_eq!(cursor.current(), Some(&4));
+    ///
+    /// assert!(cursor.move_next());
+    /// assert_eq!(cursor.current(), Some(&5));
+    /// ```
+    #[must_use]
+    pub fn cursor(&self) -> Cursor<'_, T> {
+        Cursor {
+            path: self.root.as_deref().into_iter().collect(),
+        }
+    }
+
+    /// Returns a cursor pointing at the root of the `BinaryTree`, that can insert and remove elements.
+    ///
+    /// The tree is taken apart along the path of the cursor while it moves,
+    /// and put back together when the cursor is dropped. If the cursor is leaked instead,
+    /// the tree is left empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// let mut tree = BinaryTree::from(vec![5, 3, 8]);
+    /// let mut cursor = tree.cursor_mut();
+    ///
+    /// assert_eq!(cursor.insert_after(6), Ok(()));
+    /// assert_eq!(cursor.insert_after(9), Err(9));
+    /// assert_eq!(cursor.remove_current(), Some(5));
+    /// assert_eq!(cursor.current(), Some(&6));
+    ///
+    /// drop(cursor);
+    /// assert!(tree.in_order().eq(&[3, 6, 8]));
+    /// ```
+    #[must_use]
+    pub fn cursor_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            focus: self.root.take(),
+            path: Vec::new(),
+            // the tree counts no elements until the cursor gives them back, in case it is leaked
+            count: mem::take(&mut self.count),
+            tree: self,
+        }
+    }
+}
+
+/// The methods in this implementation block require trait bounds to correctly apply the logic of the methods.
+///
+/// The trait bound in question is [`Ord`], which is used to find the element the cursor starts at.
+impl<T: Ord> BinaryTree<T> {
+    /// Returns a cursor pointing at the element equal to `target`, or `None` if the tree does not contain it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// let tree = BinaryTree::from(vec![5, 3, 8, 4]);
+    /// let mut cursor = tree.cursor_at(&4).unwrap();
+    ///
+    /// assert!(cursor.move_parent());
+    /// assert_eq!(cursor.current(), Some(&3));
+    /// assert!(tree.cursor_at(&7).is_none());
+    /// ```
+    pub fn cursor_at(&self, target: &T) -> Option<Cursor<'_, T>> {
+        let mut cursor = self.cursor();
+        while let Some(current) = cursor.current() {
+            let moved = match target.cmp(current) {
+                core::cmp::Ordering::Equal => return Some(cursor),
+                core::cmp::Ordering::Less => cursor.move_left(),
+                core::cmp::Ordering::Greater => cursor.move_right(),
+            };
+            if !moved {
+                break;
+            }
+        }
+
+        None
+    }
+
+    /// Returns a mutable cursor pointing at the element equal to `target`, or `None` if the tree does not contain it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// let mut tree = BinaryTree::from(vec![5, 3, 8]);
+    ///
+    /// if let Some(mut cursor) = tree.cursor_mut_at(&3) {
+    ///     assert_eq!(cursor.insert_after(4), Ok(()));
+    /// }
+    /// assert!(tree.in_order().eq(&[3, 4, 5, 8]));
+    /// ```
+    pub fn cursor_mut_at(&mut self, target: &T) -> Option<CursorMut<'_, T>> {
+        let mut cursor = self.cursor_mut();
+        while let Some(current) = cursor.current() {
+            let moved = match target.cmp(current) {
+                core::cmp::Ordering::Equal => return Some(cursor),
+                core::cmp::Ordering::Less => cursor.move_left(),
+                core::cmp::Ordering::Greater => cursor.move_right(),
+            };
+            if !moved {
+                break;
+            }
+        }
+
+        None
+    }
+}
+
+/// A cursor over a `BinaryTree`, which can move between the elements of the tree.
+///
+/// This `struct` is created by the `cursor` and `cursor_at` methods on [`BinaryTree`].
+pub struct Cursor<'a, T> {
+    /// The nodes from the root to the current node, which is the last one.
+    path: Vec<&'a Node<T>>,
+}
+
+impl<'a, T> Clone for Cursor<'a, T> {
+    fn clone(&self) -> Self {
+        Self {
+            path: self.path.clone(),
+        }
+    }
+}
+
+impl<'a, T> Cursor<'a, T> {
+    /// Returns a reference to the element the cursor points at, or `None` if the tree is empty.
+    #[inline]
+    pub fn current(&self) -> Option<&'a T> {
+        self.path.last().map(|node| node.value())
+    }
+
+    /// Returns the number of edges between the root and the element the cursor points at.
+    #[inline]
+    pub fn depth(&self) -> usize {
+        self.path.len().saturating_sub(1)
+    }
+
+    /// Moves the cursor to the left child of the current element, and returns `true` if it exists.
+    pub fn move_left(&mut self) -> bool {
+        self.descend(Node::left)
+    }
+
+    /// Moves the cursor to the right child of the current element, and returns `true` if it exists.
+    pub fn move_right(&mut self) -> bool {
+        self.descend(Node::right)
+    }
+
+    /// Moves the cursor to the parent of the current element, and returns `true` if it exists.
+    pub fn move_parent(&mut self) -> bool {
+        if self.path.len() < 2 {
+            return false;
+        }
+        self.path.pop();
+
+        true
+    }
+
+    /// Moves the cursor to the next element in ascending order, and returns `true` if it exists.
+    ///
+    /// The cursor stays where it is if the current element is the largest.
+    pub fn move_next(&mut self) -> bool {
+        if self.move_right() {
+            while self.move_left() {}
+            return true;
+        }
+
+        self.ascend_from(Node::left)
+    }
+
+    /// Moves the cursor to the previous element in ascending order, and returns `true` if it exists.
+    ///
+    /// The cursor stays where it is if the current element is the smallest.
+    pub fn move_prev(&mut self) -> bool {
+        if self.move_left() {
+            while self.move_right() {}
+            return true;
+        }
+
+        self.ascend_from(Node::right)
+    }
+
+    /// Moves the cursor to the child of the current node returned by `child`, if it exists.
+    fn descend(&mut self, child: fn(&'a Node<T>) -> Option<&'a Node<T>>) -> bool {
+        match self.path.last().copied().and_then(child) {
+            Some(node) => {
+                self.path.push(node);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Moves the cursor to the closest ancestor that holds the path of the cursor as the child returned by `child`.
+    fn ascend_from(&mut self, child: fn(&'a Node<T>) -> Option<&'a Node<T>>) -> bool {
+        let ancestor = self.path.windows(2).rposition(|pair| match pair {
+            [parent, node] => child(parent).is_some_and(|child| ptr::eq(child, *node)),
+            _ => false,
+        });
+
+        match ancestor {
+            Some(ancestor) => {
+                self.path.truncate(ancestor + 1);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// The side of its parent that a node hangs from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Left,
+    Right,
+}
+
+/// A cursor over a `BinaryTree`, which can move between the elements of the tree, and insert or remove elements next to it.
+///
+/// The cursor holds the subtree it points at, and the ancestors of that subtree with a hole where the
+/// subtree belongs, so it moves and edits the tree without searching from the root. The tree is put back
+/// together when the cursor is dropped.
+///
+/// This `struct` is created by the `cursor_mut` and `cursor_mut_at` methods on [`BinaryTree`].
+pub struct CursorMut<'a, T> {
+    tree: &'a mut BinaryTree<T>,
+    focus: Option<NodeBox<T>>,
+    path: Vec<(NodeBox<T>, Side)>,
+    count: usize,
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    /// Returns `true` if the tree holds [`BinaryTree::max_len`] elements, counting the ones held by the cursor.
+    fn is_full(&self) -> bool {
+        self.tree
+            .max_len
+            .is_some_and(|max_len| self.count >= max_len)
+    }
+
+    /// Returns a reference to the element the cursor points at, or `None` if the tree is empty.
+    #[inline]
+    pub fn current(&self) -> Option<&T> {
+        self.focus.as_deref().map(Node::value)
+    }
+
+    /// Returns the number of edges between the root and the element the cursor points at.
+    #[inline]
+    pub fn depth(&self) -> usize {
+        self.path.len()
+    }
+
+    /// Moves the cursor to the left child of the current element, and returns `true` if it exists.
+    pub fn move_left(&mut self) -> bool {
+        self.descend(Side::Left)
+    }
+
+    /// Moves the cursor to the right child of the current element, and returns `true` if it exists.
+    pub fn move_right(&mut self) -> bool {
+        self.descend(Side::Right)
+    }
+
+    /// Moves the cursor to the parent of the current element, and returns `true` if it exists.
+    pub fn move_parent(&mut self) -> bool {
+        self.ascend().is_some()
+    }
+
+    /// Moves the cursor to the next element in ascending order, and returns `true` if it exists.
+    ///
+    /// The cursor stays where it is if the current element is the largest.
+    pub fn move_next(&mut self) -> bool {
+        if self.descend(Side::Right) {
+            while self.descend(Side::Left) {}
+            return true;
+        }
+
+        self.ascend_from(Side::Left)
+    }
+
+    /// Moves the cursor to the previous element in ascending order, and returns `true` if it exists.
+    ///
+    /// The cursor stays where it is if the current element is the smallest.
+    pub fn move_prev(&mut self) -> bool {
+        if self.descend(Side::Left) {
+            while self.descend(Side::Right) {}
+            return true;
+        }
+
+        self.ascend_from(Side::Right)
+    }
+
+    /// Removes the element the cursor points at, and returns it.
+    ///
+    /// The cursor moves to the next element in ascending order, or to the previous one if the removed element was the largest.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// let mut tree = BinaryTree::from(vec![5, 3, 8]);
+    /// let mut cursor = tree.cursor_mut();
+    ///
+    /// assert!(cursor.move_right());
+    /// assert_eq!(cursor.remove_current(), Some(8));
+    /// assert_eq!(cursor.current(), Some(&5));
+    /// ```
+    pub fn remove_current(&mut self) -> Option<T> {
+        let mut node = self.focus.take()?;
+        self.count -= 1;
+
+        let (child, side) = match (node.left.take(), node.right.take()) {
+            (Some(left), Some(right)) => {
+                // the successor takes the place of the removed element, and the cursor stays on it
+                let mut right = Some(right);
+                let successor = Node::pop_min(&mut right);
+                let removed = successor.map(|successor| mem::replace(&mut node.value, successor));
+                node.left = Some(left);
+                node.right = right;
+                self.focus = Some(node);
+
+                return removed;
+            }
+            (Some(left), None) => (Some(left), Side::Left),
+            (None, right) => (right, Side::Right),
+        };
+        self.focus = child;
+
+        if self.focus.is_some() && side == Side::Right {
+            while self.descend(Side::Left) {}
+        } else if !self.ascend_from(Side::Left) {
+            if self.focus.is_some() {
+                while self.descend(Side::Right) {}
+            } else {
+                self.ascend();
+            }
+        }
+
+        Some(node.into_inner().value)
+    }
+
+    /// Moves the cursor to the child on the given side of the current element, if it exists.
+    fn descend(&mut self, side: Side) -> bool {
+        let Some(mut node) = self.focus.take() else {
+            return false;
+        };
+
+        let child = match side {
+            Side::Left => node.left.take(),
+            Side::Right => node.right.take(),
+        };
+        if child.is_none() {
+            self.focus = Some(node);
+            return false;
+        }
+
+        self.path.push((node, side));
+        self.focus = child;
+
+        true
+    }
+
+    /// Moves the cursor to the parent, putting the current subtree back in its place, and returns the side it hangs from.
+    fn ascend(&mut self) -> Option<Side> {
+        let (mut parent, side) = self.path.pop()?;
+        match side {
+            Side::Left => parent.left = self.focus.take(),
+            Side::Right => parent.right = self.focus.take(),
+        }
+        self.focus = Some(parent);
+
+        Some(side)
+    }
+
+    /// Moves the cursor to the closest ancestor that holds the path of the cursor on the given side.
+    fn ascend_from(&mut self, side: Side) -> bool {
+        if !self.path.iter().any(|(_, step)| *step == side) {
+            return false;
+        }
+        while self.ascend().is_some_and(|step| step != side) {}
+
+        true
+    }
+
+    /// Returns the next element in ascending order.
+    fn next_value(&self) -> Option<&T> {
+        match self.focus.as_deref()?.right() {
+            Some(mut node) => {
+                while let Some(left) = node.left() {
+                    node = left;
+                }
+                Some(node.value())
+            }
+            None => self.ancestor_value(Side::Left),
+        }
+    }
+
+    /// Returns the previous element in ascending order.
+    fn prev_value(&self) -> Option<&T> {
+        match self.focus.as_deref()?.left() {
+            Some(mut node) => {
+                while let Some(right) = node.right() {
+                    node = right;
+                }
+                Some(node.value())
+            }
+            None => self.ancestor_value(Side::Right),
+        }
+    }
+
+    /// Returns the element of the closest ancestor that holds the path of the cursor on the given side.
+    fn ancestor_value(&self, side: Side) -> Option<&T> {
+        self.path
+            .iter()
+            .rev()
+            .find(|(_, step)| *step == side)
+            .map(|(node, _)| node.value())
+    }
+}
+
+/// The methods in this implementation block require trait bounds to correctly apply the logic of the methods.
+///
+/// The trait bound in question is [`Ord`], which is used to check that an inserted element fits next to the current one.
+impl<'a, T: Ord> CursorMut<'a, T> {
+    /// Inserts the value as the next element after the current one, without moving the cursor.
+    ///
+    /// If the tree is empty, the value becomes the root and the cursor points at it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the value back if it does not fit between the current element and the next one,
+    /// which includes a value equal to either of them, or if the tree holds [`BinaryTree::max_len`] elements.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// let mut tree = BinaryTree::from(vec![5, 3, 8]);
+    /// let mut cursor = tree.cursor_mut();
+    ///
+    /// assert_eq!(cursor.insert_after(7), Ok(()));
+    /// assert_eq!(cursor.insert_after(6), Ok(()));
+    /// assert_eq!(cursor.insert_after(4), Err(4));
+    /// assert_eq!(cursor.current(), Some(&5));
+    /// ```
+    pub fn insert_after(&mut self, value: T) -> Result<(), T> {
+        let fits = match (self.current(), self.next_value()) {
+            (Some(current), Some(next)) => *current < value && value < *next,
+            (Some(current), None) => *current < value,
+            (None, _) => true,
+        };
+        if !fits || self.is_full() {
+            return Err(value);
+        }
+
+        match self.focus.as_deref_mut() {
+            Some(node) => {
+                let mut slot = &mut node.right;
+                while let Some(node) = slot {
+                    slot = &mut node.left;
+                }
+                *slot = Some(NodeBox::new(Node::new(value)));
+            }
+            None => self.focus = Some(NodeBox::new(Node::new(value))),
+        }
+        self.count += 1;
+
+        Ok(())
+    }
+
+    /// Inserts the value as the previous element before the current one, without moving the cursor.
+    ///
+    /// If the tree is empty, the value becomes the root and the cursor points at it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the value back if it does not fit between the previous element and the current one,
+    /// which includes a value equal to either of them, or if the tree holds [`BinaryTree::max_len`] elements.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// let mut tree = BinaryTree::from(vec![5, 3, 8]);
+    /// let mut cursor = tree.cursor_mut();
+    ///
+    /// assert_eq!(cursor.insert_before(4), Ok(()));
+    /// assert_eq!(cursor.insert_before(3), Err(3));
+    /// assert!(cursor.move_prev());
+    /// assert_eq!(cursor.current(), Some(&4));
+    /// ```
+    pub fn insert_before(&mut self, value: T) -> Result<(), T> {
+        let fits = match (self.current(), self.prev_value()) {
+            (Some(current), Some(prev)) => *prev < value && value < *current,
+            (Some(current), None) => value < *current,
+            (None, _) => true,
+        };
+        if !fits || self.is_full() {
+            return Err(value);
+        }
+
+        match self.focus.as_deref_mut() {
+            Some(node) => {
+                let mut slot = &mut node.left;
+                while let Some(node) = slot {
+                    slot = &mut node.right;
+                }
+                *slot = Some(NodeBox::new(Node::new(value)));
+            }
+            None => self.focus = Some(NodeBox::new(Node::new(value))),
+        }
+        self.count += 1;
+
+        Ok(())
+    }
+}
+
+impl<'a, T> Drop for CursorMut<'a, T> {
+    /// Puts the tree back together.
+    fn drop(&mut self) {
+        while self.ascend().is_some() {}
+        self.tree.root = self.focus.take();
+        self.tree.count = self.count;
+    }
+}
+
+#[cfg(test)]
+mod navigation {
+    use crate::binary_tree::BinaryTree;
+
+    #[test]
+    fn walks_in_order_both_ways() {
+        let tree = BinaryTree::from(vec![8, 4, 12, 2, 6, 10, 14, 1, 3, 5, 7]);
+        let mut cursor = tree.cursor();
+        while cursor.move_prev() {}
+
+        let mut forwards = vec![cursor.current().copied()];
+        while cursor.move_next() {
+            forwards.push(cursor.current().copied());
+        }
+        let mut backwards = vec![cursor.current().copied()];
+        while cursor.move_prev() {
+            backwards.push(cursor.current().copied());
+        }
+        backwards.reverse();
+
+        assert!(forwards.into_iter().flatten().eq(tree.in_order().copied()));
+        assert!(backwards.into_iter().flatten().eq(tree.in_order().copied()));
+    }
+
+    #[test]
+    fn stays_put_at_the_edges() {
+        let tree = BinaryTree::from(vec![2, 1, 3]);
+        let mut cursor = tree.cursor();
+
+        assert!(!cursor.move_parent());
+        assert!(cursor.move_right());
+        assert!(!cursor.move_next());
+        assert!(!cursor.move_left());
+        assert_eq!(cursor.current(), Some(&3));
+        assert_eq!(cursor.depth(), 1);
+    }
+
+    #[test]
+    fn empty_tree_points_at_nothing() {
+        let tree: BinaryTree<i32> = BinaryTree::new();
+        let mut cursor = tree.cursor();
+
+        assert_eq!(cursor.current(), None);
+        assert!(!cursor.move_next());
+        assert!(!cursor.move_left());
+        assert!(tree.cursor_at(&1).is_none());
+    }
+}
+
+#[cfg(test)]
+mod editing {
+    use crate::binary_tree::BinaryTree;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn leaked_cursor_leaves_an_empty_tree() {
+        let mut tree = BinaryTree::from(vec![5, 3, 8]);
+        let mut cursor = tree.cursor_mut();
+        assert_eq!(cursor.insert_after(6), Ok(()));
+        std::mem::forget(cursor);
+
+        assert!(tree.is_empty());
+        assert_eq!(tree.count(), 0);
+        assert_eq!(tree.in_order().len(), 0);
+    }
+
+    #[test]
+    fn random_edits_match_btree_set() {
+        let mut rng = StdRng::seed_from_u64(44);
+        let mut tree = BinaryTree::new();
+        let mut expected = BTreeSet::new();
+
+        for _ in 0..50 {
+            let mut cursor = tree.cursor_mut();
+            for _ in 0..40 {
+                match rng.gen_range(0..6) {
+                    0 => {
+                        cursor.move_next();
+                    }
+                    1 => {
+                        cursor.move_prev();
+                    }
+                    2 => {
+                        cursor.move_parent();
+                    }
+                    3 => {
+                        let removed = cursor.remove_current();
+                        assert_eq!(
+                            removed.map(|value| expected.remove(&value)),
+                            removed.map(|_| true)
+                        );
+
+                        // the cursor moves to the next element, or the previous one at the end
+                        let next = removed.and_then(|value| {
+                            expected
+                                .range(value..)
+                                .next()
+                                .or_else(|| expected.range(..value).next_back())
+                        });
+                        assert_eq!(cursor.current(), next);
+                    }
+                    4 => {
+                        let value = rng.gen_range(0..200);
+                        if cursor.insert_after(value).is_ok() {
+                            assert!(expected.insert(value));
+                        }
+                    }
+                    _ => {
+                        let value = rng.gen_range(0..200);
+                        if cursor.insert_before(value).is_ok() {
+                            assert!(expected.insert(value));
+                        }
+                    }
+                }
+            }
+            drop(cursor);
+
+            assert_eq!(tree.count(), expected.len());
+            assert!(tree.in_order().eq(expected.iter()));
+        }
+    }
+
+    #[test]
+    fn rejects_values_that_do_not_fit() {
+        let mut tree = BinaryTree::from(vec![5, 3, 8]);
+        let mut cursor = tree.cursor_mut_at(&3).unwrap();
+
+        assert_eq!(cursor.insert_after(5), Err(5));
+        assert_eq!(cursor.insert_after(6), Err(6));
+        assert_eq!(cursor.insert_before(3), Err(3));
+        assert_eq!(cursor.insert_before(2), Ok(()));
+        drop(cursor);
+
+        assert_eq!(tree.count(), 4);
+        assert!(tree.in_order().eq(&[2, 3, 5, 8]));
+    }
+
+    #[test]
+    fn respects_max_len() {
+        let mut tree = BinaryTree::with_max_len(3);
+        tree.extend([5, 3]);
+        let mut cursor = tree.cursor_mut();
+
+        assert_eq!(cursor.insert_after(6), Ok(()));
+        assert_eq!(cursor.insert_before(4), Err(4));
+        assert_eq!(cursor.remove_current(), Some(5));
+        assert_eq!(cursor.insert_before(4), Ok(()));
+        drop(cursor);
+
+        assert!(tree.in_order().eq(&[3, 4, 6]));
+    }
+
+    #[test]
+    fn builds_empty_tree() {
+        let mut tree = BinaryTree::new();
+        let mut cursor = tree.cursor_mut();
+
+        assert_eq!(cursor.insert_after(2), Ok(()));
+        assert_eq!(cursor.insert_after(3), Ok(()));
+        assert_eq!(cursor.insert_before(1), Ok(()));
+        drop(cursor);
+
+        assert_eq!(tree.root(), Some(&2));
+        assert_eq!(tree.count(), 3);
+    }
+
+    #[test]
+    fn removes_every_element() {
+        let mut tree: BinaryTree<i32> = (0..20).map(|value| (value * 7) % 20).collect();
+        let mut cursor = tree.cursor_mut();
+
+        while cursor.remove_current().is_some() {}
+        drop(cursor);
+
+        assert!(tree.is_empty());
+        assert_eq!(tree.count(), 0);
+    }
+}
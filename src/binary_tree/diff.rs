@@ -0,0 +1,205 @@
+use core::cmp::Ordering;
+use core::iter::Peekable;
+
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
+
+use super::set_ops::cmp_next;
+use super::{BinaryTree, InOrder};
+
+/// A change that turns one `BinaryTree` into another, for a single element.
+///
+/// A diff yields changes of references into the trees, which [`Change::cloned`] turns into owned changes.
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Change<T> {
+    /// The element is only in the new tree.
+    Added(T),
+    /// The element is only in the old tree.
+    Removed(T),
+}
+
+impl<T> Change<T> {
+    /// Returns a reference to the element that changed.
+    #[inline]
+    pub fn value(&self) -> &T {
+        match self {
+            Change::Added(value) | Change::Removed(value) => value,
+        }
+    }
+}
+
+impl<T: Clone> Change<&T> {
+    /// Maps a `Change<&T>` to a `Change<T>` by cloning the element.
+    #[inline]
+    #[must_use]
+    pub fn cloned(self) -> Change<T> {
+        match self {
+            Change::Added(value) => Change::Added(value.clone()),
+            Change::Removed(value) => Change::Removed(value.clone()),
+        }
+    }
+}
+
+/// The methods in this implementation block require trait bounds to correctly apply the logic of the methods.
+///
+/// The trait bound in question is [`Ord`], which is used to compare the elements of both trees.
+impl<T: Ord> BinaryTree<T> {
+    /// Returns an iterator over the changes that turn `self` into `other`, in ascending order of their elements.
+    ///
+    /// Both trees are walked side by side once, and the changes are yielded lazily.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// # use ds_rs::binary_tree::diff::Change;
+    /// let yesterday = BinaryTree::from(vec!["cache", "debug", "tls"]);
+    /// let today = BinaryTree::from(vec!["cache", "metrics", "tls"]);
+    ///
+    /// let changes: Vec<_> = yesterday.diff(&today).collect();
+    /// assert_eq!(changes, vec![Change::Removed(&"debug"), Change::Added(&"metrics")]);
+    /// ```
+    #[must_use = "iterators are evaluated lazily"]
+    pub fn diff<'a>(&'a self, other: &'a Self) -> Diff<'a, T> {
+        Diff {
+            old: self.in_order().peekable(),
+            new: other.in_order().peekable(),
+        }
+    }
+
+    /// Replays the changes onto the `BinaryTree`, inserting added elements and removing removed ones.
+    ///
+    /// Applying the diff from one tree to another onto a tree with the elements of the first
+    /// leaves it with the elements of the second.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an added element does not fit within [`BinaryTree::max_len`], leaving the changes before it applied.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// # use ds_rs::binary_tree::diff::Change;
+    /// let yesterday = BinaryTree::from(vec![1, 2, 3]);
+    /// let today = BinaryTree::from(vec![2, 3, 4]);
+    ///
+    /// let mut replica = yesterday.clone();
+    /// replica.apply_diff(yesterday.diff(&today).map(Change::cloned));
+    /// assert!(replica.in_order().eq(today.in_order()));
+    /// ```
+    pub fn apply_diff<I>(&mut self, changes: I)
+    where
+        I: IntoIterator<Item = Change<T>>,
+    {
+        for change in changes {
+            match change {
+                Change::Added(value) => self.insert(value),
+                Change::Removed(value) => {
+                    self.remove(&value);
+                }
+            }
+        }
+    }
+}
+
+/// A lazy iterator over the changes between two `BinaryTree`s, in ascending order of their elements.
+///
+/// This `struct` is created by the `diff` method on [`BinaryTree`].
+pub struct Diff<'a, T> {
+    old: Peekable<InOrder<'a, T>>,
+    new: Peekable<InOrder<'a, T>>,
+}
+
+impl<'a, T: Ord> Iterator for Diff<'a, T> {
+    type Item = Change<&'a T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match cmp_next(&mut self.old, &mut self.new)? {
+                Ordering::Less => return self.old.next().map(Change::Removed),
+                Ordering::Greater => return self.new.next().map(Change::Added),
+                Ordering::Equal => {
+                    self.old.next();
+                    self.new.next();
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.old.len().checked_add(self.new.len()))
+    }
+}
+
+#[cfg(test)]
+mod changes {
+    use super::Change;
+    use crate::binary_tree::BinaryTree;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    #[test]
+    fn identical_trees_have_no_changes() {
+        let a = BinaryTree::from(vec![2, 1, 3]);
+        let b = BinaryTree::from(vec![1, 2, 3]);
+
+        assert_eq!(a.diff(&b).next(), None);
+    }
+
+    #[test]
+    fn changes_are_sorted_and_tagged() {
+        let old = BinaryTree::from(vec![5, 1, 9, 3]);
+        let new = BinaryTree::from(vec![4, 9, 1, 10]);
+
+        let changes: Vec<_> = old.diff(&new).collect();
+        assert_eq!(
+            changes,
+            vec![
+                Change::Removed(&3),
+                Change::Added(&4),
+                Change::Removed(&5),
+                Change::Added(&10),
+            ]
+        );
+        assert!(changes.windows(2).all(|pair| match pair {
+            [a, b] => a.value() < b.value(),
+            _ => false,
+        }));
+    }
+
+    #[test]
+    fn applying_a_diff_reproduces_the_new_tree() {
+        let mut rng = StdRng::seed_from_u64(47);
+
+        for _ in 0..20 {
+            let old: BinaryTree<i32> = (0..100).map(|_| rng.gen_range(0..150)).collect();
+            let new: BinaryTree<i32> = (0..100).map(|_| rng.gen_range(0..150)).collect();
+
+            let mut replica = old.clone();
+            replica.apply_diff(old.diff(&new).map(Change::cloned));
+
+            assert_eq!(replica.count(), new.count());
+            assert!(replica.in_order().eq(new.in_order()));
+        }
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod json {
+    use super::Change;
+    use crate::binary_tree::BinaryTree;
+
+    #[test]
+    fn change_list_round_trips_through_json() {
+        let old = BinaryTree::from(vec![1, 2]);
+        let new = BinaryTree::from(vec![2, 3]);
+        let changes: Vec<Change<i32>> = old.diff(&new).map(Change::cloned).collect();
+
+        let json = serde_json::to_string(&changes).expect("should parse changes into json");
+        let actual: Vec<Change<i32>> =
+            serde_json::from_str(&json).expect("should parse json into changes");
+
+        let mut replica = old.clone();
+        replica.apply_diff(actual);
+        assert!(replica.in_order().eq(new.in_order()));
+    }
+}
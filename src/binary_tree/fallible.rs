@@ -0,0 +1,219 @@
+use core::cmp::Ordering;
+use core::fmt;
+
+use super::{BinaryTree, Node, NodeBox};
+
+/// The error returned when a value cannot be inserted into a `BinaryTree`, which hands the value back.
+///
+/// This `enum` is returned by the `try_insert`, `try_extend` and `try_from_iter` methods on [`BinaryTree`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TryInsertError<T> {
+    /// The tree already holds as many elements as its `max_len` allows.
+    MaxLenReached(T),
+    /// The memory for the node of the value could not be allocated.
+    AllocFailed(T),
+}
+
+impl<T> TryInsertError<T> {
+    /// Returns the value that could not be inserted.
+    #[inline]
+    pub fn into_value(self) -> T {
+        match self {
+            TryInsertError::MaxLenReached(value) | TryInsertError::AllocFailed(value) => value,
+        }
+    }
+}
+
+impl<T> fmt::Display for TryInsertError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TryInsertError::MaxLenReached(_) => {
+                f.write_str("the binary tree has reached its max_len")
+            }
+            TryInsertError::AllocFailed(_) => f.write_str("memory allocation for a node failed"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug> std::error::Error for TryInsertError<T> {}
+
+/// The methods in this implementation block require trait bounds to correctly apply the logic of the methods.
+///
+/// The trait bound in question is [`Ord`], which is used to compare elements in the tree.
+impl<T: Ord> BinaryTree<T> {
+    /// Inserts the provided value into the `BinaryTree` like [`BinaryTree::insert`], but returns an error instead
+    /// of panicking when the tree is full, or aborting when memory runs out.
+    ///
+    /// Inserting a value that is already in the tree succeeds without changing the tree, even if it is full.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TryInsertError::MaxLenReached`] if the tree holds [`BinaryTree::max_len`] elements,
+    /// and [`TryInsertError::AllocFailed`] if the allocator cannot provide memory for a node.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// # use ds_rs::binary_tree::fallible::TryInsertError;
+    /// let mut tree = BinaryTree::with_max_len(1);
+    ///
+    /// assert_eq!(tree.try_insert(5), Ok(()));
+    /// assert_eq!(tree.try_insert(5), Ok(()));
+    /// assert_eq!(tree.try_insert(6), Err(TryInsertError::MaxLenReached(6)));
+    /// ```
+    pub fn try_insert(&mut self, value: T) -> Result<(), TryInsertError<T>> {
+        let is_full = self.is_full();
+        let mut slot = &mut self.root;
+        while let Some(node) = slot {
+            slot = match value.cmp(&node.value) {
+                Ordering::Equal => return Ok(()),
+                Ordering::Less => &mut node.left,
+                Ordering::Greater => &mut node.right,
+            };
+        }
+
+        if is_full {
+            return Err(TryInsertError::MaxLenReached(value));
+        }
+        let node = NodeBox::try_new(Node::new(value))
+            .map_err(|node| TryInsertError::AllocFailed(node.value))?;
+
+        *slot = Some(node);
+        self.count += 1;
+
+        Ok(())
+    }
+
+    /// Inserts the values of the iterator into the `BinaryTree` like [`Extend::extend`], stopping at the first value that cannot be inserted.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first value that cannot be inserted, like [`BinaryTree::try_insert`] does.
+    /// The values before it stay in the tree, and the values after it are not consumed.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// let mut tree = BinaryTree::with_max_len(3);
+    /// let mut values = 1..10;
+    ///
+    /// let error = tree.try_extend(values.by_ref()).unwrap_err();
+    /// assert_eq!(error.into_value(), 4);
+    /// assert_eq!(tree.count(), 3);
+    /// assert_eq!(values.next(), Some(5));
+    /// ```
+    pub fn try_extend<I>(&mut self, iter: I) -> Result<(), TryInsertError<T>>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        iter.into_iter()
+            .try_for_each(|value| self.try_insert(value))
+    }
+
+    /// Constructs a `BinaryTree<T>` with the given `max_len` from an iterator for `T` like [`FromIterator::from_iter`],
+    /// but returns an error instead of panicking when the iterator yields more than `max_len` distinct values,
+    /// or aborting when memory runs out.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first value that cannot be inserted, and drops the elements inserted so far.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// let tree = BinaryTree::try_from_iter([5, 4, 6, 4], 3)?;
+    /// assert_eq!(tree.count(), 3);
+    /// assert_eq!(tree.max_len(), Some(3));
+    ///
+    /// assert!(BinaryTree::try_from_iter([5, 4, 6], 2).is_err());
+    /// # Ok::<(), ds_rs::binary_tree::fallible::TryInsertError<i32>>(())
+    /// ```
+    pub fn try_from_iter<I>(iter: I, max_len: usize) -> Result<Self, TryInsertError<T>>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let mut tree = BinaryTree::with_max_len(max_len);
+        tree.try_extend(iter)?;
+
+        Ok(tree)
+    }
+}
+
+#[cfg(test)]
+mod max_len {
+    use super::TryInsertError;
+    use crate::binary_tree::BinaryTree;
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    #[test]
+    fn full_tree_rejects_new_values() {
+        let mut tree = BinaryTree::with_max_len(2);
+        tree.extend([2, 1]);
+
+        assert_eq!(tree.try_insert(3), Err(TryInsertError::MaxLenReached(3)));
+        assert_eq!(tree.try_insert(1), Ok(()));
+        assert_eq!(tree.count(), 2);
+
+        tree.remove(&1);
+        assert_eq!(tree.try_insert(3), Ok(()));
+        assert!(tree.in_order().eq(&[2, 3]));
+    }
+
+    #[test]
+    #[should_panic(expected = "the binary tree has reached its max_len")]
+    fn insert_panics_when_full() {
+        let mut tree = BinaryTree::with_max_len(0);
+        tree.insert(1);
+    }
+
+    #[test]
+    fn max_len_survives_clear_and_clone() {
+        let mut tree = BinaryTree::with_max_len(1);
+        tree.insert(1);
+        tree.clear();
+        tree.insert(2);
+
+        let mut clone = tree.clone();
+        assert!(clone.try_insert(3).is_err());
+        assert_eq!(clone.max_len(), Some(1));
+    }
+
+    #[test]
+    fn max_len_is_left_out_of_equality() {
+        let capped: BinaryTree<i32> = BinaryTree::try_from_iter([2, 1, 3], 3).unwrap_or_default();
+        let uncapped = BinaryTree::from(vec![2, 1, 3]);
+        let hash = |tree: &BinaryTree<i32>| {
+            let mut hasher = DefaultHasher::new();
+            tree.hash(&mut hasher);
+            hasher.finish()
+        };
+
+        assert_eq!(capped, uncapped);
+        assert_eq!(hash(&capped), hash(&uncapped));
+    }
+
+    #[test]
+    fn try_from_iter_stops_at_max_len() {
+        let values = vec![5, 3, 8, 3, 1];
+        let tree = BinaryTree::try_from_iter(values.clone(), 4);
+
+        assert!(tree.is_ok_and(|tree| tree.in_order().eq(&[1, 3, 5, 8])));
+        assert_eq!(
+            BinaryTree::try_from_iter(values, 3),
+            Err(TryInsertError::MaxLenReached(1))
+        );
+    }
+
+    #[test]
+    fn error_reports_and_returns_value() {
+        let error = TryInsertError::MaxLenReached(String::from("value"));
+        assert_eq!(error.to_string(), "the binary tree has reached its max_len");
+        assert_eq!(error.into_value(), "value");
+
+        let error = TryInsertError::AllocFailed(String::from("value"));
+        assert_eq!(error.to_string(), "memory allocation for a node failed");
+        assert_eq!(error.into_value(), "value");
+    }
+}
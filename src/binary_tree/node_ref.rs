@@ -0,0 +1,139 @@
+use core::fmt;
+
+use super::{BinaryTree, Node};
+
+impl<T> BinaryTree<T> {
+    /// Returns a read-only view of the root node, or `None` if the tree is empty.
+    ///
+    /// The view walks the nodes of the tree as they are laid out, for traversals the tree does not provide.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// # use ds_rs::binary_tree::node_ref::NodeRef;
+    /// fn max_path_sum(node: Option<NodeRef<'_, i32>>) -> i32 {
+    ///     node.map_or(0, |node| {
+    ///         node.value() + max_path_sum(node.left()).max(max_path_sum(node.right()))
+    ///     })
+    /// }
+    ///
+    /// let tree = BinaryTree::from(vec![5, 3, 8, 4, 9]);
+    /// assert_eq!(max_path_sum(tree.root_node()), 22);
+    /// ```
+    #[inline]
+    pub fn root_node(&self) -> Option<NodeRef<'_, T>> {
+        self.root.as_deref().map(|node| NodeRef { node })
+    }
+}
+
+/// A read-only view of a node in a `BinaryTree`.
+///
+/// The view only hands out shared references, so it cannot break the ordering of the tree.
+///
+/// This `struct` is created by the `root_node` method on [`BinaryTree`], and by the methods of other views.
+pub struct NodeRef<'a, T> {
+    node: &'a Node<T>,
+}
+
+impl<'a, T> NodeRef<'a, T> {
+    /// Returns a reference to the value of the node.
+    #[inline]
+    pub fn value(&self) -> &'a T {
+        &self.node.value
+    }
+
+    /// Returns a view of the left child of the node, or `None` if it has none.
+    #[inline]
+    pub fn left(&self) -> Option<NodeRef<'a, T>> {
+        self.node.left().map(|node| NodeRef { node })
+    }
+
+    /// Returns a view of the right child of the node, or `None` if it has none.
+    #[inline]
+    pub fn right(&self) -> Option<NodeRef<'a, T>> {
+        self.node.right().map(|node| NodeRef { node })
+    }
+
+    /// Returns `true` if the node has no children.
+    #[inline]
+    pub fn is_leaf(&self) -> bool {
+        self.node.left.is_none() && self.node.right.is_none()
+    }
+}
+
+impl<'a, T> Clone for NodeRef<'a, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, T> Copy for NodeRef<'a, T> {}
+
+impl<'a, T: fmt::Debug> fmt::Debug for NodeRef<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NodeRef")
+            .field("value", self.value())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod node_view {
+    use super::NodeRef;
+    use crate::binary_tree::BinaryTree;
+
+    /// Returns `true` if the subtree rooted at `sub` has the same shape and values as the one rooted at `node`.
+    fn same_shape(node: Option<NodeRef<'_, i32>>, sub: Option<NodeRef<'_, i32>>) -> bool {
+        match (node, sub) {
+            (Some(node), Some(sub)) => {
+                node.value() == sub.value()
+                    && same_shape(node.left(), sub.left())
+                    && same_shape(node.right(), sub.right())
+            }
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if some subtree of the tree rooted at `node` has the shape of `sub`.
+    fn contains_subtree(node: Option<NodeRef<'_, i32>>, sub: Option<NodeRef<'_, i32>>) -> bool {
+        same_shape(node, sub)
+            || node.is_some_and(|node| {
+                contains_subtree(node.left(), sub) || contains_subtree(node.right(), sub)
+            })
+    }
+
+    #[test]
+    fn empty_tree_has_no_root_node() {
+        let tree: BinaryTree<i32> = BinaryTree::new();
+        assert!(tree.root_node().is_none());
+    }
+
+    #[test]
+    fn walks_the_layout_of_the_tree() {
+        let tree = BinaryTree::from(vec![5, 3, 8, 4]);
+        let root = tree.root_node();
+
+        assert_eq!(root.map(|root| *root.value()), Some(5));
+        assert_eq!(
+            root.and_then(|root| root.right())
+                .map(|right| right.is_leaf()),
+            Some(true)
+        );
+        assert_eq!(
+            root.and_then(|root| root.left()?.right())
+                .map(|node| *node.value()),
+            Some(4)
+        );
+    }
+
+    #[test]
+    fn finds_subtrees() {
+        let tree = BinaryTree::from(vec![8, 4, 12, 2, 6, 10, 14]);
+        let sub = BinaryTree::from(vec![4, 2, 6]);
+        let other = BinaryTree::from(vec![4, 2]);
+
+        assert!(contains_subtree(tree.root_node(), sub.root_node()));
+        assert!(!contains_subtree(tree.root_node(), other.root_node()));
+    }
+}
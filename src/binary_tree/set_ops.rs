@@ -0,0 +1,856 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::iter::Peekable;
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Sub, SubAssign};
+
+use super::{BinaryTree, InOrder, Node};
+
+/// How many times larger one tree must be than the other, before [`BinaryTree::intersection`] and
+/// [`BinaryTree::is_subset`] look up elements in the larger tree instead of walking it.
+///
+/// A lookup takes time proportional to the height of the larger tree, which is only logarithmic in its
+/// count while the tree is balanced. A `BinaryTree` does not rebalance, so when the larger tree has
+/// degenerated into long chains, for example by inserting sorted values, the lookups can be slower than
+/// walking both trees.
+pub const PROBE_RATIO: usize = 16;
+
+/// The set operations in this implementation block walk both trees in ascending order.
+///
+/// The iterators yield the elements of the result lazily, in ascending order as well, and
+/// elements that are in both trees are yielded from `self`. The predicates stop as soon as the answer is known.
+impl<T: Ord> BinaryTree<T> {
+    /// Returns an iterator over the elements that are in `self`, `other` or both.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// let a = BinaryTree::from(vec![3, 1, 5]);
+    /// let b = BinaryTree::from(vec![4, 3, 2]);
+    ///
+    /// assert!(a.union(&b).eq(&[1, 2, 3, 4, 5]));
+    /// ```
+    #[must_use = "iterators are evaluated lazily"]
+    pub fn union<'a>(&'a self, other: &'a Self) -> Union<'a, T> {
+        Union {
+            a: self.in_order().peekable(),
+            b: other.in_order().peekable(),
+        }
+    }
+
+    /// Returns an iterator over the elements that are in both `self` and `other`.
+    ///
+    /// When one tree holds at least [`PROBE_RATIO`] times as many elements as the other, the iterator
+    /// walks the smaller tree only, and looks up each of its elements in the larger one, which assumes
+    /// the larger tree is reasonably balanced.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// let a = BinaryTree::from(vec![3, 1, 5]);
+    /// let b = BinaryTree::from(vec![4, 3, 5]);
+    ///
+    /// assert!(a.intersection(&b).eq(&[3, 5]));
+    /// ```
+    #[must_use = "iterators are evaluated lazily"]
+    pub fn intersection<'a>(&'a self, other: &'a Self) -> Intersection<'a, T> {
+        let inner = if other.count / PROBE_RATIO >= self.count.max(1) {
+            IntersectionInner::Probe {
+                small: self.in_order(),
+                large: other,
+                small_is_self: true,
+            }
+        } else if self.count / PROBE_RATIO >= other.count.max(1) {
+            IntersectionInner::Probe {
+                small: other.in_order(),
+                large: self,
+                small_is_self: false,
+            }
+        } else {
+            IntersectionInner::Merge {
+                a: self.in_order().peekable(),
+                b: other.in_order().peekable(),
+            }
+        };
+
+        Intersection { inner }
+    }
+
+    /// Returns an iterator over the elements that are in `self`, but not in `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// let a = BinaryTree::from(vec![3, 1, 5]);
+    /// let b = BinaryTree::from(vec![4, 3, 2]);
+    ///
+    /// assert!(a.difference(&b).eq(&[1, 5]));
+    /// ```
+    #[must_use = "iterators are evaluated lazily"]
+    pub fn difference<'a>(&'a self, other: &'a Self) -> Difference<'a, T> {
+        Difference {
+            a: self.in_order(),
+            b: other.in_order().peekable(),
+        }
+    }
+
+    /// Returns an iterator over the elements that are in either `self` or `other`, but not in both.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// let a = BinaryTree::from(vec![3, 1, 5]);
+    /// let b = BinaryTree::from(vec![4, 3, 2]);
+    ///
+    /// assert!(a.symmetric_difference(&b).eq(&[1, 2, 4, 5]));
+    /// ```
+    #[must_use = "iterators are evaluated lazily"]
+    pub fn symmetric_difference<'a>(&'a self, other: &'a Self) -> SymmetricDifference<'a, T> {
+        SymmetricDifference {
+            a: self.in_order().peekable(),
+            b: other.in_order().peekable(),
+        }
+    }
+
+    /// Returns `true` if every element of `self` is in `other`.
+    ///
+    /// When `other` holds at least [`PROBE_RATIO`] times as many elements as `self`, the elements of `self`
+    /// are looked up in `other`, which assumes `other` is reasonably balanced, otherwise both trees are walked
+    /// side by side. Either way, the check stops at the first element that is missing from `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// let granted = BinaryTree::from(vec!["read", "write", "admin"]);
+    ///
+    /// assert!(BinaryTree::from(vec!["read", "write"]).is_subset(&granted));
+    /// assert!(!BinaryTree::from(vec!["read", "delete"]).is_subset(&granted));
+    /// ```
+    pub fn is_subset(&self, other: &Self) -> bool {
+        if self.count > other.count {
+            return false;
+        }
+        if other.count / PROBE_RATIO >= self.count {
+            return self.in_order().all(|value| other.contains(value));
+        }
+
+        let mut other = other.in_order().peekable();
+        self.in_order().all(|value| {
+            while other.next_if(|other| *other < value).is_some() {}
+
+            other.next_if(|other| *other == value).is_some()
+        })
+    }
+
+    /// Returns `true` if every element of `other` is in `self`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// let granted = BinaryTree::from(vec!["read", "write", "admin"]);
+    ///
+    /// assert!(granted.is_superset(&BinaryTree::from(vec!["read", "write"])));
+    /// assert!(!granted.is_superset(&BinaryTree::from(vec!["read", "delete"])));
+    /// ```
+    #[inline]
+    pub fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Returns `true` if `self` and `other` have no elements in common.
+    ///
+    /// The check stops at the first common element, which is found the same way as by [`BinaryTree::intersection`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// let a = BinaryTree::from(vec![1, 3, 5]);
+    ///
+    /// assert!(a.is_disjoint(&BinaryTree::from(vec![2, 4])));
+    /// assert!(!a.is_disjoint(&BinaryTree::from(vec![4, 5])));
+    /// ```
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.intersection(other).next().is_none()
+    }
+}
+
+/// Compares the next elements of both iterators, where an exhausted iterator is greater than any element.
+pub(super) fn cmp_next<T: Ord>(
+    a: &mut Peekable<InOrder<'_, T>>,
+    b: &mut Peekable<InOrder<'_, T>>,
+) -> Option<Ordering> {
+    match (a.peek(), b.peek()) {
+        (Some(x), Some(y)) => Some(x.cmp(y)),
+        (Some(_), None) => Some(Ordering::Less),
+        (None, Some(_)) => Some(Ordering::Greater),
+        (None, None) => None,
+    }
+}
+
+/// Returns the element of the tree equal to `target`, like [`BinaryTree::contains`] looks for it.
+fn find<'a, T: Ord>(tree: &'a BinaryTree<T>, target: &T) -> Option<&'a T> {
+    let mut node = tree.root.as_deref()?;
+
+    loop {
+        node = match target.cmp(node.value()) {
+            Ordering::Equal => return Some(node.value()),
+            Ordering::Less => node.left()?,
+            Ordering::Greater => node.right()?,
+        };
+    }
+}
+
+/// A lazy iterator over the union of two `BinaryTree`s, in ascending order.
+///
+/// This `struct` is created by the `union` method on [`BinaryTree`].
+pub struct Union<'a, T> {
+    a: Peekable<InOrder<'a, T>>,
+    b: Peekable<InOrder<'a, T>>,
+}
+
+impl<'a, T: Ord> Iterator for Union<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match cmp_next(&mut self.a, &mut self.b)? {
+            Ordering::Less => self.a.next(),
+            Ordering::Greater => self.b.next(),
+            Ordering::Equal => {
+                self.b.next();
+                self.a.next()
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (a, b) = (self.a.len(), self.b.len());
+
+        (a.max(b), a.checked_add(b))
+    }
+}
+
+/// A lazy iterator over the intersection of two `BinaryTree`s, in ascending order.
+///
+/// This `struct` is created by the `intersection` method on [`BinaryTree`].
+pub struct Intersection<'a, T> {
+    inner: IntersectionInner<'a, T>,
+}
+
+enum IntersectionInner<'a, T> {
+    /// Walks both trees side by side.
+    Merge {
+        a: Peekable<InOrder<'a, T>>,
+        b: Peekable<InOrder<'a, T>>,
+    },
+    /// Walks the smaller tree, and looks up its elements in the larger one.
+    Probe {
+        small: InOrder<'a, T>,
+        large: &'a BinaryTree<T>,
+        small_is_self: bool,
+    },
+}
+
+impl<'a, T: Ord> Iterator for Intersection<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.inner {
+            IntersectionInner::Merge { a, b } => loop {
+                match a.peek()?.cmp(b.peek()?) {
+                    Ordering::Less => {
+                        a.next();
+                    }
+                    Ordering::Greater => {
+                        b.next();
+                    }
+                    Ordering::Equal => {
+                        b.next();
+                        return a.next();
+                    }
+                }
+            },
+            IntersectionInner::Probe {
+                small,
+                large,
+                small_is_self,
+            } => loop {
+                let value = small.next()?;
+
+                // yield the element of `self`, which may differ from an equal element of `other`
+                if let Some(found) = find(large, value) {
+                    return Some(if *small_is_self { value } else { found });
+                }
+            },
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.inner {
+            IntersectionInner::Merge { a, b } => (0, Some(a.len().min(b.len()))),
+            IntersectionInner::Probe { small, .. } => (0, Some(small.len())),
+        }
+    }
+}
+
+/// A lazy iterator over the difference of two `BinaryTree`s, in ascending order.
+///
+/// This `struct` is created by the `difference` method on [`BinaryTree`].
+pub struct Difference<'a, T> {
+    a: InOrder<'a, T>,
+    b: Peekable<InOrder<'a, T>>,
+}
+
+impl<'a, T: Ord> Iterator for Difference<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        'elements: loop {
+            let value = self.a.next()?;
+
+            while let Some(other) = self.b.peek() {
+                match value.cmp(other) {
+                    Ordering::Less => break,
+                    Ordering::Equal => continue 'elements,
+                    Ordering::Greater => {
+                        self.b.next();
+                    }
+                }
+            }
+
+            return Some(value);
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let a = self.a.len();
+
+        (a.saturating_sub(self.b.len()), Some(a))
+    }
+}
+
+/// A lazy iterator over the symmetric difference of two `BinaryTree`s, in ascending order.
+///
+/// This `struct` is created by the `symmetric_difference` method on [`BinaryTree`].
+pub struct SymmetricDifference<'a, T> {
+    a: Peekable<InOrder<'a, T>>,
+    b: Peekable<InOrder<'a, T>>,
+}
+
+impl<'a, T: Ord> Iterator for SymmetricDifference<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match cmp_next(&mut self.a, &mut self.b)? {
+                Ordering::Less => return self.a.next(),
+                Ordering::Greater => return self.b.next(),
+                Ordering::Equal => {
+                    self.a.next();
+                    self.b.next();
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.a.len().checked_add(self.b.len()))
+    }
+}
+
+impl<T: Ord> BinaryTree<T> {
+    /// Builds a balanced tree from values that are sorted in ascending order, and contain no duplicates.
+    fn from_sorted(values: Vec<T>) -> Self {
+        Self {
+            count: values.len(),
+            root: Node::from_sorted(values),
+            max_len: None,
+        }
+    }
+
+    /// Replaces the elements of the tree with a balanced tree of the values, which are sorted in ascending order,
+    /// and contain no duplicates.
+    fn rebuild(&mut self, values: Vec<T>) {
+        self.count = values.len();
+        self.root = Node::from_sorted(values);
+    }
+
+    /// Empties the tree, and returns its elements in ascending order.
+    fn take_sorted(&mut self) -> Vec<T> {
+        let mut values = Vec::with_capacity(self.count);
+        Node::drain_sorted(self.root.take(), &mut values);
+        self.count = 0;
+
+        values
+    }
+
+    /// Rebuilds the tree balanced from the sorted elements of both trees, keeping the elements
+    /// found in only one of them, and the element of `self` between equal elements if `keep_common` is set.
+    ///
+    /// # Panics
+    ///
+    /// Panics before changing the tree if the result holds more than [`BinaryTree::max_len`] elements.
+    fn merge_from(&mut self, mut other: Self, keep_common: bool) {
+        if let Some(max_len) = self.max_len {
+            let len = if keep_common {
+                self.union(&other).count()
+            } else {
+                self.symmetric_difference(&other).count()
+            };
+            assert!(len <= max_len, "the binary tree has reached its max_len");
+        }
+
+        let a = self.take_sorted();
+        let b = other.take_sorted();
+        let mut merged = Vec::with_capacity(a.len() + b.len());
+        let mut a = a.into_iter().peekable();
+        let mut b = b.into_iter().peekable();
+
+        loop {
+            let order = match (a.peek(), b.peek()) {
+                (Some(x), Some(y)) => x.cmp(y),
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => break,
+            };
+
+            match order {
+                Ordering::Less => merged.extend(a.next()),
+                Ordering::Greater => merged.extend(b.next()),
+                Ordering::Equal => {
+                    b.next();
+                    merged.extend(a.next().filter(|_| keep_common));
+                }
+            }
+        }
+
+        self.rebuild(merged);
+    }
+
+    /// Rebuilds the tree balanced from its elements, keeping those whose presence in `other` equals `in_other`.
+    fn retain_by(&mut self, other: &Self, in_other: bool) {
+        let mut values = self.take_sorted();
+        let mut other = other.in_order().peekable();
+
+        values.retain(|value| {
+            while other.next_if(|other| *other < value).is_some() {}
+
+            other.peek().is_some_and(|other| *other == value) == in_other
+        });
+
+        self.rebuild(values);
+    }
+}
+
+impl<T: Ord + Clone> BitOr<&BinaryTree<T>> for &BinaryTree<T> {
+    type Output = BinaryTree<T>;
+
+    /// Returns the union of `self` and `rhs` as a new balanced `BinaryTree<T>`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// let a = BinaryTree::from(vec![1, 2, 3]);
+    /// let b = BinaryTree::from(vec![3, 4, 5]);
+    ///
+    /// let union = &a | &b;
+    /// assert!(union.in_order().eq(&[1, 2, 3, 4, 5]));
+    /// assert_eq!(union.height(), 3);
+    /// ```
+    fn bitor(self, rhs: &BinaryTree<T>) -> Self::Output {
+        BinaryTree::from_sorted(self.union(rhs).cloned().collect())
+    }
+}
+
+impl<T: Ord + Clone> BitAnd<&BinaryTree<T>> for &BinaryTree<T> {
+    type Output = BinaryTree<T>;
+
+    /// Returns the intersection of `self` and `rhs` as a new balanced `BinaryTree<T>`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// let a = BinaryTree::from(vec![1, 2, 3]);
+    /// let b = BinaryTree::from(vec![2, 3, 4]);
+    ///
+    /// assert!((&a & &b).in_order().eq(&[2, 3]));
+    /// ```
+    fn bitand(self, rhs: &BinaryTree<T>) -> Self::Output {
+        BinaryTree::from_sorted(self.intersection(rhs).cloned().collect())
+    }
+}
+
+impl<T: Ord + Clone> Sub<&BinaryTree<T>> for &BinaryTree<T> {
+    type Output = BinaryTree<T>;
+
+    /// Returns the difference of `self` and `rhs` as a new balanced `BinaryTree<T>`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// let a = BinaryTree::from(vec![1, 2, 3]);
+    /// let b = BinaryTree::from(vec![2, 3, 4]);
+    ///
+    /// assert!((&a - &b).in_order().eq(&[1]));
+    /// ```
+    fn sub(self, rhs: &BinaryTree<T>) -> Self::Output {
+        BinaryTree::from_sorted(self.difference(rhs).cloned().collect())
+    }
+}
+
+impl<T: Ord + Clone> BitXor<&BinaryTree<T>> for &BinaryTree<T> {
+    type Output = BinaryTree<T>;
+
+    /// Returns the symmetric difference of `self` and `rhs` as a new balanced `BinaryTree<T>`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// let a = BinaryTree::from(vec![1, 2, 3]);
+    /// let b = BinaryTree::from(vec![2, 3, 4]);
+    ///
+    /// assert!((&a ^ &b).in_order().eq(&[1, 4]));
+    /// ```
+    fn bitxor(self, rhs: &BinaryTree<T>) -> Self::Output {
+        BinaryTree::from_sorted(self.symmetric_difference(rhs).cloned().collect())
+    }
+}
+
+impl<T: Ord> BitOrAssign<BinaryTree<T>> for BinaryTree<T> {
+    /// Moves the elements of `rhs` that are not in `self` into `self`, and rebalances the tree.
+    ///
+    /// # Panics
+    ///
+    /// Panics without changing `self` if the union holds more than [`BinaryTree::max_len`] elements.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// let mut a = BinaryTree::from(vec![1, 2, 3]);
+    /// a |= BinaryTree::from(vec![3, 4, 5]);
+    ///
+    /// assert!(a.in_order().eq(&[1, 2, 3, 4, 5]));
+    /// ```
+    fn bitor_assign(&mut self, rhs: BinaryTree<T>) {
+        self.merge_from(rhs, true);
+    }
+}
+
+impl<T: Ord> BitAndAssign<&BinaryTree<T>> for BinaryTree<T> {
+    /// Removes the elements of `self` that are not in `rhs`, and rebalances the tree.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// let mut a = BinaryTree::from(vec![1, 2, 3]);
+    /// a &= &BinaryTree::from(vec![2, 3, 4]);
+    ///
+    /// assert!(a.in_order().eq(&[2, 3]));
+    /// ```
+    fn bitand_assign(&mut self, rhs: &BinaryTree<T>) {
+        self.retain_by(rhs, true);
+    }
+}
+
+impl<T: Ord> SubAssign<&BinaryTree<T>> for BinaryTree<T> {
+    /// Removes the elements of `self` that are in `rhs`, and rebalances the tree.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// let mut a = BinaryTree::from(vec![1, 2, 3]);
+    /// a -= &BinaryTree::from(vec![2, 3, 4]);
+    ///
+    /// assert!(a.in_order().eq(&[1]));
+    /// ```
+    fn sub_assign(&mut self, rhs: &BinaryTree<T>) {
+        self.retain_by(rhs, false);
+    }
+}
+
+impl<T: Ord> BitXorAssign<BinaryTree<T>> for BinaryTree<T> {
+    /// Removes the elements of `self` that are in `rhs`, moves the other elements of `rhs` into `self`,
+    /// and rebalances the tree.
+    ///
+    /// # Panics
+    ///
+    /// Panics without changing `self` if the symmetric difference holds more than [`BinaryTree::max_len`] elements.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// let mut a = BinaryTree::from(vec![1, 2, 3]);
+    /// a ^= BinaryTree::from(vec![2, 3, 4]);
+    ///
+    /// assert!(a.in_order().eq(&[1, 4]));
+    /// ```
+    fn bitxor_assign(&mut self, rhs: BinaryTree<T>) {
+        self.merge_from(rhs, false);
+    }
+}
+
+#[cfg(test)]
+mod set_iterators {
+    use crate::binary_tree::BinaryTree;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use std::collections::BTreeSet;
+
+    fn random_pair(rng: &mut StdRng, a_len: usize, b_len: usize) -> (Vec<i32>, Vec<i32>) {
+        let a = (0..a_len).map(|_| rng.gen_range(0..400)).collect();
+        let b = (0..b_len).map(|_| rng.gen_range(0..400)).collect();
+
+        (a, b)
+    }
+
+    #[test]
+    fn match_btree_set() {
+        let mut rng = StdRng::seed_from_u64(41);
+
+        for (a_len, b_len) in [(0, 0), (0, 50), (50, 0), (100, 120), (300, 10), (5, 300)] {
+            let (a, b) = random_pair(&mut rng, a_len, b_len);
+            let (tree_a, tree_b) = (BinaryTree::from(a.clone()), BinaryTree::from(b.clone()));
+            let (set_a, set_b): (BTreeSet<_>, BTreeSet<_>) =
+                (a.into_iter().collect(), b.into_iter().collect());
+
+            assert!(tree_a.union(&tree_b).eq(set_a.union(&set_b)));
+            assert!(tree_a.intersection(&tree_b).eq(set_a.intersection(&set_b)));
+            assert!(tree_b.intersection(&tree_a).eq(set_b.intersection(&set_a)));
+            assert!(tree_a.difference(&tree_b).eq(set_a.difference(&set_b)));
+            assert!(tree_b.difference(&tree_a).eq(set_b.difference(&set_a)));
+            assert!(tree_a
+                .symmetric_difference(&tree_b)
+                .eq(set_a.symmetric_difference(&set_b)));
+        }
+    }
+
+    #[test]
+    fn size_hints_bound_the_results() {
+        let a = BinaryTree::from(vec![1, 2, 3, 4, 5, 6]);
+        let b = BinaryTree::from(vec![4, 5, 6, 7]);
+
+        for (hint, len) in [
+            (a.union(&b).size_hint(), a.union(&b).count()),
+            (a.intersection(&b).size_hint(), a.intersection(&b).count()),
+            (a.difference(&b).size_hint(), a.difference(&b).count()),
+            (
+                a.symmetric_difference(&b).size_hint(),
+                a.symmetric_difference(&b).count(),
+            ),
+        ] {
+            assert!(hint.0 <= len);
+            assert!(hint.1.is_some_and(|upper| len <= upper));
+        }
+    }
+
+    #[test]
+    fn intersection_yields_elements_of_self() {
+        #[derive(Debug)]
+        struct Tagged(i32, char);
+
+        impl PartialEq for Tagged {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl Eq for Tagged {}
+
+        impl PartialOrd for Tagged {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Tagged {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+
+        let small = BinaryTree::from(vec![Tagged(3, 'a')]);
+        let large: BinaryTree<_> = (0..100).map(|value| Tagged(value, 'b')).collect();
+
+        assert_eq!(
+            small.intersection(&large).map(|t| t.1).collect::<String>(),
+            "a"
+        );
+        assert_eq!(
+            large.intersection(&small).map(|t| t.1).collect::<String>(),
+            "b"
+        );
+        assert_eq!(
+            small.union(&large).find(|t| t.0 == 3).map(|t| t.1),
+            Some('a')
+        );
+    }
+}
+
+#[cfg(test)]
+mod operators {
+    use crate::binary_tree::BinaryTree;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use std::collections::BTreeSet;
+
+    /// Returns the height of a balanced tree holding `count` elements.
+    fn balanced_height(count: usize) -> usize {
+        (usize::BITS - count.leading_zeros()) as usize
+    }
+
+    #[test]
+    fn match_btree_set() {
+        let mut rng = StdRng::seed_from_u64(42);
+
+        for (a_len, b_len) in [(0, 0), (0, 40), (40, 0), (150, 150), (300, 10)] {
+            let a: Vec<i32> = (0..a_len).map(|_| rng.gen_range(0..300)).collect();
+            let b: Vec<i32> = (0..b_len).map(|_| rng.gen_range(0..300)).collect();
+            let (tree_a, tree_b) = (BinaryTree::from(a.clone()), BinaryTree::from(b.clone()));
+            let (set_a, set_b): (BTreeSet<_>, BTreeSet<_>) =
+                (a.into_iter().collect(), b.into_iter().collect());
+
+            let results = [
+                (&tree_a | &tree_b, &set_a | &set_b),
+                (&tree_a & &tree_b, &set_a & &set_b),
+                (&tree_a - &tree_b, &set_a - &set_b),
+                (&tree_a ^ &tree_b, &set_a ^ &set_b),
+            ];
+            for (tree, set) in results {
+                assert!(tree.in_order().eq(set.iter()));
+                assert_eq!(tree.count(), set.len());
+                assert_eq!(tree.height(), balanced_height(set.len()));
+            }
+
+            let mut assigned = [tree_a.clone(), tree_a.clone(), tree_a.clone(), tree_a];
+            let [union, intersection, difference, symmetric_difference] = &mut assigned;
+            *union |= tree_b.clone();
+            *intersection &= &tree_b;
+            *difference -= &tree_b;
+            *symmetric_difference ^= tree_b;
+
+            let expected = [
+                &set_a | &set_b,
+                &set_a & &set_b,
+                &set_a - &set_b,
+                &set_a ^ &set_b,
+            ];
+            for (tree, set) in assigned.iter().zip(expected) {
+                assert!(tree.in_order().eq(set.iter()));
+                assert_eq!(tree.count(), set.len());
+                assert_eq!(tree.height(), balanced_height(set.len()));
+            }
+        }
+    }
+
+    #[test]
+    fn assign_ops_do_not_need_clone() {
+        struct Key(u8);
+
+        impl PartialEq for Key {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl Eq for Key {}
+
+        impl PartialOrd for Key {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for Key {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+
+        let mut tree: BinaryTree<Key> = (0..10).map(Key).collect();
+        tree |= (5..15).map(Key).collect();
+        tree -= &(0..3).map(Key).collect();
+        tree &= &(0..12).map(Key).collect();
+        tree ^= (10..20).map(Key).collect();
+
+        assert!(tree.in_order().map(|key| key.0).eq((3..10).chain(12..20)));
+    }
+
+    #[test]
+    fn assign_ops_keep_max_len() {
+        let mut tree = BinaryTree::with_max_len(4);
+        tree.extend([1, 2, 3]);
+
+        tree |= BinaryTree::from(vec![3, 4]);
+        tree -= &BinaryTree::from(vec![1]);
+        tree ^= BinaryTree::from(vec![4, 5]);
+
+        assert_eq!(tree.max_len(), Some(4));
+        assert!(tree.in_order().eq(&[2, 3, 5]));
+    }
+
+    #[test]
+    #[should_panic(expected = "the binary tree has reached its max_len")]
+    fn union_beyond_max_len_panics() {
+        let mut tree = BinaryTree::with_max_len(3);
+        tree.extend([1, 2, 3]);
+
+        tree |= BinaryTree::from(vec![3, 4]);
+    }
+}
+
+#[cfg(test)]
+mod predicates {
+    use crate::binary_tree::BinaryTree;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn match_btree_set() {
+        let mut rng = StdRng::seed_from_u64(43);
+
+        for (a_len, b_len, range) in [
+            (0, 0, 10),
+            (0, 20, 10),
+            (5, 200, 100),
+            (30, 40, 60),
+            (50, 50, 1000),
+            (4, 300, 1000),
+        ] {
+            for _ in 0..20 {
+                let a: Vec<i32> = (0..a_len).map(|_| rng.gen_range(0..range)).collect();
+                let b: Vec<i32> = (0..b_len).map(|_| rng.gen_range(0..range)).collect();
+                let (tree_a, tree_b) = (BinaryTree::from(a.clone()), BinaryTree::from(b.clone()));
+                let (set_a, set_b): (BTreeSet<_>, BTreeSet<_>) =
+                    (a.into_iter().collect(), b.into_iter().collect());
+
+                assert_eq!(tree_a.is_subset(&tree_b), set_a.is_subset(&set_b));
+                assert_eq!(tree_b.is_subset(&tree_a), set_b.is_subset(&set_a));
+                assert_eq!(tree_a.is_superset(&tree_b), set_a.is_superset(&set_b));
+                assert_eq!(tree_a.is_disjoint(&tree_b), set_a.is_disjoint(&set_b));
+            }
+        }
+    }
+
+    #[test]
+    fn subsets_by_both_strategies() {
+        let large: BinaryTree<i32> = (0..100).collect();
+
+        // a few elements are looked up, while many elements are walked side by side
+        for small in [vec![3, 50, 99], (10..60).collect()] {
+            let mut small = BinaryTree::from(small);
+            assert!(small.is_subset(&large));
+            assert!(large.is_superset(&small));
+
+            small.insert(100);
+            assert!(!small.is_subset(&large));
+        }
+    }
+
+    #[test]
+    fn empty_tree_is_subset_and_disjoint() {
+        let empty = BinaryTree::new();
+        let tree = BinaryTree::from(vec![1, 2]);
+
+        assert!(empty.is_subset(&tree));
+        assert!(empty.is_subset(&empty));
+        assert!(tree.is_superset(&empty));
+        assert!(empty.is_disjoint(&tree));
+        assert!(empty.is_disjoint(&empty));
+    }
+}
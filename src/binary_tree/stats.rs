@@ -0,0 +1,191 @@
+use alloc::vec::Vec;
+
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
+
+use super::BinaryTree;
+
+/// A report on the shape of a `BinaryTree`, to tell how far it is from balanced.
+///
+/// Depths count the edges from the root, so the root has a depth of `0`,
+/// and the height is one more than the largest depth of a non-empty tree.
+///
+/// This `struct` is created by the `stats` method on [`BinaryTree`].
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TreeStats {
+    /// The number of elements in the tree.
+    pub count: usize,
+    /// The number of levels in the tree, like [`BinaryTree::height`].
+    pub height: usize,
+    /// The depth of the shallowest leaf.
+    pub min_leaf_depth: usize,
+    /// The depth of the deepest node.
+    pub max_depth: usize,
+    /// The average depth of the nodes.
+    pub average_depth: f64,
+    /// The number of nodes without children.
+    pub leaves: usize,
+    /// The number of nodes with at least one child.
+    pub internal_nodes: usize,
+    /// The number of nodes on each level, starting from the root.
+    pub level_widths: Vec<usize>,
+    /// The height of a balanced tree holding `count` elements, divided by `height`.
+    ///
+    /// The ratio is `1.0` for a balanced or empty tree, and approaches `0.0` as the tree degenerates.
+    pub balance_ratio: f64,
+    /// Whether every level is full, except for the last one, which is filled from the left.
+    pub is_complete: bool,
+    /// Whether every level is full.
+    pub is_perfect: bool,
+    /// Whether the tree has more than two elements, and every node has at most one child, like a linked list.
+    pub is_degenerate: bool,
+}
+
+impl<T> BinaryTree<T> {
+    /// Computes statistics on the shape of the `BinaryTree` in a single traversal.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::binary_tree::BinaryTree;
+    /// let stats = BinaryTree::from(vec![4, 2, 6, 1]).stats();
+    ///
+    /// assert_eq!(stats.height, 3);
+    /// assert_eq!(stats.level_widths, vec![1, 2, 1]);
+    /// assert!(stats.is_complete);
+    /// assert!(!stats.is_perfect);
+    ///
+    /// let stats = BinaryTree::from(vec![1, 2, 3, 4]).stats();
+    /// assert!(stats.is_degenerate);
+    /// assert_eq!(stats.balance_ratio, 0.75);
+    /// ```
+    pub fn stats(&self) -> TreeStats {
+        let mut level_widths: Vec<usize> = Vec::new();
+        let mut min_leaf_depth = usize::MAX;
+        let mut depth_sum = 0;
+        let mut leaves = 0;
+        let mut max_position = Some(0);
+
+        self.walk(|level, position, node| {
+            let depth = level - 1;
+            match level_widths.get_mut(depth) {
+                Some(width) => *width += 1,
+                None => level_widths.push(1),
+            }
+            depth_sum += depth;
+
+            if node.left.is_none() && node.right.is_none() {
+                leaves += 1;
+                min_leaf_depth = min_leaf_depth.min(depth);
+            }
+            max_position = max_position
+                .zip(position)
+                .map(|(max, position)| max.max(position));
+        });
+
+        let count = self.count;
+        let height = level_widths.len();
+        let balanced_height = (usize::BITS - count.leading_zeros()) as usize;
+        let is_complete = max_position == Some(count);
+
+        TreeStats {
+            count,
+            height,
+            min_leaf_depth: if leaves == 0 { 0 } else { min_leaf_depth },
+            max_depth: height.saturating_sub(1),
+            average_depth: if count == 0 {
+                0.0
+            } else {
+                depth_sum as f64 / count as f64
+            },
+            leaves,
+            internal_nodes: count - leaves,
+            level_widths,
+            balance_ratio: if height == 0 {
+                1.0
+            } else {
+                balanced_height as f64 / height as f64
+            },
+            is_complete,
+            is_perfect: is_complete && count.checked_add(1).is_some_and(usize::is_power_of_two),
+            is_degenerate: count > 2 && height == count,
+        }
+    }
+}
+
+#[cfg(test)]
+mod shape {
+    use super::TreeStats;
+    use crate::binary_tree::BinaryTree;
+
+    #[test]
+    fn empty_tree() {
+        let stats = BinaryTree::<i32>::new().stats();
+
+        assert_eq!(
+            stats,
+            TreeStats {
+                balance_ratio: 1.0,
+                is_complete: true,
+                is_perfect: true,
+                ..TreeStats::default()
+            }
+        );
+    }
+
+    #[test]
+    fn perfect_tree() {
+        let stats = BinaryTree::from(vec![4, 2, 6, 1, 3, 5, 7]).stats();
+
+        assert_eq!(
+            stats,
+            TreeStats {
+                count: 7,
+                height: 3,
+                min_leaf_depth: 2,
+                max_depth: 2,
+                average_depth: 10.0 / 7.0,
+                leaves: 4,
+                internal_nodes: 3,
+                level_widths: vec![1, 2, 4],
+                balance_ratio: 1.0,
+                is_complete: true,
+                is_perfect: true,
+                is_degenerate: false,
+            }
+        );
+    }
+
+    #[test]
+    fn gap_on_last_level_is_not_complete() {
+        let stats = BinaryTree::from(vec![4, 2, 6, 1, 7]).stats();
+
+        assert_eq!(stats.level_widths, vec![1, 2, 2]);
+        assert_eq!(stats.balance_ratio, 1.0);
+        assert!(!stats.is_complete);
+        assert!(!stats.is_perfect);
+    }
+
+    #[test]
+    fn degenerate_tree_beyond_heap_positions() {
+        let stats: TreeStats = (0..200).collect::<BinaryTree<i32>>().stats();
+
+        assert_eq!(stats.height, 200);
+        assert_eq!(stats.min_leaf_depth, 199);
+        assert_eq!(stats.leaves, 1);
+        assert_eq!(stats.average_depth, 99.5);
+        assert_eq!(stats.balance_ratio, 8.0 / 200.0);
+        assert!(stats.is_degenerate);
+        assert!(!stats.is_complete);
+    }
+
+    #[test]
+    fn matches_height() {
+        let tree = BinaryTree::from(vec![8, 4, 12, 2, 1, 3, 14]);
+        let stats = tree.stats();
+
+        assert_eq!(stats.height, tree.height());
+        assert_eq!(stats.level_widths.iter().sum::<usize>(), tree.count());
+        assert_eq!(stats.leaves + stats.internal_nodes, tree.count());
+    }
+}
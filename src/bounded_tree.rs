@@ -0,0 +1,318 @@
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
+
+use crate::binary_tree::{BinaryTree, InOrder};
+
+/// The element a full [`BoundedTree`] gives up to make room for a new one.
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Evict {
+    /// Evicts the smallest element, which keeps the largest elements seen.
+    Min,
+    /// Evicts the largest element, which keeps the smallest elements seen.
+    Max,
+}
+
+/// A binary tree that holds at most `capacity` elements, and evicts the smallest or largest element when it is full.
+///
+/// Feeding a stream through the tree keeps the top `capacity` elements of the stream,
+/// where the top is the largest elements with [`Evict::Min`], and the smallest with [`Evict::Max`].
+///
+/// # Examples
+/// ```
+/// # use ds_rs::bounded_tree::{BoundedTree, Evict};
+/// let mut leaderboard = BoundedTree::new(3, Evict::Min);
+///
+/// for score in [40, 95, 10, 70, 88] {
+///     leaderboard.insert(score);
+/// }
+///
+/// assert!(leaderboard.iter().eq(&[70, 88, 95]));
+/// ```
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "json", serde(try_from = "BoundedTreeData<T>"))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BoundedTree<T> {
+    /// Always has a `max_len`, which is the capacity of the bounded tree.
+    tree: BinaryTree<T>,
+    evict: Evict,
+}
+
+/// The serialized form of a [`BoundedTree`], which is checked before it becomes one.
+#[cfg(feature = "json")]
+#[derive(Deserialize)]
+struct BoundedTreeData<T> {
+    tree: BinaryTree<T>,
+    evict: Evict,
+}
+
+#[cfg(feature = "json")]
+impl<T> TryFrom<BoundedTreeData<T>> for BoundedTree<T> {
+    type Error = &'static str;
+
+    fn try_from(data: BoundedTreeData<T>) -> Result<Self, Self::Error> {
+        match data.tree.max_len() {
+            Some(max_len) if data.tree.count() <= max_len => Ok(Self {
+                tree: data.tree,
+                evict: data.evict,
+            }),
+            Some(_) => Err("the tree of a bounded tree holds more elements than its max_len"),
+            None => Err("the tree of a bounded tree has no max_len"),
+        }
+    }
+}
+
+impl<T> BoundedTree<T> {
+    /// Constructs a new empty `BoundedTree<T>` that holds at most `capacity` elements, and evicts by the given policy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ds_rs::bounded_tree::{BoundedTree, Evict};
+    /// let tree: BoundedTree<i32> = BoundedTree::new(10, Evict::Max);
+    /// assert_eq!(tree.capacity(), 10);
+    /// assert_eq!(tree.evict(), Evict::Max);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new(capacity: usize, evict: Evict) -> Self {
+        Self {
+            tree: BinaryTree::with_max_len(capacity),
+            evict,
+        }
+    }
+
+    /// Returns the largest number of elements the tree holds.
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.tree.max_len().unwrap_or_default()
+    }
+
+    /// Returns the policy that decides which element is evicted.
+    #[inline]
+    pub fn evict(&self) -> Evict {
+        self.evict
+    }
+
+    /// Returns the number of elements in the tree.
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.tree.count()
+    }
+
+    /// Returns `true` if the tree contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.tree.is_empty()
+    }
+
+    /// Returns `true` if the tree holds `capacity` elements, so the next new element causes an eviction.
+    #[inline]
+    pub fn is_full(&self) -> bool {
+        self.tree.count() >= self.capacity()
+    }
+
+    /// Clears the tree of all elements, keeping its capacity and policy.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.tree.clear();
+    }
+
+    /// Returns the smallest element in the tree.
+    #[inline]
+    pub fn min(&self) -> Option<&T> {
+        self.tree.min()
+    }
+
+    /// Returns the largest element in the tree.
+    #[inline]
+    pub fn max(&self) -> Option<&T> {
+        self.tree.max()
+    }
+
+    /// Returns a non-consuming iterator over the tree that yields all items in ascending order.
+    #[inline]
+    #[must_use = "iterators are evaluated lazily"]
+    pub fn iter(&self) -> InOrder<'_, T> {
+        self.tree.in_order()
+    }
+
+    /// Returns a reference to the underlying `BinaryTree`.
+    #[inline]
+    pub fn as_tree(&self) -> &BinaryTree<T> {
+        &self.tree
+    }
+
+    /// Consumes the `BoundedTree`, and returns the underlying `BinaryTree`, which keeps `capacity` as its `max_len`.
+    #[inline]
+    pub fn into_tree(self) -> BinaryTree<T> {
+        self.tree
+    }
+}
+
+/// The methods in this implementation block require trait bounds to correctly apply the logic of the methods.
+///
+/// The trait bound in question is [`Ord`], which is used to compare elements in the tree.
+impl<T: Ord> BoundedTree<T> {
+    /// Inserts the value into the tree, and returns the element that does not fit, if any.
+    ///
+    /// When the tree is full, the value is compared with the element the policy evicts, found with
+    /// [`BinaryTree::min`] or [`BinaryTree::max`]. If the value ranks above it, that element is evicted
+    /// and returned, otherwise the value itself is returned. A value that is already in the tree
+    /// is returned as well, since duplicates are discarded.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::bounded_tree::{BoundedTree, Evict};
+    /// let mut smallest = BoundedTree::new(2, Evict::Max);
+    ///
+    /// assert_eq!(smallest.insert(5), None);
+    /// assert_eq!(smallest.insert(3), None);
+    /// assert_eq!(smallest.insert(4), Some(5));
+    /// assert_eq!(smallest.insert(9), Some(9));
+    /// assert_eq!(smallest.insert(3), Some(3));
+    /// ```
+    pub fn insert(&mut self, value: T) -> Option<T> {
+        if self.tree.contains(&value) {
+            return Some(value);
+        }
+        if !self.is_full() {
+            self.tree.insert(value);
+            return None;
+        }
+
+        let ranks_above = match self.evict {
+            Evict::Min => self.tree.min().is_some_and(|min| *min < value),
+            Evict::Max => self.tree.max().is_some_and(|max| value < *max),
+        };
+        if !ranks_above {
+            return Some(value);
+        }
+
+        let evicted = match self.evict {
+            Evict::Min => self.tree.pop_min(),
+            Evict::Max => self.tree.pop_max(),
+        };
+        self.tree.insert(value);
+
+        evicted
+    }
+
+    /// Returns `true` if the tree contains the given value.
+    #[inline]
+    pub fn contains(&self, target: &T) -> bool {
+        self.tree.contains(target)
+    }
+
+    /// Removes the element equal to the given value from the tree, and returns it.
+    #[inline]
+    pub fn remove(&mut self, target: &T) -> Option<T> {
+        self.tree.remove(target)
+    }
+}
+
+impl<T: Ord> Extend<T> for BoundedTree<T> {
+    /// Inserts the contents of the provided iterator into the tree, evicting elements as needed.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a BoundedTree<T> {
+    type Item = &'a T;
+
+    type IntoIter = InOrder<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod eviction {
+    use super::{BoundedTree, Evict};
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    #[test]
+    fn keeps_top_k_of_stream() {
+        let mut rng = StdRng::seed_from_u64(49);
+        let stream: Vec<i32> = (0..2000).map(|_| rng.gen_range(0..5000)).collect();
+        let mut sorted = stream.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+
+        let mut largest = BoundedTree::new(25, Evict::Min);
+        let mut smallest = BoundedTree::new(25, Evict::Max);
+        largest.extend(stream.iter().copied());
+        smallest.extend(stream);
+
+        assert!(largest.iter().eq(sorted.iter().rev().take(25).rev()));
+        assert!(smallest.iter().eq(sorted.iter().take(25)));
+        assert!(largest.is_full() && smallest.is_full());
+    }
+
+    #[test]
+    fn returns_what_does_not_fit() {
+        let mut tree = BoundedTree::new(2, Evict::Min);
+
+        assert_eq!(tree.insert(5), None);
+        assert_eq!(tree.insert(5), Some(5));
+        assert_eq!(tree.insert(7), None);
+        assert_eq!(tree.insert(1), Some(1));
+        assert_eq!(tree.insert(6), Some(5));
+        assert!(tree.iter().eq(&[6, 7]));
+    }
+
+    #[test]
+    fn zero_capacity_keeps_nothing() {
+        let mut tree = BoundedTree::new(0, Evict::Max);
+
+        assert_eq!(tree.insert(1), Some(1));
+        assert!(tree.is_empty());
+        assert!(tree.is_full());
+    }
+
+    #[test]
+    fn removal_makes_room() {
+        let mut tree = BoundedTree::new(2, Evict::Min);
+        tree.extend([1, 2]);
+
+        assert_eq!(tree.remove(&2), Some(2));
+        assert_eq!(tree.insert(0), None);
+        assert_eq!(tree.min(), Some(&0));
+        assert_eq!(tree.max(), Some(&1));
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod json {
+    use super::{BoundedTree, Evict};
+
+    #[test]
+    fn leaderboard_survives_restart() {
+        let mut tree = BoundedTree::new(3, Evict::Min);
+        tree.extend([40, 95, 10, 70, 88]);
+
+        let json = serde_json::to_string(&tree).expect("should parse tree into json");
+        let mut actual: BoundedTree<i32> =
+            serde_json::from_str(&json).expect("should parse json into tree");
+
+        assert_eq!(actual, tree);
+        assert_eq!(actual.insert(90), Some(70));
+        assert!(actual.iter().eq(&[88, 90, 95]));
+        assert_eq!(actual.into_tree().max_len(), Some(3));
+    }
+
+    #[test]
+    fn rejects_tree_without_a_valid_max_len() {
+        for json in [
+            r#"{"tree":{"root":null,"count":0},"evict":"Min"}"#,
+            r#"{"tree":{"root":{"value":1,"left":null,"right":null},"count":1,"max_len":0},"evict":"Min"}"#,
+        ] {
+            let error = serde_json::from_str::<BoundedTree<i32>>(json).map(|_| ());
+            assert!(error.is_err_and(|error| error.to_string().contains("max_len")));
+        }
+    }
+}
@@ -0,0 +1,578 @@
+use std::sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+use std::vec;
+
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
+
+use crate::b_tree::BTree;
+
+/// The number of shards used by [`ConcurrentTree::from`].
+pub const DEFAULT_SHARDS: usize = 16;
+
+/// An ordered set that many threads can read and change at the same time through `&self`.
+///
+/// The elements are divided into shards by ranges of values, and every shard is a [`BTree`]
+/// behind its own [`RwLock`]. Operations on a single value only lock the shard holding it, so
+/// readers never wait for writers in other shards. The shards are decided when the tree is created,
+/// and work spreads best when the elements are spread evenly between the boundaries.
+///
+/// A panic in another thread while it held a lock does not make the tree unusable, the elements
+/// of the shard are used as that thread left them.
+///
+/// # Examples
+/// ```
+/// # use ds_rs::concurrent::ConcurrentTree;
+/// let tree = ConcurrentTree::with_boundaries([100, 200]);
+///
+/// std::thread::scope(|scope| {
+///     for thread in 0..3 {
+///         let tree = &tree;
+///         scope.spawn(move || {
+///             for value in (0..300).filter(|value| value % 3 == thread) {
+///                 tree.insert(value);
+///             }
+///         });
+///     }
+/// });
+///
+/// assert_eq!(tree.count(), 300);
+/// assert!(tree.snapshot().eq(0..300));
+/// ```
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "json",
+    serde(
+        try_from = "ConcurrentTreeData<T>",
+        bound(deserialize = "T: Ord + Deserialize<'de>")
+    )
+)]
+#[derive(Debug)]
+pub struct ConcurrentTree<T> {
+    first: RwLock<BTree<T>>,
+    rest: Vec<(T, RwLock<BTree<T>>)>,
+}
+
+/// The serialized form of a [`ConcurrentTree`], which is checked before it becomes one.
+#[cfg(feature = "json")]
+#[derive(Deserialize)]
+struct ConcurrentTreeData<T> {
+    first: RwLock<BTree<T>>,
+    rest: Vec<(T, RwLock<BTree<T>>)>,
+}
+
+#[cfg(feature = "json")]
+impl<T: Ord> TryFrom<ConcurrentTreeData<T>> for ConcurrentTree<T> {
+    type Error = &'static str;
+
+    fn try_from(data: ConcurrentTreeData<T>) -> Result<Self, Self::Error> {
+        let tree = Self {
+            first: data.first,
+            rest: data.rest,
+        };
+
+        let boundaries = || tree.rest.iter().map(|(boundary, _)| boundary);
+        if boundaries().zip(boundaries().skip(1)).any(|(a, b)| a >= b) {
+            return Err("the boundaries of a concurrent tree are not strictly ascending");
+        }
+
+        let starts = std::iter::once(None).chain(boundaries().map(Some));
+        let ends = boundaries().map(Some).chain(std::iter::once(None));
+        for ((start, end), shard) in starts.zip(ends).zip(tree.shards()) {
+            let shard = read(shard);
+            let below_start = start.is_some_and(|start| shard.min().is_some_and(|min| min < start));
+            let past_end = end.is_some_and(|end| shard.max().is_some_and(|max| max >= end));
+            if below_start || past_end {
+                return Err("a shard of a concurrent tree holds values outside its range");
+            }
+        }
+
+        Ok(tree)
+    }
+}
+
+impl<T> ConcurrentTree<T> {
+    /// Constructs a new empty `ConcurrentTree<T>` with a single shard.
+    ///
+    /// Every operation locks the same shard, see [`ConcurrentTree::with_boundaries`] to spread them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ds_rs::concurrent::ConcurrentTree;
+    /// let tree: ConcurrentTree<i32> = ConcurrentTree::new();
+    /// assert_eq!(tree.shard_count(), 1);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            first: RwLock::new(BTree::new()),
+            rest: Vec::new(),
+        }
+    }
+
+    /// Returns the number of shards, which is one more than the number of boundaries.
+    #[inline]
+    pub fn shard_count(&self) -> usize {
+        self.rest.len() + 1
+    }
+
+    /// Returns `true` if the tree contains no elements.
+    ///
+    /// The answer may be outdated as soon as it is returned, if other threads change the tree.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::concurrent::ConcurrentTree;
+    /// let tree = ConcurrentTree::new();
+    /// assert!(tree.is_empty());
+    ///
+    /// tree.insert(0);
+    /// assert!(!tree.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.shards().all(|shard| read(shard).is_empty())
+    }
+
+    /// Returns the number of elements in the tree.
+    ///
+    /// The shards are counted one at a time, so the count may miss changes made while counting.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::concurrent::ConcurrentTree;
+    /// let tree = ConcurrentTree::with_boundaries([10]);
+    /// tree.insert(5);
+    /// tree.insert(15);
+    /// assert_eq!(tree.count(), 2);
+    /// ```
+    pub fn count(&self) -> usize {
+        self.shards().map(|shard| read(shard).count()).sum()
+    }
+
+    /// Clears the tree of all elements, keeping its shards.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::concurrent::ConcurrentTree;
+    /// let tree = ConcurrentTree::from(vec![1, 2, 3]);
+    ///
+    /// tree.clear();
+    /// assert!(tree.is_empty());
+    /// ```
+    pub fn clear(&self) {
+        for shard in self.shards() {
+            write(shard).clear();
+        }
+    }
+
+    /// Returns an iterator over a copy of all elements in ascending order.
+    ///
+    /// Every shard is read locked at once while the elements are copied, so the snapshot holds the
+    /// elements of a single moment, and later changes do not affect it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::concurrent::ConcurrentTree;
+    /// let tree = ConcurrentTree::with_boundaries([2]);
+    /// tree.insert(3);
+    /// tree.insert(1);
+    ///
+    /// let snapshot = tree.snapshot();
+    /// tree.insert(2);
+    ///
+    /// assert_eq!(snapshot.collect::<Vec<_>>(), vec![1, 3]);
+    /// ```
+    #[must_use = "iterators are evaluated lazily"]
+    pub fn snapshot(&self) -> Snapshot<T>
+    where
+        T: Clone,
+    {
+        // shards are always locked in ascending order, and writers hold one lock at a time
+        let guards: Vec<_> = self.shards().map(read).collect();
+        let values: Vec<_> = guards
+            .iter()
+            .flat_map(|shard| shard.iter().cloned())
+            .collect();
+
+        Snapshot {
+            values: values.into_iter(),
+        }
+    }
+
+    /// Returns every shard in ascending order of their ranges.
+    fn shards(&self) -> impl Iterator<Item = &RwLock<BTree<T>>> {
+        std::iter::once(&self.first).chain(self.rest.iter().map(|(_, shard)| shard))
+    }
+}
+
+impl<T> Default for ConcurrentTree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The methods in this implementation block require trait bounds to correctly apply the logic of the methods.
+///
+/// The trait bound in question is [`Ord`], which is used to find the shard of a value and its place in it.
+impl<T: Ord> ConcurrentTree<T> {
+    /// Constructs a new empty `ConcurrentTree<T>`, with a shard between every pair of neighbouring boundaries.
+    ///
+    /// Values below the first boundary go in the first shard, and values equal to a boundary go in
+    /// the shard that starts at it. The boundaries are sorted, and duplicates are discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ds_rs::concurrent::ConcurrentTree;
+    /// let tree: ConcurrentTree<i32> = ConcurrentTree::with_boundaries([0, 100, 100]);
+    /// assert_eq!(tree.shard_count(), 3);
+    /// ```
+    #[must_use]
+    pub fn with_boundaries<I: IntoIterator<Item = T>>(boundaries: I) -> Self {
+        let mut boundaries: Vec<_> = boundaries.into_iter().collect();
+        boundaries.sort_unstable();
+        boundaries.dedup();
+
+        Self {
+            first: RwLock::new(BTree::new()),
+            rest: boundaries
+                .into_iter()
+                .map(|boundary| (boundary, RwLock::new(BTree::new())))
+                .collect(),
+        }
+    }
+
+    /// Inserts the provided value into the `ConcurrentTree`, and returns `true` if it was not already present.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::concurrent::ConcurrentTree;
+    /// let tree = ConcurrentTree::new();
+    /// assert!(tree.insert(1));
+    /// assert!(!tree.insert(1));
+    /// ```
+    pub fn insert(&self, value: T) -> bool {
+        let mut shard = write(self.shard(&value));
+        let count = shard.count();
+        shard.insert(value);
+
+        shard.count() > count
+    }
+
+    /// Removes the provided value from the `ConcurrentTree`, and returns it if it was present.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::concurrent::ConcurrentTree;
+    /// let tree = ConcurrentTree::from(vec![1, 2]);
+    /// assert_eq!(tree.remove(&1), Some(1));
+    /// assert_eq!(tree.remove(&1), None);
+    /// ```
+    pub fn remove(&self, target: &T) -> Option<T> {
+        write(self.shard(target)).remove(target)
+    }
+
+    /// Returns `true` if the `ConcurrentTree` contains the given value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::concurrent::ConcurrentTree;
+    /// let tree = ConcurrentTree::from(vec![1, 2]);
+    /// assert!(tree.contains(&1));
+    /// assert!(!tree.contains(&3));
+    /// ```
+    pub fn contains(&self, target: &T) -> bool {
+        read(self.shard(target)).contains(target)
+    }
+
+    /// Returns the shard whose range holds the value.
+    fn shard(&self, value: &T) -> &RwLock<BTree<T>> {
+        let starts_before = self.rest.partition_point(|(boundary, _)| boundary <= value);
+
+        match starts_before
+            .checked_sub(1)
+            .and_then(|index| self.rest.get(index))
+        {
+            Some((_, shard)) => shard,
+            None => &self.first,
+        }
+    }
+}
+
+/// Acquires a read lock on the shard, using the elements as they are if another thread panicked while holding the lock.
+fn read<T>(shard: &RwLock<BTree<T>>) -> RwLockReadGuard<'_, BTree<T>> {
+    shard.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Acquires a write lock on the shard, using the elements as they are if another thread panicked while holding the lock.
+fn write<T>(shard: &RwLock<BTree<T>>) -> RwLockWriteGuard<'_, BTree<T>> {
+    shard.write().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl<T: Ord + Clone> From<Vec<T>> for ConcurrentTree<T> {
+    /// Creates a `ConcurrentTree<T>` from `Vec<T>`, with [`DEFAULT_SHARDS`] shards holding about as many elements each.
+    ///
+    /// Duplicate values are discarded.
+    fn from(mut vec: Vec<T>) -> Self {
+        vec.sort_unstable();
+        vec.dedup();
+
+        let step = vec.len().div_ceil(DEFAULT_SHARDS).max(1);
+        let tree = Self::with_boundaries(vec.iter().step_by(step).skip(1).cloned());
+        for value in vec {
+            tree.insert(value);
+        }
+
+        tree
+    }
+}
+
+impl<T: Ord + Clone> FromIterator<T> for ConcurrentTree<T> {
+    /// Constructs a `ConcurrentTree<T>` from an iterator, with [`DEFAULT_SHARDS`] shards holding about as many elements each.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+impl<T: Ord> Extend<T> for ConcurrentTree<T> {
+    /// Extends the `ConcurrentTree` with the contents of the provided iterator, keeping its shards.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<T> IntoIterator for ConcurrentTree<T> {
+    type Item = T;
+
+    type IntoIter = IntoIter<T>;
+
+    /// Returns a consuming iterator over the `ConcurrentTree`.
+    ///
+    /// The iterator yields all elements in ascending order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::concurrent::ConcurrentTree;
+    /// let tree = ConcurrentTree::from(vec![3, 1, 2]);
+    /// assert_eq!(tree.into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            values: std::iter::once(self.first)
+                .chain(self.rest.into_iter().map(|(_, shard)| shard))
+                .flat_map(|shard| shard.into_inner().unwrap_or_else(PoisonError::into_inner))
+                .collect::<Vec<_>>()
+                .into_iter(),
+        }
+    }
+}
+
+/// An iterator that moves out of the `ConcurrentTree`.
+///
+/// This `struct` is created by the `into_iter` method on [`ConcurrentTree`] (provided by the [`IntoIterator`] trait).
+pub struct IntoIter<T> {
+    values: vec::IntoIter<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.values.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+/// An iterator over a copy of the elements of a `ConcurrentTree`, taken at a single moment.
+///
+/// This `struct` is created by the `snapshot` method on [`ConcurrentTree`].
+pub struct Snapshot<T> {
+    values: vec::IntoIter<T>,
+}
+
+impl<T> Iterator for Snapshot<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.values.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.values.size_hint()
+    }
+}
+
+impl<T> DoubleEndedIterator for Snapshot<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.values.next_back()
+    }
+}
+
+impl<T> ExactSizeIterator for Snapshot<T> {}
+
+#[cfg(test)]
+mod sharding {
+    use super::ConcurrentTree;
+
+    #[test]
+    fn values_go_to_the_shard_of_their_range() {
+        let tree = ConcurrentTree::with_boundaries([10, 20]);
+        for value in [5, 10, 19, 20, 25] {
+            tree.insert(value);
+        }
+
+        let shards: Vec<Vec<i32>> = tree
+            .shards()
+            .map(|shard| super::read(shard).iter().copied().collect())
+            .collect();
+        assert_eq!(shards, vec![vec![5], vec![10, 19], vec![20, 25]]);
+    }
+
+    #[test]
+    fn from_vec_spreads_values_evenly() {
+        let tree: ConcurrentTree<i32> = (0..1600).rev().collect();
+
+        assert_eq!(tree.shard_count(), 16);
+        assert!(tree.shards().all(|shard| super::read(shard).count() == 100));
+    }
+
+    #[test]
+    fn small_vec_gets_a_shard_per_value() {
+        let tree = ConcurrentTree::from(vec![3, 1, 2, 2]);
+
+        assert_eq!(tree.shard_count(), 3);
+        assert_eq!(tree.count(), 3);
+    }
+
+    #[test]
+    fn poisoned_shard_stays_usable() {
+        let tree = ConcurrentTree::from(vec![1, 2, 3]);
+
+        let _ = std::thread::scope(|scope| {
+            scope
+                .spawn(|| {
+                    let _guard = super::write(tree.shard(&2));
+                    panic!("poison the shard");
+                })
+                .join()
+        });
+
+        assert!(tree.insert(4));
+        assert!(tree.contains(&2));
+        assert!(tree.snapshot().eq(1..=4));
+    }
+}
+
+#[cfg(test)]
+mod stress {
+    use super::ConcurrentTree;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use std::collections::BTreeSet;
+
+    const WRITERS: u64 = 8;
+
+    #[test]
+    fn concurrent_writers_match_sequential_model() {
+        let tree = ConcurrentTree::with_boundaries((1..8).map(|shard| shard * 1000));
+
+        let models: Vec<BTreeSet<u64>> = std::thread::scope(|scope| {
+            let tree = &tree;
+            let writers: Vec<_> = (0..WRITERS)
+                .map(|writer| {
+                    scope.spawn(move || {
+                        // every writer owns the values equal to its index modulo the writer count,
+                        // which are spread over every shard
+                        let mut rng = StdRng::seed_from_u64(38 + writer);
+                        let mut model = BTreeSet::new();
+
+                        for _ in 0..5000 {
+                            let value = rng.gen_range(0..1000) * WRITERS + writer;
+                            if rng.gen_bool(0.6) {
+                                assert_eq!(tree.insert(value), model.insert(value));
+                            } else {
+                                assert_eq!(tree.remove(&value).is_some(), model.remove(&value));
+                            }
+                            assert_eq!(tree.contains(&value), model.contains(&value));
+                        }
+
+                        model
+                    })
+                })
+                .collect();
+
+            let readers: Vec<_> = (0..2)
+                .map(|_| {
+                    scope.spawn(move || {
+                        for _ in 0..50 {
+                            let snapshot: Vec<_> = tree.snapshot().collect();
+                            assert!(snapshot.windows(2).all(|pair| pair.first() < pair.last()));
+                        }
+                    })
+                })
+                .collect();
+
+            for reader in readers {
+                assert!(reader.join().is_ok());
+            }
+            writers
+                .into_iter()
+                .filter_map(|writer| writer.join().ok())
+                .collect()
+        });
+
+        assert_eq!(models.len(), WRITERS as usize);
+        let expected: BTreeSet<_> = models.into_iter().flatten().collect();
+        assert_eq!(tree.count(), expected.len());
+        assert!(tree.snapshot().eq(expected.iter().copied()));
+        assert!(tree.into_iter().eq(expected));
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod json {
+    use super::ConcurrentTree;
+
+    #[test]
+    fn round_trips_through_json() {
+        let tree: ConcurrentTree<i32> = (0..100).collect();
+
+        let json = serde_json::to_string(&tree).expect("should parse tree into json");
+        let actual: ConcurrentTree<i32> =
+            serde_json::from_str(&json).expect("should parse json into tree");
+
+        assert_eq!(actual.shard_count(), tree.shard_count());
+        assert!(actual.snapshot().eq(tree.snapshot()));
+    }
+
+    #[test]
+    fn rejects_unsorted_boundaries() {
+        let tree: ConcurrentTree<i32> = ConcurrentTree::with_boundaries([10, 20]);
+
+        let json = serde_json::to_string(&tree).expect("should parse tree into json");
+        let json = json.replacen("10", "30", 1);
+
+        assert!(serde_json::from_str::<ConcurrentTree<i32>>(&json)
+            .is_err_and(|error| error.to_string().contains("strictly ascending")));
+    }
+
+    #[test]
+    fn rejects_values_outside_their_shard() {
+        let tree = ConcurrentTree::with_boundaries([10]);
+        tree.insert(5);
+        tree.insert(15);
+
+        let json = serde_json::to_string(&tree).expect("should parse tree into json");
+        let json = json.replacen("10", "3", 1);
+
+        assert!(serde_json::from_str::<ConcurrentTree<i32>>(&json)
+            .is_err_and(|error| error.to_string().contains("outside its range")));
+    }
+}
@@ -0,0 +1,588 @@
+use std::ops::{AddAssign, Bound, Neg, RangeBounds, Sub};
+
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
+
+/// A fixed-length sequence that adds to single elements and sums prefixes in logarithmic time.
+///
+/// Each slot `i`, counting from `1`, holds the sum of the `i & -i` elements that end at `i`,
+/// so every prefix is the sum of at most `log(n)` slots.
+/// The default value of `T` is used as zero, and subtraction is used to turn prefix sums into range sums.
+///
+/// # Examples
+/// ```
+/// # use ds_rs::fenwick_tree::FenwickTree;
+/// let mut tree = FenwickTree::from(vec![1, 2, 3, 4, 5]);
+///
+/// tree.add(2, 10);
+/// assert_eq!(tree.prefix_sum(3), Some(16));
+/// assert_eq!(tree.range_sum(2..4), Some(17));
+/// assert_eq!(tree.lower_bound(&16), Some(2));
+/// ```
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FenwickTree<T> {
+    slots: Vec<T>,
+}
+
+impl<T> FenwickTree<T> {
+    /// Returns the number of elements in the sequence.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::fenwick_tree::FenwickTree;
+    /// let tree: FenwickTree<i32> = FenwickTree::new(8);
+    /// assert_eq!(tree.len(), 8);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Returns `true` if the sequence contains no elements.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::fenwick_tree::FenwickTree;
+    /// let tree: FenwickTree<i32> = FenwickTree::new(0);
+    /// assert!(tree.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.slots.is_empty()
+    }
+}
+
+/// The methods in this implementation block require trait bounds to correctly apply the logic of the methods.
+///
+/// The trait bounds [`Default`], [`AddAssign`] and [`Sub`] make `T` behave like a group of numbers,
+/// where the default value is zero. [`Clone`] is used to copy sums out of the tree.
+impl<T> FenwickTree<T>
+where
+    T: Default + Clone + AddAssign + Sub<Output = T>,
+{
+    /// Constructs a new `FenwickTree<T>` of `len` zeroes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ds_rs::fenwick_tree::FenwickTree;
+    /// let tree: FenwickTree<i32> = FenwickTree::new(8);
+    /// assert_eq!(tree.prefix_sum(8), Some(0));
+    /// ```
+    #[must_use]
+    pub fn new(len: usize) -> Self {
+        Self {
+            slots: vec![T::default(); len],
+        }
+    }
+
+    /// Adds `delta` to the element at `index`.
+    ///
+    /// Returns `false` and leaves the sequence unchanged if the index is out of bounds.
+    ///
+    /// # Time Complexity
+    ///
+    /// The delta is added to every slot covering the element, of which there are at most `log(n)`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::fenwick_tree::FenwickTree;
+    /// let mut tree: FenwickTree<i32> = FenwickTree::new(4);
+    /// assert!(tree.add(1, 5));
+    /// assert_eq!(tree.get(1), Some(5));
+    ///
+    /// assert!(!tree.add(4, 5));
+    /// ```
+    pub fn add(&mut self, index: usize, delta: T) -> bool {
+        if index >= self.len() {
+            return false;
+        }
+
+        let mut position = index + 1;
+        while let Some(slot) = self.slots.get_mut(position - 1) {
+            *slot += delta.clone();
+            position += lowest_bit(position);
+        }
+
+        true
+    }
+
+    /// Returns the sum of the first `end` elements, or `None` if there are fewer than `end` elements.
+    ///
+    /// # Time Complexity
+    ///
+    /// The prefix is covered by at most `log(n)` slots.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::fenwick_tree::FenwickTree;
+    /// let tree = FenwickTree::from(vec![1, 2, 3]);
+    /// assert_eq!(tree.prefix_sum(0), Some(0));
+    /// assert_eq!(tree.prefix_sum(2), Some(3));
+    /// assert_eq!(tree.prefix_sum(4), None);
+    /// ```
+    pub fn prefix_sum(&self, end: usize) -> Option<T> {
+        if end > self.len() {
+            return None;
+        }
+
+        let mut sum = T::default();
+        let mut position = end;
+        while position > 0 {
+            sum += self.slots.get(position - 1)?.clone();
+            position -= lowest_bit(position);
+        }
+
+        Some(sum)
+    }
+
+    /// Returns the sum of the elements within the range, or `None` if the range reaches outside the sequence.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::fenwick_tree::FenwickTree;
+    /// let tree = FenwickTree::from(vec![1, 2, 3, 4]);
+    /// assert_eq!(tree.range_sum(1..3), Some(5));
+    /// assert_eq!(tree.range_sum(2..), Some(7));
+    /// assert_eq!(tree.range_sum(..=4), None);
+    /// ```
+    pub fn range_sum<R: RangeBounds<usize>>(&self, range: R) -> Option<T> {
+        let (start, end) = bounds(&range, self.len())?;
+
+        Some(self.prefix_sum(end)? - self.prefix_sum(start)?)
+    }
+
+    /// Returns the element at `index`, or `None` if the index is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::fenwick_tree::FenwickTree;
+    /// let tree = FenwickTree::from(vec![1, 2, 3]);
+    /// assert_eq!(tree.get(2), Some(3));
+    /// assert_eq!(tree.get(3), None);
+    /// ```
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<T> {
+        self.range_sum(index..=index)
+    }
+}
+
+/// The methods in this implementation block require trait bounds to correctly apply the logic of the methods.
+///
+/// On top of the bounds of the block above, [`PartialOrd`] is used to compare sums with a target.
+impl<T> FenwickTree<T>
+where
+    T: Default + Clone + AddAssign + Sub<Output = T> + PartialOrd,
+{
+    /// Returns the first index at which the prefix sum, including the element at the index, reaches `target`.
+    ///
+    /// Returns `None` if the sum of all elements is below `target`. The search assumes that no
+    /// element is negative, so that the prefix sums never decrease.
+    ///
+    /// # Time Complexity
+    ///
+    /// The index is found by descending through the slots, which takes `log(n)` time.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::fenwick_tree::FenwickTree;
+    /// let tree = FenwickTree::from(vec![3, 0, 2, 5]);
+    /// assert_eq!(tree.lower_bound(&3), Some(0));
+    /// assert_eq!(tree.lower_bound(&4), Some(2));
+    /// assert_eq!(tree.lower_bound(&10), Some(3));
+    /// assert_eq!(tree.lower_bound(&11), None);
+    /// ```
+    pub fn lower_bound(&self, target: &T) -> Option<usize> {
+        let mut position = 0;
+        let mut remaining = target.clone();
+        let mut step = self.len().checked_next_power_of_two()?;
+
+        while step > 0 {
+            if let Some(slot) = self.slots.get(position + step - 1) {
+                if slot < &remaining {
+                    position += step;
+                    remaining = remaining - slot.clone();
+                }
+            }
+            step /= 2;
+        }
+
+        (position < self.len()).then_some(position)
+    }
+}
+
+/// Returns the half-open bounds of `range` within a sequence of `len` elements, or `None` if they fall outside it.
+fn bounds<R: RangeBounds<usize>>(range: &R, len: usize) -> Option<(usize, usize)> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1)?,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end.checked_add(1)?,
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => len,
+    };
+
+    (start <= end && end <= len).then_some((start, end))
+}
+
+/// Returns the lowest set bit of `position`, which is the number of elements its slot covers.
+#[inline]
+fn lowest_bit(position: usize) -> usize {
+    position & position.wrapping_neg()
+}
+
+impl<T> From<Vec<T>> for FenwickTree<T>
+where
+    T: Default + Clone + AddAssign + Sub<Output = T>,
+{
+    /// Creates a `FenwickTree<T>` over the elements of `Vec<T>`.
+    ///
+    /// Every slot passes its sum on to the next slot covering it, which takes linear time.
+    fn from(vec: Vec<T>) -> Self {
+        let mut slots = vec;
+
+        for position in 1..=slots.len() {
+            let parent = position + lowest_bit(position);
+
+            if let Some([child, .., last]) = slots.get_mut(position - 1..parent) {
+                *last += child.clone();
+            }
+        }
+
+        Self { slots }
+    }
+}
+
+impl<T> FromIterator<T> for FenwickTree<T>
+where
+    T: Default + Clone + AddAssign + Sub<Output = T>,
+{
+    /// Constructs a `FenwickTree<T>` over the elements of an iterator.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+/// A fixed-length sequence that adds to whole ranges and reads single elements in logarithmic time.
+///
+/// The tree stores the differences between neighbouring elements in a [`FenwickTree`],
+/// so adding to a range changes two differences, and an element is the sum of the differences up to it.
+///
+/// The differences can be negative, so `T` must be negatable. Unsigned elements work when wrapped in
+/// [`Wrapping`](std::num::Wrapping), as the wrapped differences still sum up to the elements.
+///
+/// # Examples
+/// ```
+/// # use ds_rs::fenwick_tree::RangeFenwickTree;
+/// let mut tree: RangeFenwickTree<i32> = RangeFenwickTree::new(5);
+///
+/// tree.add(1..4, 10);
+/// tree.add(3.., 1);
+/// assert_eq!(tree.get(0), Some(0));
+/// assert_eq!(tree.get(3), Some(11));
+/// assert_eq!(tree.get(4), Some(1));
+/// ```
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeFenwickTree<T> {
+    differences: FenwickTree<T>,
+}
+
+impl<T> RangeFenwickTree<T> {
+    /// Returns the number of elements in the sequence.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::fenwick_tree::RangeFenwickTree;
+    /// let tree: RangeFenwickTree<i32> = RangeFenwickTree::new(8);
+    /// assert_eq!(tree.len(), 8);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.differences.len()
+    }
+
+    /// Returns `true` if the sequence contains no elements.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::fenwick_tree::RangeFenwickTree;
+    /// let tree: RangeFenwickTree<i32> = RangeFenwickTree::new(0);
+    /// assert!(tree.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.differences.is_empty()
+    }
+}
+
+/// The methods in this implementation block require trait bounds to correctly apply the logic of the methods.
+///
+/// The trait bounds [`Default`], [`AddAssign`], [`Sub`] and [`Neg`] make `T` behave like a group of numbers,
+/// where the default value is zero. [`Clone`] is used to copy sums out of the tree.
+impl<T> RangeFenwickTree<T>
+where
+    T: Default + Clone + AddAssign + Sub<Output = T> + Neg<Output = T>,
+{
+    /// Constructs a new `RangeFenwickTree<T>` of `len` zeroes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ds_rs::fenwick_tree::RangeFenwickTree;
+    /// let tree: RangeFenwickTree<i32> = RangeFenwickTree::new(8);
+    /// assert_eq!(tree.get(7), Some(0));
+    /// ```
+    #[must_use]
+    pub fn new(len: usize) -> Self {
+        Self {
+            differences: FenwickTree::new(len),
+        }
+    }
+
+    /// Adds `delta` to every element within the range.
+    ///
+    /// Returns `false` and leaves the sequence unchanged if the range reaches outside of it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::fenwick_tree::RangeFenwickTree;
+    /// let mut tree: RangeFenwickTree<i32> = RangeFenwickTree::new(4);
+    /// assert!(tree.add(1..3, 5));
+    /// assert_eq!(tree.get(2), Some(5));
+    ///
+    /// assert!(!tree.add(2..5, 5));
+    /// ```
+    pub fn add<R: RangeBounds<usize>>(&mut self, range: R, delta: T) -> bool {
+        let Some((start, end)) = bounds(&range, self.len()) else {
+            return false;
+        };
+
+        if start < end {
+            // the difference past the last element does not exist, as nothing follows it
+            if end < self.len() {
+                self.differences.add(end, -delta.clone());
+            }
+            self.differences.add(start, delta);
+        }
+
+        true
+    }
+
+    /// Returns the element at `index`, or `None` if the index is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::fenwick_tree::RangeFenwickTree;
+    /// let mut tree = RangeFenwickTree::from(vec![1, 2, 3]);
+    /// tree.add(.., 10);
+    /// assert_eq!(tree.get(1), Some(12));
+    /// assert_eq!(tree.get(3), None);
+    /// ```
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<T> {
+        self.differences.prefix_sum(index.checked_add(1)?)
+    }
+}
+
+impl<T> From<Vec<T>> for RangeFenwickTree<T>
+where
+    T: Default + Clone + AddAssign + Sub<Output = T> + Neg<Output = T>,
+{
+    /// Creates a `RangeFenwickTree<T>` over the elements of `Vec<T>`.
+    fn from(vec: Vec<T>) -> Self {
+        let mut previous = T::default();
+        let differences = vec
+            .into_iter()
+            .map(|value| {
+                let difference = value.clone() - previous.clone();
+                previous = value;
+                difference
+            })
+            .collect();
+
+        Self { differences }
+    }
+}
+
+impl<T> FromIterator<T> for RangeFenwickTree<T>
+where
+    T: Default + Clone + AddAssign + Sub<Output = T> + Neg<Output = T>,
+{
+    /// Constructs a `RangeFenwickTree<T>` over the elements of an iterator.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+#[cfg(test)]
+mod point_update {
+    use super::FenwickTree;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    #[test]
+    fn builds_the_same_slots_as_adding() {
+        let values: Vec<i64> = (1..=37).map(|v| v * v % 11).collect();
+        let mut added = FenwickTree::new(values.len());
+
+        for (index, value) in values.iter().enumerate() {
+            added.add(index, *value);
+        }
+
+        assert_eq!(FenwickTree::from(values), added);
+    }
+
+    #[test]
+    fn matches_naive_sums() {
+        let mut rng = StdRng::seed_from_u64(34);
+        let mut naive: Vec<i64> = (0..100).map(|_| rng.gen_range(-50..50)).collect();
+        let mut tree = FenwickTree::from(naive.clone());
+
+        for _ in 0..2000 {
+            let start = rng.gen_range(0..=naive.len());
+            let end = rng.gen_range(start..=naive.len());
+
+            if rng.gen_bool(0.5) && start < naive.len() {
+                let delta = rng.gen_range(-50..50);
+                assert!(tree.add(start, delta));
+                if let Some(value) = naive.get_mut(start) {
+                    *value += delta;
+                }
+            } else {
+                let expected = naive.get(start..end).map(|values| values.iter().sum());
+                assert_eq!(tree.range_sum(start..end), expected);
+                assert_eq!(
+                    tree.prefix_sum(end),
+                    naive.get(..end).map(|values| values.iter().sum())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn out_of_bounds_access_is_rejected() {
+        let mut tree = FenwickTree::from(vec![1, 2, 3]);
+        let before = tree.clone();
+
+        assert!(!tree.add(3, 1));
+        assert!(!tree.add(usize::MAX, 1));
+        assert_eq!(tree.prefix_sum(4), None);
+        assert_eq!(tree.range_sum(2..5), None);
+        assert_eq!(tree.range_sum(..=usize::MAX), None);
+        assert_eq!(tree.get(3), None);
+        assert_eq!(tree, before);
+    }
+
+    #[test]
+    fn lower_bound_matches_linear_scan() {
+        let mut rng = StdRng::seed_from_u64(134);
+
+        for len in [0, 1, 2, 7, 8, 9, 100] {
+            let values: Vec<u32> = (0..len).map(|_| rng.gen_range(0..5)).collect();
+            let tree = FenwickTree::from(values.clone());
+            let total: u32 = values.iter().sum();
+
+            for target in 0..=total + 1 {
+                let expected = values
+                    .iter()
+                    .scan(0, |sum, value| {
+                        *sum += value;
+                        Some(*sum)
+                    })
+                    .position(|sum| sum >= target);
+
+                assert_eq!(tree.lower_bound(&target), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn empty_tree() {
+        let tree: FenwickTree<i32> = FenwickTree::new(0);
+
+        assert_eq!(tree.prefix_sum(0), Some(0));
+        assert_eq!(tree.range_sum(..), Some(0));
+        assert_eq!(tree.lower_bound(&0), None);
+    }
+}
+
+#[cfg(test)]
+mod range_update {
+    use super::RangeFenwickTree;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use std::num::Wrapping;
+
+    #[test]
+    fn matches_naive_elements() {
+        let mut rng = StdRng::seed_from_u64(234);
+        let mut naive: Vec<i64> = (0..100).map(|_| rng.gen_range(-50..50)).collect();
+        let mut tree = RangeFenwickTree::from(naive.clone());
+
+        for _ in 0..2000 {
+            let start = rng.gen_range(0..=naive.len());
+            let end = rng.gen_range(start..=naive.len());
+            let delta = rng.gen_range(-50..50);
+
+            assert!(tree.add(start..end, delta));
+            naive
+                .iter_mut()
+                .take(end)
+                .skip(start)
+                .for_each(|value| *value += delta);
+
+            let index = rng.gen_range(0..naive.len());
+            assert_eq!(tree.get(index), naive.get(index).copied());
+        }
+
+        assert!((0..naive.len())
+            .map(|index| tree.get(index))
+            .eq(naive.into_iter().map(Some)));
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn out_of_bounds_access_is_rejected() {
+        let mut tree = RangeFenwickTree::from(vec![1, 2, 3]);
+        let before = tree.clone();
+
+        assert!(!tree.add(2..4, 1));
+        assert!(!tree.add(2..1, 1));
+        assert_eq!(tree.get(3), None);
+        assert_eq!(tree.get(usize::MAX), None);
+        assert_eq!(tree, before);
+    }
+
+    #[test]
+    fn wrapped_unsigned_elements() {
+        let mut tree = RangeFenwickTree::from(vec![Wrapping(5u32), Wrapping(3), Wrapping(1)]);
+
+        assert!(tree.add(1..2, Wrapping(5)));
+        assert!(tree.add(1.., Wrapping(2)));
+        assert!((0..3)
+            .map(|index| tree.get(index))
+            .eq([5, 10, 3].map(|value| Some(Wrapping(value)))));
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod json {
+    use super::{FenwickTree, RangeFenwickTree};
+
+    #[test]
+    fn round_trips_through_json() {
+        let tree = FenwickTree::from(vec![1, 2, 3, 4]);
+        let json = serde_json::to_string(&tree).expect("should parse tree into json");
+        let actual: FenwickTree<i32> =
+            serde_json::from_str(&json).expect("should parse json into tree");
+        assert_eq!(actual, tree);
+
+        let tree = RangeFenwickTree::from(vec![1, 2, 3, 4]);
+        let json = serde_json::to_string(&tree).expect("should parse tree into json");
+        let actual: RangeFenwickTree<i32> =
+            serde_json::from_str(&json).expect("should parse json into tree");
+        assert_eq!(actual, tree);
+    }
+}
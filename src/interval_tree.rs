@@ -0,0 +1,933 @@
+use std::cmp::Ordering;
+use std::ops::{Bound, Range};
+
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
+
+use crate::avl::{self, AvlNode};
+
+/// A map from half-open intervals to values, which finds every interval overlapping a range or containing a point.
+///
+/// Intervals are ordered by their start, and then by their end. Every node stores the largest end
+/// in its subtree, which lets queries skip subtrees that end before the queried range begins.
+/// The tree is kept height balanced, so intervals may be inserted in any order.
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct IntervalTree<K, V> {
+    root: Link<K, V>,
+    count: usize,
+}
+
+type Link<K, V> = Option<Box<Node<K, V>>>;
+
+impl<K, V> IntervalTree<K, V> {
+    /// Constructs a new empty `IntervalTree<K, V>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ds_rs::interval_tree::IntervalTree;
+    /// # #[allow(unused_mut)]
+    /// let mut tree: IntervalTree<u32, &str> = IntervalTree::new();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            root: None,
+            count: 0,
+        }
+    }
+
+    /// Returns `true` if the tree contains no intervals.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::interval_tree::IntervalTree;
+    /// let mut tree = IntervalTree::new();
+    /// assert!(tree.is_empty());
+    ///
+    /// tree.insert(0..5, "a");
+    /// assert!(!tree.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Clears the tree of all intervals.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::interval_tree::IntervalTree;
+    /// let mut tree = IntervalTree::from(vec![(0..5, "a"), (3..8, "b")]);
+    ///
+    /// tree.clear();
+    /// assert!(tree.is_empty());
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.root = None;
+        self.count = 0;
+    }
+
+    /// Returns the number of intervals in the tree.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::interval_tree::IntervalTree;
+    /// let mut tree = IntervalTree::new();
+    /// tree.insert(0..5, "a");
+    ///
+    /// // inserting an equal interval replaces the value
+    /// // and therefore does not affect the count
+    /// tree.insert(0..5, "b");
+    /// assert_eq!(tree.count(), 1);
+    ///
+    /// // but overlapping intervals are distinct
+    /// tree.insert(0..6, "c");
+    /// assert_eq!(tree.count(), 2);
+    /// ```
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the height of the tree.
+    ///
+    /// An empty tree has a height of `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::interval_tree::IntervalTree;
+    /// let tree: IntervalTree<u32, ()> = (0..1000).map(|start| (start..start + 10, ())).collect();
+    /// assert!(tree.height() <= 15);
+    /// ```
+    #[inline]
+    pub fn height(&self) -> usize {
+        height(&self.root)
+    }
+
+    /// Returns a non-consuming iterator over the `IntervalTree`.
+    ///
+    /// The iterator yields all intervals and their values, ordered by start and then by end.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::interval_tree::IntervalTree;
+    /// let tree = IntervalTree::from(vec![(5..9, "b"), (1..3, "a"), (5..6, "c")]);
+    /// let mut tree_iter = tree.iter();
+    ///
+    /// assert_eq!(tree_iter.next(), Some((&(1..3), &"a")));
+    /// assert_eq!(tree_iter.next(), Some((&(5..6), &"c")));
+    /// assert_eq!(tree_iter.next(), Some((&(5..9), &"b")));
+    ///
+    /// // the iterator is now empty
+    /// assert_eq!(tree_iter.next(), None);
+    /// ```
+    #[inline]
+    #[must_use = "iterators are evaluated lazily"]
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        self.into_iter()
+    }
+}
+
+/// The methods in this implementation block require trait bounds to correctly apply the logic of the methods.
+///
+/// The trait bound [`Ord`] is used to compare the bounds of intervals,
+/// and [`Clone`] lets every node keep a copy of the largest end in its subtree.
+impl<K, V> IntervalTree<K, V>
+where
+    K: Ord + Clone,
+{
+    /// Inserts the interval with its value into the `IntervalTree`.
+    ///
+    /// If the tree already holds an equal interval, its value is replaced and the old value is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the interval is empty, as it contains no points and overlaps nothing.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::interval_tree::IntervalTree;
+    /// let mut tree = IntervalTree::new();
+    /// assert_eq!(tree.insert(0..5, "a"), None);
+    /// assert_eq!(tree.insert(0..5, "b"), Some("a"));
+    /// assert_eq!(tree.get(&(0..5)), Some(&"b"));
+    /// ```
+    pub fn insert(&mut self, interval: Range<K>, value: V) -> Option<V> {
+        assert!(!interval.is_empty(), "interval must not be empty");
+
+        let old = insert(&mut self.root, interval, value);
+        if old.is_none() {
+            self.count += 1;
+        }
+
+        old
+    }
+
+    /// Returns a reference to the value of the given interval.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::interval_tree::IntervalTree;
+    /// let tree = IntervalTree::from(vec![(0..5, "a"), (3..8, "b")]);
+    /// assert_eq!(tree.get(&(3..8)), Some(&"b"));
+    /// assert_eq!(tree.get(&(3..7)), None);
+    /// ```
+    pub fn get(&self, interval: &Range<K>) -> Option<&V> {
+        let mut node = self.root.as_deref();
+
+        while let Some(current) = node {
+            node = match compare(interval, &current.interval) {
+                Ordering::Equal => return Some(&current.value),
+                Ordering::Less => current.left.as_deref(),
+                Ordering::Greater => current.right.as_deref(),
+            };
+        }
+
+        None
+    }
+
+    /// Removes the given interval from the `IntervalTree`, and returns its value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::interval_tree::IntervalTree;
+    /// let mut tree = IntervalTree::from(vec![(0..5, "a"), (3..8, "b")]);
+    /// assert_eq!(tree.remove(&(0..5)), Some("a"));
+    /// assert_eq!(tree.remove(&(0..5)), None);
+    /// assert_eq!(tree.count(), 1);
+    /// ```
+    pub fn remove(&mut self, interval: &Range<K>) -> Option<V> {
+        let removed = remove(&mut self.root, interval)?;
+        self.count -= 1;
+
+        Some(removed)
+    }
+
+    /// Returns an iterator over the intervals that share at least one point with the given range.
+    ///
+    /// The iterator yields the intervals in the same order as [`IntervalTree::iter`].
+    /// An empty range overlaps no intervals.
+    ///
+    /// # Time Complexity
+    ///
+    /// Subtrees that lie entirely before or after the range are skipped,
+    /// so iterating all `k` matching intervals takes `O((k + 1) log(n))` time at worst.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::interval_tree::IntervalTree;
+    /// let tree = IntervalTree::from(vec![(0..5, "a"), (3..8, "b"), (8..10, "c")]);
+    /// let overlapping: Vec<_> = tree.overlapping(4..8).map(|(_, value)| *value).collect();
+    ///
+    /// // intervals are half-open, so `8..10` starts after the range ends
+    /// assert_eq!(overlapping, vec!["a", "b"]);
+    /// ```
+    #[must_use = "iterators are evaluated lazily"]
+    pub fn overlapping(&self, range: Range<K>) -> Overlapping<'_, K, V> {
+        let is_empty = range.is_empty();
+        let mut search = Search::new(range.start, Bound::Excluded(range.end));
+
+        if !is_empty {
+            search.push_left(self.root.as_deref());
+        }
+
+        Overlapping { search }
+    }
+
+    /// Returns an iterator over the intervals that contain the given point.
+    ///
+    /// The iterator yields the intervals in the same order as [`IntervalTree::iter`].
+    ///
+    /// # Time Complexity
+    ///
+    /// Subtrees that lie entirely before or after the point are skipped,
+    /// so iterating all `k` matching intervals takes `O((k + 1) log(n))` time at worst.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::interval_tree::IntervalTree;
+    /// let tree = IntervalTree::from(vec![(0..5, "a"), (3..8, "b"), (8..10, "c")]);
+    /// let containing: Vec<_> = tree.containing(8).map(|(_, value)| *value).collect();
+    ///
+    /// // intervals are half-open, so `3..8` does not contain its end
+    /// assert_eq!(containing, vec!["c"]);
+    /// ```
+    #[must_use = "iterators are evaluated lazily"]
+    pub fn containing(&self, point: K) -> Containing<'_, K, V> {
+        let mut search = Search::new(point.clone(), Bound::Included(point));
+        search.push_left(self.root.as_deref());
+
+        Containing { search }
+    }
+}
+
+/// Orders intervals by their start, and then by their end.
+#[inline]
+fn compare<K: Ord>(a: &Range<K>, b: &Range<K>) -> Ordering {
+    a.start.cmp(&b.start).then_with(|| a.end.cmp(&b.end))
+}
+
+#[inline]
+fn height<K, V>(link: &Link<K, V>) -> usize {
+    link.as_deref().map_or(0, |node| node.height)
+}
+
+fn insert<K: Ord + Clone, V>(link: &mut Link<K, V>, interval: Range<K>, value: V) -> Option<V> {
+    let Some(node) = link.as_deref_mut() else {
+        *link = Some(Box::new(Node::new(interval, value)));
+        return None;
+    };
+
+    let old = match compare(&interval, &node.interval) {
+        Ordering::Equal => return Some(std::mem::replace(&mut node.value, value)),
+        Ordering::Less => insert(&mut node.left, interval, value),
+        Ordering::Greater => insert(&mut node.right, interval, value),
+    };
+    avl::rebalance(link);
+
+    old
+}
+
+fn remove<K: Ord + Clone, V>(link: &mut Link<K, V>, interval: &Range<K>) -> Option<V> {
+    let node = link.as_deref_mut()?;
+
+    let removed = match compare(interval, &node.interval) {
+        Ordering::Less => remove(&mut node.left, interval),
+        Ordering::Greater => remove(&mut node.right, interval),
+        Ordering::Equal => {
+            let mut node = link.take()?;
+            *link = match (node.left.take(), node.right.take()) {
+                (None, None) => None,
+                (Some(child), None) | (None, Some(child)) => Some(child),
+                (Some(left), Some(right)) => {
+                    let mut right = Some(right);
+                    let mut successor = avl::pop_min(&mut right)?;
+                    successor.left = Some(left);
+                    successor.right = right;
+
+                    Some(successor)
+                }
+            };
+
+            Some(node.value)
+        }
+    };
+    avl::rebalance(link);
+
+    removed
+}
+
+impl<K: Ord + Clone, V> From<Vec<(Range<K>, V)>> for IntervalTree<K, V> {
+    /// Creates an `IntervalTree<K, V>` from `Vec<(Range<K>, V)>`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the intervals is empty.
+    fn from(vec: Vec<(Range<K>, V)>) -> Self {
+        let mut tree = IntervalTree::new();
+        for (interval, value) in vec {
+            tree.insert(interval, value);
+        }
+
+        tree
+    }
+}
+
+impl<K: Ord + Clone, V> FromIterator<(Range<K>, V)> for IntervalTree<K, V> {
+    /// Constructs an `IntervalTree<K, V>` from an iterator of intervals and values.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the intervals is empty.
+    fn from_iter<I: IntoIterator<Item = (Range<K>, V)>>(iter: I) -> Self {
+        let mut tree = IntervalTree::new();
+
+        for (interval, value) in iter {
+            tree.insert(interval, value);
+        }
+
+        tree
+    }
+}
+
+impl<K: Ord + Clone, V> Extend<(Range<K>, V)> for IntervalTree<K, V> {
+    /// Extends the `IntervalTree` with the contents of the provided iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any of the intervals is empty.
+    fn extend<I: IntoIterator<Item = (Range<K>, V)>>(&mut self, iter: I) {
+        for (interval, value) in iter {
+            self.insert(interval, value);
+        }
+    }
+}
+
+impl<K, V> IntoIterator for IntervalTree<K, V> {
+    type Item = (Range<K>, V);
+
+    type IntoIter = IntoIter<K, V>;
+
+    /// Returns a consuming iterator over the `IntervalTree`.
+    ///
+    /// The iterator yields all intervals and their values, ordered by start and then by end.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::interval_tree::IntervalTree;
+    /// let tree = IntervalTree::from(vec![(5..9, "b"), (1..3, "a")]);
+    /// let mut tree_iter = tree.into_iter();
+    ///
+    /// assert_eq!(tree_iter.next(), Some((1..3, "a")));
+    /// assert_eq!(tree_iter.next(), Some((5..9, "b")));
+    ///
+    /// // the iterator is now empty
+    /// assert_eq!(tree_iter.next(), None);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        let mut iter = IntoIter {
+            stack: Vec::new(),
+            remaining: self.count,
+        };
+        iter.push_left(self.root);
+
+        iter
+    }
+}
+
+/// An iterator that moves out of the `IntervalTree`.
+///
+/// This `struct` is created by the `into_iter` method on [`IntervalTree`] (provided by the [`IntoIterator`] trait).
+pub struct IntoIter<K, V> {
+    stack: Vec<(Range<K>, V, Link<K, V>)>,
+    remaining: usize,
+}
+
+impl<K, V> IntoIter<K, V> {
+    /// Pushes the left spine of the subtree, keeping each right subtree for later.
+    fn push_left(&mut self, mut link: Link<K, V>) {
+        while let Some(node) = link {
+            let Node {
+                interval,
+                value,
+                left,
+                right,
+                ..
+            } = *node;
+            self.stack.push((interval, value, right));
+            link = left;
+        }
+    }
+}
+
+impl<K, V> Iterator for IntoIter<K, V> {
+    type Item = (Range<K>, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (interval, value, right) = self.stack.pop()?;
+        self.push_left(right);
+        self.remaining -= 1;
+
+        Some((interval, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for IntoIter<K, V> {}
+
+impl<'a, K, V> IntoIterator for &'a IntervalTree<K, V> {
+    type Item = (&'a Range<K>, &'a V);
+
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let mut iter = Iter {
+            stack: Vec::new(),
+            remaining: self.count,
+        };
+        iter.push_left(self.root.as_deref());
+
+        iter
+    }
+}
+
+/// An iterator that borrows from the `IntervalTree`.
+///
+/// This `struct` is created by the `iter` method on [`IntervalTree`].
+pub struct Iter<'a, K, V> {
+    stack: Vec<&'a Node<K, V>>,
+    remaining: usize,
+}
+
+impl<'a, K, V> Iter<'a, K, V> {
+    /// Pushes the left spine of the subtree.
+    fn push_left(&mut self, mut node: Option<&'a Node<K, V>>) {
+        while let Some(current) = node {
+            self.stack.push(current);
+            node = current.left.as_deref();
+        }
+    }
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a Range<K>, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left(node.right.as_deref());
+        self.remaining -= 1;
+
+        Some((&node.interval, &node.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for Iter<'a, K, V> {}
+
+/// An iterator over the intervals that overlap a range.
+///
+/// This `struct` is created by the `overlapping` method on [`IntervalTree`].
+pub struct Overlapping<'a, K, V> {
+    search: Search<'a, K, V>,
+}
+
+impl<'a, K: Ord, V> Iterator for Overlapping<'a, K, V> {
+    type Item = (&'a Range<K>, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.search.next()
+    }
+}
+
+/// An iterator over the intervals that contain a point.
+///
+/// This `struct` is created by the `containing` method on [`IntervalTree`].
+pub struct Containing<'a, K, V> {
+    search: Search<'a, K, V>,
+}
+
+impl<'a, K: Ord, V> Iterator for Containing<'a, K, V> {
+    type Item = (&'a Range<K>, &'a V);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.search.next()
+    }
+}
+
+/// An in-order walk that only visits the intervals ending after `start` and starting before `end`.
+struct Search<'a, K, V> {
+    stack: Vec<&'a Node<K, V>>,
+    start: K,
+    end: Bound<K>,
+}
+
+impl<'a, K: Ord, V> Search<'a, K, V> {
+    fn new(start: K, end: Bound<K>) -> Self {
+        Self {
+            stack: Vec::new(),
+            start,
+            end,
+        }
+    }
+
+    /// Returns `true` if the interval starting at `start` begins before the end of the query.
+    fn starts_before_end(&self, start: &K) -> bool {
+        match &self.end {
+            Bound::Included(end) => start <= end,
+            Bound::Excluded(end) => start < end,
+            Bound::Unbounded => true,
+        }
+    }
+
+    /// Pushes the left spine of the subtree, stopping at the first subtree that ends before the query starts.
+    fn push_left(&mut self, mut node: Option<&'a Node<K, V>>) {
+        while let Some(current) = node.filter(|node| node.max_end > self.start) {
+            self.stack.push(current);
+            node = current.left.as_deref();
+        }
+    }
+}
+
+impl<'a, K: Ord, V> Iterator for Search<'a, K, V> {
+    type Item = (&'a Range<K>, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(node) = self.stack.pop() {
+            // every interval that comes later starts at or after this one
+            if !self.starts_before_end(&node.interval.start) {
+                self.stack.clear();
+                return None;
+            }
+
+            self.push_left(node.right.as_deref());
+            if node.interval.end > self.start {
+                return Some((&node.interval, &node.value));
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Node<K, V> {
+    interval: Range<K>,
+    value: V,
+    max_end: K,
+    height: usize,
+    left: Link<K, V>,
+    right: Link<K, V>,
+}
+
+impl<K: Clone, V> Node<K, V> {
+    /// Constructs a new `Node<K, V>` without children.
+    fn new(interval: Range<K>, value: V) -> Self {
+        Self {
+            max_end: interval.end.clone(),
+            interval,
+            value,
+            height: 1,
+            left: None,
+            right: None,
+        }
+    }
+}
+
+impl<K: Ord + Clone, V> AvlNode for Node<K, V> {
+    fn left(&self) -> &Link<K, V> {
+        &self.left
+    }
+
+    fn right(&self) -> &Link<K, V> {
+        &self.right
+    }
+
+    fn left_mut(&mut self) -> &mut Link<K, V> {
+        &mut self.left
+    }
+
+    fn right_mut(&mut self) -> &mut Link<K, V> {
+        &mut self.right
+    }
+
+    fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Recomputes the height and largest end from the node and its children.
+    fn update(&mut self) {
+        self.height = height(&self.left).max(height(&self.right)) + 1;
+
+        let mut max_end = &self.interval.end;
+        for child in [self.left.as_deref(), self.right.as_deref()]
+            .into_iter()
+            .flatten()
+        {
+            max_end = max_end.max(&child.max_end);
+        }
+        self.max_end = max_end.clone();
+    }
+}
+
+#[cfg(test)]
+impl<K: Ord + Clone + std::fmt::Debug, V> IntervalTree<K, V> {
+    /// Panics if any node breaks the ordering, balance, height or largest end invariants of the tree.
+    fn assert_invariants(&self) {
+        fn check<K: Ord + Clone + std::fmt::Debug, V>(link: &Link<K, V>) -> usize {
+            let Some(node) = link.as_deref() else {
+                return 0;
+            };
+
+            if let Some(left) = node.left.as_deref() {
+                assert_eq!(compare(&left.interval, &node.interval), Ordering::Less);
+            }
+            if let Some(right) = node.right.as_deref() {
+                assert_eq!(compare(&right.interval, &node.interval), Ordering::Greater);
+            }
+
+            let (left, right) = (check(&node.left), check(&node.right));
+            assert!(left.abs_diff(right) <= 1);
+            assert_eq!(node.height, left.max(right) + 1);
+
+            let max_end = [node.left.as_deref(), node.right.as_deref()]
+                .into_iter()
+                .flatten()
+                .map(|child| &child.max_end)
+                .fold(&node.interval.end, Ord::max);
+            assert_eq!(&node.max_end, max_end);
+
+            node.height
+        }
+
+        let mut count = 0;
+        let mut previous: Option<&Range<K>> = None;
+        for (interval, _) in self.iter() {
+            if let Some(previous) = previous {
+                assert_eq!(compare(previous, interval), Ordering::Less);
+            }
+            previous = Some(interval);
+            count += 1;
+        }
+
+        assert_eq!(count, self.count);
+        check(&self.root);
+    }
+}
+
+#[cfg(test)]
+mod insert {
+    use super::{IntervalTree, Node};
+
+    #[test]
+    fn insert_one_interval_that_becomes_root() {
+        let mut tree = IntervalTree::new();
+        let expected = IntervalTree {
+            root: Some(Box::new(Node {
+                interval: 0..5,
+                value: "a",
+                max_end: 5,
+                height: 1,
+                left: None,
+                right: None,
+            })),
+            count: 1,
+        };
+
+        assert_eq!(tree.insert(0..5, "a"), None);
+        assert_eq!(tree, expected);
+    }
+
+    #[test]
+    fn ascending_starts_rotate_and_track_max_end() {
+        let mut tree = IntervalTree::new();
+        let expected = IntervalTree {
+            root: Some(Box::new(Node {
+                interval: 1..2,
+                value: "b",
+                max_end: 20,
+                height: 2,
+                left: Some(Box::new(Node {
+                    interval: 0..20,
+                    value: "a",
+                    max_end: 20,
+                    height: 1,
+                    left: None,
+                    right: None,
+                })),
+                right: Some(Box::new(Node {
+                    interval: 2..3,
+                    value: "c",
+                    max_end: 3,
+                    height: 1,
+                    left: None,
+                    right: None,
+                })),
+            })),
+            count: 3,
+        };
+
+        tree.extend([(0..20, "a"), (1..2, "b"), (2..3, "c")]);
+        assert_eq!(tree, expected);
+    }
+
+    #[test]
+    fn equal_interval_replaces_value() {
+        let mut tree = IntervalTree::from(vec![(0..5, 1), (0..6, 2)]);
+
+        assert_eq!(tree.insert(0..5, 3), Some(1));
+        assert_eq!(tree.count(), 2);
+        assert_eq!(tree.get(&(0..5)), Some(&3));
+    }
+
+    #[test]
+    fn sorted_inserts_stay_balanced() {
+        let mut tree = IntervalTree::new();
+
+        for start in 0..500 {
+            tree.insert(start..start + 3, start);
+            tree.assert_invariants();
+        }
+
+        assert!(tree.height() <= 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "interval must not be empty")]
+    fn empty_interval_panics() {
+        let mut tree = IntervalTree::new();
+        #[allow(clippy::reversed_empty_ranges)]
+        tree.insert(5..3, ());
+    }
+}
+
+#[cfg(test)]
+mod remove {
+    use super::IntervalTree;
+
+    #[test]
+    fn missing_interval_returns_none() {
+        let mut tree = IntervalTree::from(vec![(0..5, "a")]);
+
+        assert_eq!(tree.remove(&(0..6)), None);
+        assert_eq!(tree.count(), 1);
+    }
+
+    #[test]
+    fn removes_every_interval() {
+        let mut tree: IntervalTree<i32, i32> = (0..200).map(|v| (v..v + v % 7 + 1, v)).collect();
+
+        for v in (0..200).map(|v| (v * 13) % 200) {
+            assert_eq!(tree.remove(&(v..v + v % 7 + 1)), Some(v));
+            assert_eq!(tree.remove(&(v..v + v % 7 + 1)), None);
+            tree.assert_invariants();
+        }
+
+        assert!(tree.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod queries {
+    use super::IntervalTree;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use std::ops::Range;
+
+    fn random_tree(rng: &mut StdRng, count: usize) -> (IntervalTree<u32, usize>, Vec<Range<u32>>) {
+        let mut tree = IntervalTree::new();
+        let mut intervals = Vec::new();
+
+        for index in 0..count {
+            let start = rng.gen_range(0..1000);
+            let interval = start..start + rng.gen_range(1..100);
+
+            if tree.insert(interval.clone(), index).is_none() {
+                intervals.push(interval);
+            }
+        }
+        intervals.sort_by(|a, b| a.start.cmp(&b.start).then(a.end.cmp(&b.end)));
+
+        (tree, intervals)
+    }
+
+    #[test]
+    fn overlapping_matches_brute_force() {
+        let mut rng = StdRng::seed_from_u64(31);
+        let (tree, intervals) = random_tree(&mut rng, 500);
+        tree.assert_invariants();
+
+        for _ in 0..200 {
+            let start = rng.gen_range(0..1100);
+            let query = start..start + rng.gen_range(1..50);
+
+            let expected: Vec<_> = intervals
+                .iter()
+                .filter(|interval| interval.start < query.end && query.start < interval.end)
+                .collect();
+            let actual: Vec<_> = tree
+                .overlapping(query)
+                .map(|(interval, _)| interval)
+                .collect();
+
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn containing_matches_brute_force() {
+        let mut rng = StdRng::seed_from_u64(131);
+        let (tree, intervals) = random_tree(&mut rng, 500);
+
+        for _ in 0..200 {
+            let point = rng.gen_range(0..1100);
+
+            let expected: Vec<_> = intervals
+                .iter()
+                .filter(|interval| interval.contains(&point))
+                .collect();
+            let actual: Vec<_> = tree
+                .containing(point)
+                .map(|(interval, _)| interval)
+                .collect();
+
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn bounds_are_half_open() {
+        let tree = IntervalTree::from(vec![(0..5, "a"), (5..10, "b")]);
+
+        assert!(tree.containing(5).map(|(_, value)| *value).eq(["b"]));
+        assert!(tree.overlapping(4..5).map(|(_, value)| *value).eq(["a"]));
+        assert_eq!(tree.overlapping(10..20).count(), 0);
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn empty_range_overlaps_nothing() {
+        let tree = IntervalTree::from(vec![(0..10, "a")]);
+
+        assert_eq!(tree.overlapping(5..5).count(), 0);
+        assert_eq!(tree.overlapping(8..2).count(), 0);
+    }
+
+    #[test]
+    fn empty_tree_yields_nothing() {
+        let tree: IntervalTree<i32, ()> = IntervalTree::new();
+
+        assert_eq!(tree.overlapping(0..10).count(), 0);
+        assert_eq!(tree.containing(0).count(), 0);
+    }
+}
+
+#[cfg(test)]
+mod iterator_trait_impls {
+    use super::IntervalTree;
+
+    #[test]
+    fn iter_yields_intervals_in_order() {
+        let tree = IntervalTree::from(vec![(3..4, 'c'), (1..9, 'b'), (1..2, 'a')]);
+        let mut iter = tree.iter();
+
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some((&(1..2), &'a')));
+        assert_eq!(iter.len(), 2);
+        assert!(iter.map(|(_, value)| *value).eq(['b', 'c']));
+    }
+
+    #[test]
+    fn into_iter_yields_intervals_in_order() {
+        let tree: IntervalTree<i32, i32> = (0..50).rev().map(|v| (v..v + 1, v)).collect();
+
+        assert!(tree.into_iter().map(|(_, value)| value).eq(0..50));
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod json {
+    use super::IntervalTree;
+
+    #[test]
+    fn round_trips_through_json() {
+        let tree = IntervalTree::from(vec![(0..5, "a".to_string()), (3..8, "b".to_string())]);
+
+        let json = serde_json::to_string(&tree).expect("should parse tree into json");
+        let actual: IntervalTree<i32, String> =
+            serde_json::from_str(&json).expect("should parse json into tree");
+
+        assert_eq!(actual, tree);
+    }
+}
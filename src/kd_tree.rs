@@ -0,0 +1,1048 @@
+use std::cmp::Ordering;
+use std::ops::{Add, Mul, Sub};
+
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
+
+/// A measure of distance between points, used by the searches of a [`KdTree`].
+///
+/// The searches skip a subtree when the distance along a single axis already rules it out,
+/// so [`Metric::axis_distance`] must never exceed [`Metric::distance`] between two points
+/// whose coordinates along some axis are the ones given.
+pub trait Metric<T> {
+    /// The type of distances, which only needs to be ordered.
+    type Distance: PartialOrd;
+
+    /// Returns the distance between two points.
+    fn distance<const K: usize>(&self, a: &[T; K], b: &[T; K]) -> Self::Distance;
+
+    /// Returns the smallest distance between two points whose coordinates along one axis are `a` and `b`.
+    fn axis_distance(&self, a: &T, b: &T) -> Self::Distance;
+}
+
+/// The square of the straight-line distance, which orders points like the distance itself
+/// while avoiding square roots.
+///
+/// The default value of `T` is used as zero.
+///
+/// # Examples
+/// ```
+/// # use ds_rs::kd_tree::{Metric, SquaredEuclidean};
+/// assert_eq!(SquaredEuclidean.distance(&[0, 0], &[3, 4]), 25);
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SquaredEuclidean;
+
+/// The sum of the distances along every axis.
+///
+/// The default value of `T` is used as zero.
+///
+/// # Examples
+/// ```
+/// # use ds_rs::kd_tree::{Manhattan, Metric};
+/// assert_eq!(Manhattan.distance(&[0, 0], &[3, 4]), 7);
+/// ```
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Manhattan;
+
+/// Returns the absolute difference of `a` and `b`, which also works for unsigned types.
+#[inline]
+fn difference<T: Copy + PartialOrd + Sub<Output = T>>(a: T, b: T) -> T {
+    if a > b {
+        a - b
+    } else {
+        b - a
+    }
+}
+
+impl<T> Metric<T> for SquaredEuclidean
+where
+    T: Copy + Default + PartialOrd + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    type Distance = T;
+
+    fn distance<const K: usize>(&self, a: &[T; K], b: &[T; K]) -> T {
+        a.iter().zip(b).fold(T::default(), |sum, (&a, &b)| {
+            let difference = difference(a, b);
+            sum + difference * difference
+        })
+    }
+
+    #[inline]
+    fn axis_distance(&self, &a: &T, &b: &T) -> T {
+        let difference = difference(a, b);
+        difference * difference
+    }
+}
+
+impl<T> Metric<T> for Manhattan
+where
+    T: Copy + Default + PartialOrd + Add<Output = T> + Sub<Output = T>,
+{
+    type Distance = T;
+
+    fn distance<const K: usize>(&self, a: &[T; K], b: &[T; K]) -> T {
+        a.iter()
+            .zip(b)
+            .fold(T::default(), |sum, (&a, &b)| sum + difference(a, b))
+    }
+
+    #[inline]
+    fn axis_distance(&self, &a: &T, &b: &T) -> T {
+        difference(a, b)
+    }
+}
+
+/// A tree of points in `K` dimensions, which finds the points near a location or within a box.
+///
+/// Every level of the tree splits the points along the next axis, cycling through all `K` axes.
+/// Building the tree from a set of points splits each level at the median, which keeps it balanced,
+/// while points inserted later are placed without rebalancing. Equal points are all kept.
+///
+/// Coordinates are compared with [`PartialOrd`], so coordinates that do not compare,
+/// such as `NaN`, make the placement and the results of searches unspecified.
+///
+/// # Examples
+/// ```
+/// # use ds_rs::kd_tree::{KdTree, SquaredEuclidean};
+/// let sensors = KdTree::from(vec![[0.0, 0.0], [5.0, 5.0], [9.0, 1.0]]);
+///
+/// assert_eq!(sensors.nearest(&[6.0, 3.0], &SquaredEuclidean), Some(&[5.0, 5.0]));
+/// ```
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KdTree<T, const K: usize> {
+    root: Link<T, K>,
+    count: usize,
+}
+
+type Link<T, const K: usize> = Option<Box<Node<T, K>>>;
+
+impl<T, const K: usize> KdTree<T, K> {
+    /// Rejects trees without any axis to split along.
+    const VALID_DIMENSION: () = assert!(K > 0, "a k-d tree must have at least one dimension");
+
+    /// Constructs a new empty `KdTree<T, K>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ds_rs::kd_tree::KdTree;
+    /// # #[allow(unused_mut)]
+    /// let mut tree: KdTree<f64, 3> = KdTree::new();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_DIMENSION;
+
+        Self {
+            root: None,
+            count: 0,
+        }
+    }
+
+    /// Returns `true` if the tree contains no points.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::kd_tree::KdTree;
+    /// let mut tree = KdTree::new();
+    /// assert!(tree.is_empty());
+    ///
+    /// tree.insert([0, 0]);
+    /// assert!(!tree.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Clears the tree of all points.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::kd_tree::KdTree;
+    /// let mut tree = KdTree::from(vec![[0, 0], [1, 1]]);
+    ///
+    /// tree.clear();
+    /// assert!(tree.is_empty());
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.root = None;
+        self.count = 0;
+    }
+
+    /// Returns the number of points in the tree.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::kd_tree::KdTree;
+    /// let mut tree = KdTree::new();
+    /// tree.insert([1, 2]);
+    ///
+    /// // equal points are all kept
+    /// tree.insert([1, 2]);
+    /// assert_eq!(tree.count(), 2);
+    /// ```
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Traverses and returns the height of the tree.
+    ///
+    /// An empty tree has a height of `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::kd_tree::KdTree;
+    /// let tree: KdTree<i32, 2> = (0..1000).map(|i| [i % 37, i / 37]).collect();
+    /// assert_eq!(tree.height(), 10);
+    /// ```
+    pub fn height(&self) -> usize {
+        let mut height = 0;
+        let mut stack: Vec<_> = self
+            .root
+            .as_deref()
+            .map(|root| (1, root))
+            .into_iter()
+            .collect();
+
+        while let Some((node_height, node)) = stack.pop() {
+            height = height.max(node_height);
+            stack.extend(node.left.as_deref().map(|left| (node_height + 1, left)));
+            stack.extend(node.right.as_deref().map(|right| (node_height + 1, right)));
+        }
+
+        height
+    }
+
+    /// Returns a non-consuming iterator over the `KdTree`.
+    ///
+    /// The iterator yields all points using the **preorder tree traversal technique**.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::kd_tree::KdTree;
+    /// let tree = KdTree::from(vec![[1, 1], [0, 0], [2, 2]]);
+    /// let mut tree_iter = tree.iter();
+    ///
+    /// assert_eq!(tree_iter.next(), Some(&[1, 1]));
+    /// assert_eq!(tree_iter.next(), Some(&[0, 0]));
+    /// assert_eq!(tree_iter.next(), Some(&[2, 2]));
+    ///
+    /// // the iterator is now empty
+    /// assert_eq!(tree_iter.next(), None);
+    /// ```
+    #[inline]
+    #[must_use = "iterators are evaluated lazily"]
+    pub fn iter(&self) -> Iter<'_, T, K> {
+        self.into_iter()
+    }
+}
+
+impl<T, const K: usize> Default for KdTree<T, K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The methods in this implementation block require trait bounds to correctly apply the logic of the methods.
+///
+/// The trait bound in question is [`PartialOrd`], which is used to compare coordinates along an axis.
+impl<T, const K: usize> KdTree<T, K>
+where
+    T: PartialOrd,
+{
+    /// Inserts the provided point into the `KdTree`.
+    ///
+    /// The point is placed at the bottom of the tree without rebalancing, so many insertions
+    /// after a balanced construction may slow down searches.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::kd_tree::KdTree;
+    /// let mut tree = KdTree::new();
+    /// tree.insert([5, 1]);
+    /// tree.insert([3, 9]);
+    /// assert_eq!(tree.count(), 2);
+    /// ```
+    pub fn insert(&mut self, point: [T; K]) {
+        let mut slot = &mut self.root;
+        let mut axis = 0;
+
+        while let Some(node) = slot {
+            slot = match compare(&point, &node.point, axis) {
+                Ordering::Less => &mut node.left,
+                Ordering::Equal | Ordering::Greater => &mut node.right,
+            };
+            axis = (axis + 1) % K;
+        }
+
+        *slot = Some(Box::new(Node::new(point)));
+        self.count += 1;
+    }
+
+    /// Returns `true` if the `KdTree` contains a point equal to the given point.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::kd_tree::KdTree;
+    /// let tree = KdTree::from(vec![[1, 2], [3, 4]]);
+    /// assert!(tree.contains(&[3, 4]));
+    /// assert!(!tree.contains(&[4, 3]));
+    /// ```
+    pub fn contains(&self, target: &[T; K]) -> bool {
+        let mut stack: Vec<_> = self
+            .root
+            .as_deref()
+            .map(|root| (root, 0))
+            .into_iter()
+            .collect();
+
+        // equal coordinates may sit on either side of a split, so both sides are searched
+        while let Some((node, axis)) = stack.pop() {
+            if &node.point == target {
+                return true;
+            }
+
+            let next = (axis + 1) % K;
+            let ordering = compare(target, &node.point, axis);
+            if ordering != Ordering::Greater {
+                stack.extend(node.left.as_deref().map(|left| (left, next)));
+            }
+            if ordering != Ordering::Less {
+                stack.extend(node.right.as_deref().map(|right| (right, next)));
+            }
+        }
+
+        false
+    }
+
+    /// Returns the point closest to `query` under the given metric.
+    ///
+    /// If several points are equally close, any of them may be returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::kd_tree::{KdTree, Manhattan};
+    /// let tree = KdTree::from(vec![[0, 0], [4, 4], [10, 0]]);
+    /// assert_eq!(tree.nearest(&[7, 1], &Manhattan), Some(&[10, 0]));
+    /// ```
+    pub fn nearest<M: Metric<T>>(&self, query: &[T; K], metric: &M) -> Option<&[T; K]> {
+        self.k_nearest(query, 1, metric).into_iter().next()
+    }
+
+    /// Returns the `k` points closest to `query` under the given metric, from the closest to the furthest.
+    ///
+    /// Fewer points are returned if the tree holds fewer than `k` points.
+    ///
+    /// # Time Complexity
+    ///
+    /// Subtrees that lie further away than the `k` closest points found so far are skipped,
+    /// so for a balanced tree of evenly spread points this takes close to `k log(n)` time.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::kd_tree::{KdTree, SquaredEuclidean};
+    /// let tree = KdTree::from(vec![[0, 0], [1, 1], [2, 2], [3, 3]]);
+    /// let nearest = tree.k_nearest(&[3, 2], 2, &SquaredEuclidean);
+    /// assert_eq!(nearest, vec![&[2, 2], &[3, 3]]);
+    /// ```
+    pub fn k_nearest<M: Metric<T>>(&self, query: &[T; K], k: usize, metric: &M) -> Vec<&[T; K]> {
+        if k == 0 {
+            return Vec::new();
+        }
+        let mut best: Vec<(M::Distance, &[T; K])> = Vec::with_capacity(k.min(self.count));
+
+        // far sides carry the smallest distance any of their points can have from the query
+        let mut stack: Vec<_> = self
+            .root
+            .as_deref()
+            .map(|root| (root, 0, None))
+            .into_iter()
+            .collect();
+
+        while let Some((node, axis, bound)) = stack.pop() {
+            let is_full = best.len() == k;
+            let worst = best.last().map(|(distance, _)| distance);
+            if let (true, Some(bound), Some(worst)) = (is_full, &bound, worst) {
+                if bound > worst {
+                    continue;
+                }
+            }
+
+            let distance = metric.distance(query, &node.point);
+            let position = best.partition_point(|(best, _)| best <= &distance);
+            if position < k {
+                best.insert(position, (distance, &node.point));
+                best.truncate(k);
+            }
+
+            let next = (axis + 1) % K;
+            let (near, far) = match compare(query, &node.point, axis) {
+                Ordering::Less => (&node.left, &node.right),
+                Ordering::Equal | Ordering::Greater => (&node.right, &node.left),
+            };
+
+            if let (Some(far), Some(query_coordinate), Some(split)) =
+                (far.as_deref(), query.get(axis), node.point.get(axis))
+            {
+                let bound = metric.axis_distance(query_coordinate, split);
+                stack.push((far, next, Some(bound)));
+            }
+            stack.extend(near.as_deref().map(|near| (near, next, None)));
+        }
+
+        best.into_iter().map(|(_, point)| point).collect()
+    }
+
+    /// Returns an iterator over the points within `radius` of `query` under the given metric, including those exactly at it.
+    ///
+    /// The points are yielded in no particular order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::kd_tree::{KdTree, SquaredEuclidean};
+    /// let tree = KdTree::from(vec![[0, 0], [3, 4], [6, 8]]);
+    ///
+    /// // the squared distance to `[3, 4]` is exactly 25
+    /// let mut within: Vec<_> = tree.within_radius([0, 0], 25, SquaredEuclidean).collect();
+    /// within.sort();
+    /// assert_eq!(within, vec![&[0, 0], &[3, 4]]);
+    /// ```
+    #[must_use = "iterators are evaluated lazily"]
+    pub fn within_radius<M: Metric<T>>(
+        &self,
+        query: [T; K],
+        radius: M::Distance,
+        metric: M,
+    ) -> WithinRadius<'_, T, M, K> {
+        WithinRadius {
+            stack: self
+                .root
+                .as_deref()
+                .map(|root| (root, 0))
+                .into_iter()
+                .collect(),
+            query,
+            radius,
+            metric,
+        }
+    }
+
+    /// Returns an iterator over the points within the axis-aligned box between the two corners, including its boundary.
+    ///
+    /// The points are yielded in no particular order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::kd_tree::KdTree;
+    /// let tree = KdTree::from(vec![[1, 1], [4, 2], [2, 5], [7, 7]]);
+    ///
+    /// let mut inside: Vec<_> = tree.within_box([0, 0], [4, 5]).collect();
+    /// inside.sort();
+    /// assert_eq!(inside, vec![&[1, 1], &[2, 5], &[4, 2]]);
+    /// ```
+    #[must_use = "iterators are evaluated lazily"]
+    pub fn within_box(&self, min: [T; K], max: [T; K]) -> WithinBox<'_, T, K> {
+        WithinBox {
+            stack: self
+                .root
+                .as_deref()
+                .map(|root| (root, 0))
+                .into_iter()
+                .collect(),
+            min,
+            max,
+        }
+    }
+}
+
+/// Compares two points along the given axis.
+///
+/// Coordinates that do not compare are treated as equal.
+#[inline]
+fn compare<T: PartialOrd, const K: usize>(a: &[T; K], b: &[T; K], axis: usize) -> Ordering {
+    a.get(axis)
+        .partial_cmp(&b.get(axis))
+        .unwrap_or(Ordering::Equal)
+}
+
+/// Builds a balanced subtree from the points, split along `axis` at the median.
+///
+/// The points before the median end up in the left subtree, and none of them is greater along the axis.
+fn build<T: PartialOrd, const K: usize>(mut points: Vec<[T; K]>, axis: usize) -> Link<T, K> {
+    if points.is_empty() {
+        return None;
+    }
+
+    let median = points.len() / 2;
+    points.select_nth_unstable_by(median, |a, b| compare(a, b, axis));
+
+    let mut right = points.split_off(median);
+    let point = right.swap_remove(0);
+    // `swap_remove` moved the last point to the front, which does not matter for an unsorted half
+    let next = (axis + 1) % K;
+
+    Some(Box::new(Node {
+        point,
+        left: build(points, next),
+        right: build(right, next),
+    }))
+}
+
+impl<T: PartialOrd, const K: usize> From<Vec<[T; K]>> for KdTree<T, K> {
+    /// Creates a balanced `KdTree<T, K>` from `Vec<[T; K]>`.
+    ///
+    /// Every level is split at the median along its axis, which takes `n log(n)` time.
+    fn from(vec: Vec<[T; K]>) -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_DIMENSION;
+
+        Self {
+            count: vec.len(),
+            root: build(vec, 0),
+        }
+    }
+}
+
+impl<T: PartialOrd, const K: usize> FromIterator<[T; K]> for KdTree<T, K> {
+    /// Constructs a balanced `KdTree<T, K>` from an iterator of points.
+    fn from_iter<I: IntoIterator<Item = [T; K]>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+impl<T: PartialOrd, const K: usize> Extend<[T; K]> for KdTree<T, K> {
+    /// Extends the `KdTree` with the contents of the provided iterator, inserting one point at a time.
+    fn extend<I: IntoIterator<Item = [T; K]>>(&mut self, iter: I) {
+        for point in iter {
+            self.insert(point);
+        }
+    }
+}
+
+impl<T, const K: usize> IntoIterator for KdTree<T, K> {
+    type Item = [T; K];
+
+    type IntoIter = IntoIter<T, K>;
+
+    /// Returns a consuming iterator over the `KdTree`.
+    ///
+    /// The iterator yields all points using the **preorder tree traversal technique**.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::kd_tree::KdTree;
+    /// let tree = KdTree::from(vec![[1, 1], [0, 0], [2, 2]]);
+    /// let mut tree_iter = tree.into_iter();
+    ///
+    /// assert_eq!(tree_iter.next(), Some([1, 1]));
+    /// assert_eq!(tree_iter.next(), Some([0, 0]));
+    /// assert_eq!(tree_iter.next(), Some([2, 2]));
+    ///
+    /// // the iterator is now empty
+    /// assert_eq!(tree_iter.next(), None);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            stack: self.root.into_iter().collect(),
+            remaining: self.count,
+        }
+    }
+}
+
+/// An iterator that moves out of the `KdTree`.
+///
+/// This `struct` is created by the `into_iter` method on [`KdTree`] (provided by the [`IntoIterator`] trait).
+pub struct IntoIter<T, const K: usize> {
+    stack: Vec<Box<Node<T, K>>>,
+    remaining: usize,
+}
+
+impl<T, const K: usize> Iterator for IntoIter<T, K> {
+    type Item = [T; K];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        let Node { point, left, right } = *node;
+        self.stack.extend(right);
+        self.stack.extend(left);
+        self.remaining -= 1;
+
+        Some(point)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T, const K: usize> ExactSizeIterator for IntoIter<T, K> {}
+
+impl<'a, T, const K: usize> IntoIterator for &'a KdTree<T, K> {
+    type Item = &'a [T; K];
+
+    type IntoIter = Iter<'a, T, K>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Iter {
+            stack: self.root.as_deref().into_iter().collect(),
+            remaining: self.count,
+        }
+    }
+}
+
+/// An iterator that borrows from the `KdTree`.
+///
+/// This `struct` is created by the `iter` method on [`KdTree`].
+pub struct Iter<'a, T, const K: usize> {
+    stack: Vec<&'a Node<T, K>>,
+    remaining: usize,
+}
+
+impl<'a, T, const K: usize> Iterator for Iter<'a, T, K> {
+    type Item = &'a [T; K];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.stack.extend(node.right.as_deref());
+        self.stack.extend(node.left.as_deref());
+        self.remaining -= 1;
+
+        Some(&node.point)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T, const K: usize> ExactSizeIterator for Iter<'a, T, K> {}
+
+/// An iterator over the points within a distance of a location.
+///
+/// This `struct` is created by the `within_radius` method on [`KdTree`].
+pub struct WithinRadius<'a, T, M: Metric<T>, const K: usize> {
+    stack: Vec<(&'a Node<T, K>, usize)>,
+    query: [T; K],
+    radius: M::Distance,
+    metric: M,
+}
+
+impl<'a, T: PartialOrd, M: Metric<T>, const K: usize> Iterator for WithinRadius<'a, T, M, K> {
+    type Item = &'a [T; K];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((node, axis)) = self.stack.pop() {
+            let next = (axis + 1) % K;
+            let ordering = compare(&self.query, &node.point, axis);
+            let reaches_across = match (self.query.get(axis), node.point.get(axis)) {
+                (Some(query), Some(split)) => {
+                    self.metric.axis_distance(query, split) <= self.radius
+                }
+                _ => true,
+            };
+
+            if ordering == Ordering::Less || reaches_across {
+                self.stack
+                    .extend(node.left.as_deref().map(|left| (left, next)));
+            }
+            if ordering != Ordering::Less || reaches_across {
+                self.stack
+                    .extend(node.right.as_deref().map(|right| (right, next)));
+            }
+
+            if self.metric.distance(&self.query, &node.point) <= self.radius {
+                return Some(&node.point);
+            }
+        }
+
+        None
+    }
+}
+
+/// An iterator over the points within an axis-aligned box.
+///
+/// This `struct` is created by the `within_box` method on [`KdTree`].
+pub struct WithinBox<'a, T, const K: usize> {
+    stack: Vec<(&'a Node<T, K>, usize)>,
+    min: [T; K],
+    max: [T; K],
+}
+
+impl<'a, T: PartialOrd, const K: usize> Iterator for WithinBox<'a, T, K> {
+    type Item = &'a [T; K];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((node, axis)) = self.stack.pop() {
+            let next = (axis + 1) % K;
+
+            // the left subtree holds no coordinates above the split, and the right none below it
+            if compare(&self.min, &node.point, axis) != Ordering::Greater {
+                self.stack
+                    .extend(node.left.as_deref().map(|left| (left, next)));
+            }
+            if compare(&self.max, &node.point, axis) != Ordering::Less {
+                self.stack
+                    .extend(node.right.as_deref().map(|right| (right, next)));
+            }
+
+            let inside = node
+                .point
+                .iter()
+                .zip(self.min.iter().zip(&self.max))
+                .all(|(coordinate, (min, max))| min <= coordinate && coordinate <= max);
+            if inside {
+                return Some(&node.point);
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "json",
+    serde(bound(serialize = "T: Serialize", deserialize = "T: Deserialize<'de>"))
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Node<T, const K: usize> {
+    #[cfg_attr(feature = "json", serde(with = "point"))]
+    point: [T; K],
+    left: Link<T, K>,
+    right: Link<T, K>,
+}
+
+impl<T, const K: usize> Node<T, K> {
+    /// Constructs a new `Node<T, K>` without children.
+    fn new(point: [T; K]) -> Self {
+        Self {
+            point,
+            left: None,
+            right: None,
+        }
+    }
+}
+
+/// Serializes points as tuples, as serde only provides this for arrays of up to 32 elements.
+#[cfg(feature = "json")]
+mod point {
+    use serde::de::{Error, SeqAccess, Visitor};
+    use serde::ser::SerializeTuple;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::fmt;
+    use std::marker::PhantomData;
+
+    pub(super) fn serialize<S, T, const K: usize>(
+        point: &[T; K],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+        T: Serialize,
+    {
+        let mut tuple = serializer.serialize_tuple(K)?;
+        for coordinate in point {
+            tuple.serialize_element(coordinate)?;
+        }
+
+        tuple.end()
+    }
+
+    pub(super) fn deserialize<'de, D, T, const K: usize>(
+        deserializer: D,
+    ) -> Result<[T; K], D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Deserialize<'de>,
+    {
+        deserializer.deserialize_tuple(K, PointVisitor(PhantomData))
+    }
+
+    struct PointVisitor<T, const K: usize>(PhantomData<T>);
+
+    impl<'de, T: Deserialize<'de>, const K: usize> Visitor<'de> for PointVisitor<T, K> {
+        type Value = [T; K];
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "a point of {K} coordinates")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+            let mut coordinates = Vec::with_capacity(K);
+            while let Some(coordinate) = seq.next_element()? {
+                coordinates.push(coordinate);
+            }
+
+            let len = coordinates.len();
+            coordinates
+                .try_into()
+                .map_err(|_| A::Error::invalid_length(len, &self))
+        }
+    }
+}
+
+#[cfg(test)]
+mod construction {
+    use super::{KdTree, Node};
+
+    #[test]
+    fn splits_at_the_median_of_alternating_axes() {
+        let tree = KdTree::from(vec![[2, 3], [5, 4], [9, 6], [4, 7], [8, 1], [7, 2]]);
+        let leaf = |point| Some(Box::new(Node::new(point)));
+        let expected = KdTree {
+            root: Some(Box::new(Node {
+                point: [7, 2],
+                left: Some(Box::new(Node {
+                    point: [5, 4],
+                    left: leaf([2, 3]),
+                    right: leaf([4, 7]),
+                })),
+                right: Some(Box::new(Node {
+                    point: [9, 6],
+                    left: leaf([8, 1]),
+                    right: None,
+                })),
+            })),
+            count: 6,
+        };
+
+        assert_eq!(tree, expected);
+    }
+
+    #[test]
+    fn inserts_follow_the_splits() {
+        let mut tree = KdTree::new();
+        tree.extend([[5, 5], [3, 8], [3, 2], [7, 1], [5, 5]]);
+
+        let leaf = |point| Some(Box::new(Node::new(point)));
+        let expected = KdTree {
+            root: Some(Box::new(Node {
+                point: [5, 5],
+                left: Some(Box::new(Node {
+                    point: [3, 8],
+                    left: leaf([3, 2]),
+                    right: None,
+                })),
+                right: Some(Box::new(Node {
+                    point: [7, 1],
+                    left: None,
+                    right: leaf([5, 5]),
+                })),
+            })),
+            count: 5,
+        };
+
+        assert_eq!(tree, expected);
+    }
+
+    #[test]
+    fn contains_finds_points_equal_to_a_split() {
+        let tree = KdTree::from(vec![[1, 0], [1, 1], [1, 2], [1, 3], [1, 4]]);
+
+        for y in 0..5 {
+            assert!(tree.contains(&[1, y]));
+        }
+        assert!(!tree.contains(&[1, 5]));
+        assert!(!tree.contains(&[0, 0]));
+    }
+
+    #[test]
+    fn balanced_build_keeps_height_logarithmic() {
+        let tree: KdTree<i32, 3> = (0..4095).map(|i| [i % 16, i / 16 % 16, i / 256]).collect();
+
+        assert_eq!(tree.count(), 4095);
+        assert_eq!(tree.height(), 12);
+    }
+}
+
+#[cfg(test)]
+mod search {
+    use super::{KdTree, Manhattan, Metric, SquaredEuclidean};
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    fn random_points(rng: &mut StdRng, count: usize) -> Vec<[i64; 3]> {
+        (0..count)
+            .map(|_| {
+                [
+                    rng.gen_range(-50..50),
+                    rng.gen_range(-50..50),
+                    rng.gen_range(-50..50),
+                ]
+            })
+            .collect()
+    }
+
+    fn brute_force_k_nearest<M: Metric<i64, Distance = i64>>(
+        points: &[[i64; 3]],
+        query: &[i64; 3],
+        k: usize,
+        metric: &M,
+    ) -> Vec<i64> {
+        let mut distances: Vec<_> = points
+            .iter()
+            .map(|point| metric.distance(query, point))
+            .collect();
+        distances.sort_unstable();
+        distances.truncate(k);
+
+        distances
+    }
+
+    fn k_nearest_matches_brute_force<M: Metric<i64, Distance = i64>>(seed: u64, metric: M) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let points = random_points(&mut rng, 400);
+        let mut tree = KdTree::from(points.clone());
+        // points inserted after the balanced build must be found as well
+        let extra = random_points(&mut rng, 100);
+        tree.extend(extra.iter().copied());
+        let points = [points, extra].concat();
+
+        for k in [0, 1, 5, 20, 600] {
+            for _ in 0..50 {
+                let query = random_points(&mut rng, 1).pop().unwrap_or_default();
+                // ties may be broken either way, so distances are compared instead of points
+                let actual: Vec<_> = tree
+                    .k_nearest(&query, k, &metric)
+                    .into_iter()
+                    .map(|point| metric.distance(&query, point))
+                    .collect();
+
+                assert_eq!(actual, brute_force_k_nearest(&points, &query, k, &metric));
+            }
+        }
+    }
+
+    #[test]
+    fn k_nearest_squared_euclidean() {
+        k_nearest_matches_brute_force(35, SquaredEuclidean);
+    }
+
+    #[test]
+    fn k_nearest_manhattan() {
+        k_nearest_matches_brute_force(135, Manhattan);
+    }
+
+    #[test]
+    fn within_radius_matches_brute_force() {
+        let mut rng = StdRng::seed_from_u64(235);
+        let points = random_points(&mut rng, 500);
+        let tree = KdTree::from(points.clone());
+
+        for _ in 0..100 {
+            let query = random_points(&mut rng, 1).pop().unwrap_or_default();
+            let radius = rng.gen_range(0..500);
+
+            let mut expected: Vec<_> = points
+                .iter()
+                .filter(|point| SquaredEuclidean.distance(&query, point) <= radius)
+                .collect();
+            let mut actual: Vec<_> = tree
+                .within_radius(query, radius, SquaredEuclidean)
+                .collect();
+            expected.sort_unstable();
+            actual.sort_unstable();
+
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn within_box_matches_brute_force() {
+        let mut rng = StdRng::seed_from_u64(335);
+        let points = random_points(&mut rng, 500);
+        let tree = KdTree::from(points.clone());
+
+        for _ in 0..100 {
+            let [a, b] = [random_points(&mut rng, 1), random_points(&mut rng, 1)]
+                .map(|mut point| point.pop().unwrap_or_default());
+            let [[ax, ay, az], [bx, by, bz]] = [a, b];
+            let min = [ax.min(bx), ay.min(by), az.min(bz)];
+            let max = [ax.max(bx), ay.max(by), az.max(bz)];
+
+            let inside = |point: &&[i64; 3]| {
+                point
+                    .iter()
+                    .zip(min.iter().zip(&max))
+                    .all(|(coordinate, (min, max))| min <= coordinate && coordinate <= max)
+            };
+            let mut expected: Vec<_> = points.iter().filter(inside).collect();
+            let mut actual: Vec<_> = tree.within_box(min, max).collect();
+            expected.sort_unstable();
+            actual.sort_unstable();
+
+            assert_eq!(actual, expected);
+        }
+    }
+
+    #[test]
+    fn float_coordinates() {
+        let tree = KdTree::from(vec![[0.0, 0.0], [3.0, 1.2], [-1.0, 3.5]]);
+
+        // the second point is closer in a straight line, but further along the axes
+        assert_eq!(
+            tree.nearest(&[2.0, 0.0], &SquaredEuclidean),
+            Some(&[3.0, 1.2])
+        );
+        assert_eq!(tree.nearest(&[2.0, 0.0], &Manhattan), Some(&[0.0, 0.0]));
+    }
+
+    #[test]
+    fn empty_tree_finds_nothing() {
+        let tree: KdTree<i32, 2> = KdTree::new();
+
+        assert_eq!(tree.nearest(&[0, 0], &SquaredEuclidean), None);
+        assert_eq!(tree.within_radius([0, 0], 10, Manhattan).count(), 0);
+        assert_eq!(tree.within_box([0, 0], [1, 1]).count(), 0);
+    }
+}
+
+#[cfg(test)]
+mod iterator_trait_impls {
+    use super::KdTree;
+
+    #[test]
+    fn iter_and_into_iter_yield_every_point() {
+        let mut points: Vec<[u8; 2]> = (0..50).map(|i| [i % 7, i / 7]).collect();
+        let tree: KdTree<u8, 2> = points.iter().copied().collect();
+
+        let mut borrowed: Vec<_> = tree.iter().copied().collect();
+        assert_eq!(tree.iter().len(), 50);
+        let mut owned: Vec<_> = tree.into_iter().collect();
+        points.sort_unstable();
+        borrowed.sort_unstable();
+        owned.sort_unstable();
+
+        assert_eq!(borrowed, points);
+        assert_eq!(owned, points);
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod json {
+    use super::KdTree;
+
+    #[test]
+    fn round_trips_through_json() {
+        let tree = KdTree::from(vec![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+
+        let json = serde_json::to_string(&tree).expect("should parse tree into json");
+        let actual: KdTree<f64, 3> =
+            serde_json::from_str(&json).expect("should parse json into tree");
+
+        assert_eq!(actual, tree);
+    }
+
+    #[test]
+    fn rejects_points_of_the_wrong_dimension() {
+        let json = r#"{"root":{"point":[1.0,2.0],"left":null,"right":null},"count":1}"#;
+
+        assert!(serde_json::from_str::<KdTree<f64, 3>>(json).is_err());
+    }
+}
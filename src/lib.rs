@@ -1,4 +1,41 @@
 //! # Features
+//! **std** (default): enables the data structures that depend on the standard library.
+//! Without it, the crate is `no_std` and only depends on `alloc`, which keeps the
+//! [`binary_tree`], [`bounded_tree`], [`arena_tree`] and [`sorted_set`] modules.
+//!
 //! **json**: derives the serde Serialize and Deserialize on the provided data structures.
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+pub mod arena_tree;
+#[cfg(feature = "std")]
+pub mod augmented_tree;
+#[cfg(feature = "std")]
+mod avl;
+#[cfg(feature = "std")]
+pub mod b_tree;
 pub mod binary_tree;
+pub mod bounded_tree;
+#[cfg(feature = "std")]
+pub mod concurrent;
+#[cfg(feature = "std")]
+pub mod fenwick_tree;
+#[cfg(feature = "std")]
+pub mod interval_tree;
+#[cfg(feature = "std")]
+pub mod kd_tree;
+#[cfg(feature = "std")]
+pub mod persistent;
+#[cfg(feature = "std")]
+pub mod r_tree;
+#[cfg(feature = "std")]
+pub mod scapegoat_tree;
+#[cfg(feature = "std")]
+pub mod segment_tree;
+pub mod sorted_set;
+#[cfg(feature = "std")]
+pub mod splay_tree;
+#[cfg(feature = "std")]
+pub mod weight_balanced_tree;
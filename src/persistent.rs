@@ -0,0 +1,734 @@
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
+
+use crate::sorted_set::SortedSet;
+
+/// An immutable binary search tree, where every change creates a new version of the tree.
+///
+/// The nodes are shared between versions through [`Arc`], and a change copies nothing but the
+/// nodes on the path to the changed element, so every version stays valid and cloning a version
+/// takes constant time. Versions can be handed to other threads while a writer keeps creating new ones.
+///
+/// The tree is kept balanced like an AVL tree, and creating a new version clones the `O(log(n))`
+/// elements on the copied path, so changes require `T: Clone`.
+///
+/// # Examples
+/// ```
+/// # use ds_rs::persistent::PersistentTree;
+/// let v1 = PersistentTree::from(vec![1, 2, 3]);
+/// let v2 = v1.insert(4);
+/// let v3 = v2.remove(&1);
+///
+/// assert_eq!(v1.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+/// assert_eq!(v2.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+/// assert_eq!(v3.iter().copied().collect::<Vec<_>>(), vec![2, 3, 4]);
+/// ```
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct PersistentTree<T> {
+    root: Link<T>,
+    count: usize,
+}
+
+type Link<T> = Option<Arc<Node<T>>>;
+
+impl<T> PersistentTree<T> {
+    /// Constructs a new empty `PersistentTree<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ds_rs::persistent::PersistentTree;
+    /// let tree: PersistentTree<i32> = PersistentTree::new();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            root: None,
+            count: 0,
+        }
+    }
+
+    /// Returns `true` if the tree contains no elements.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::persistent::PersistentTree;
+    /// let tree = PersistentTree::new();
+    /// assert!(tree.is_empty());
+    ///
+    /// let tree = tree.insert(0);
+    /// assert!(!tree.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Returns the height of the tree.
+    ///
+    /// An empty tree has a height of `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::persistent::PersistentTree;
+    /// let tree: PersistentTree<i32> = (0..7).collect();
+    /// assert_eq!(tree.height(), 3);
+    /// ```
+    #[inline]
+    pub fn height(&self) -> usize {
+        height(&self.root)
+    }
+
+    /// Returns the number of elements in the tree.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::persistent::PersistentTree;
+    /// let tree = PersistentTree::new().insert(1).insert(2);
+    /// assert_eq!(tree.count(), 2);
+    /// ```
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns a reference to the value of the root, or `None` if the tree is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::persistent::PersistentTree;
+    /// let tree = PersistentTree::from(vec![1, 2, 3]);
+    /// assert_eq!(tree.root(), Some(&2));
+    /// ```
+    #[inline]
+    pub fn root(&self) -> Option<&T> {
+        self.root.as_deref().map(|node| &node.value)
+    }
+
+    /// Returns a reference to the smallest element in the tree, or `None` if the tree is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::persistent::PersistentTree;
+    /// let tree = PersistentTree::from(vec![5, 1, 9]);
+    /// assert_eq!(tree.min(), Some(&1));
+    /// ```
+    pub fn min(&self) -> Option<&T> {
+        let mut node = self.root.as_deref()?;
+        while let Some(left) = node.left.as_deref() {
+            node = left;
+        }
+
+        Some(&node.value)
+    }
+
+    /// Returns a reference to the largest element in the tree, or `None` if the tree is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::persistent::PersistentTree;
+    /// let tree = PersistentTree::from(vec![5, 1, 9]);
+    /// assert_eq!(tree.max(), Some(&9));
+    /// ```
+    pub fn max(&self) -> Option<&T> {
+        let mut node = self.root.as_deref()?;
+        while let Some(right) = node.right.as_deref() {
+            node = right;
+        }
+
+        Some(&node.value)
+    }
+
+    /// Returns `true` if both trees are the same version, sharing their root node.
+    ///
+    /// Trees that are not the same version may still hold equal elements.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::persistent::PersistentTree;
+    /// let tree = PersistentTree::from(vec![1, 2, 3]);
+    ///
+    /// // inserting an element that is already present does not create a new version
+    /// assert!(tree.ptr_eq(&tree.clone()));
+    /// assert!(tree.ptr_eq(&tree.insert(2)));
+    /// assert!(!tree.ptr_eq(&PersistentTree::from(vec![1, 2, 3])));
+    /// ```
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        match (&self.root, &other.root) {
+            (Some(root), Some(other)) => Arc::ptr_eq(root, other),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    /// Returns a non-consuming iterator over the `PersistentTree`.
+    ///
+    /// The iterator yields all elements in ascending order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::persistent::PersistentTree;
+    /// let tree = PersistentTree::from(vec![2, 3, 1]);
+    /// let mut tree_iter = tree.iter();
+    ///
+    /// assert_eq!(tree_iter.next(), Some(&1));
+    /// assert_eq!(tree_iter.next(), Some(&2));
+    /// assert_eq!(tree_iter.next(), Some(&3));
+    ///
+    /// // the iterator is now empty
+    /// assert_eq!(tree_iter.next(), None);
+    /// ```
+    #[inline]
+    #[must_use = "iterators are evaluated lazily"]
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.into_iter()
+    }
+}
+
+impl<T> Clone for PersistentTree<T> {
+    /// Returns the same version of the tree, which shares every node and takes constant time.
+    fn clone(&self) -> Self {
+        Self {
+            root: self.root.clone(),
+            count: self.count,
+        }
+    }
+}
+
+impl<T> Default for PersistentTree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The methods in this implementation block require trait bounds to correctly apply the logic of the methods.
+///
+/// The trait bound in question is [`Ord`], which is used to order the elements,
+/// and changes additionally require [`Clone`] to copy the elements on the changed path.
+impl<T: Ord> PersistentTree<T> {
+    /// Returns a new version of the tree that also contains the provided value.
+    ///
+    /// If the tree already contains the value, the same version is returned.
+    ///
+    /// # Time Complexity
+    ///
+    /// Copies the `O(log(n))` nodes on the path to the new value, and shares every other node.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::persistent::PersistentTree;
+    /// let before = PersistentTree::new().insert(1);
+    /// let after = before.insert(2);
+    ///
+    /// assert!(!before.contains(&2));
+    /// assert!(after.contains(&2));
+    /// ```
+    #[must_use = "insert returns a new version and leaves this one unchanged"]
+    pub fn insert(&self, value: T) -> Self
+    where
+        T: Clone,
+    {
+        match insert(&self.root, value) {
+            Some(root) => Self {
+                root: Some(root),
+                count: self.count + 1,
+            },
+            None => self.clone(),
+        }
+    }
+
+    /// Returns a new version of the tree without the provided value.
+    ///
+    /// If the tree does not contain the value, the same version is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::persistent::PersistentTree;
+    /// let before = PersistentTree::from(vec![1, 2]);
+    /// let after = before.remove(&1);
+    ///
+    /// assert!(before.contains(&1));
+    /// assert!(!after.contains(&1));
+    /// ```
+    #[must_use = "remove returns a new version and leaves this one unchanged"]
+    pub fn remove(&self, target: &T) -> Self
+    where
+        T: Clone,
+    {
+        match remove(&self.root, target) {
+            Some((root, _)) => Self {
+                root,
+                count: self.count - 1,
+            },
+            None => self.clone(),
+        }
+    }
+
+    /// Returns `true` if the `PersistentTree` contains the given value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::persistent::PersistentTree;
+    /// let tree = PersistentTree::from(vec![1, 2]);
+    /// assert!(tree.contains(&1));
+    /// assert!(!tree.contains(&3));
+    /// ```
+    pub fn contains(&self, target: &T) -> bool {
+        let mut link = &self.root;
+        while let Some(node) = link.as_deref() {
+            link = match target.cmp(&node.value) {
+                Ordering::Equal => return true,
+                Ordering::Less => &node.left,
+                Ordering::Greater => &node.right,
+            };
+        }
+
+        false
+    }
+}
+
+fn height<T>(link: &Link<T>) -> usize {
+    link.as_deref().map_or(0, |node| node.height)
+}
+
+/// Returns the path to `value` copied with `value` added, or `None` if the value is already present.
+fn insert<T: Ord + Clone>(link: &Link<T>, value: T) -> Option<Arc<Node<T>>> {
+    let Some(node) = link.as_deref() else {
+        return Some(Node::new(value, None, None));
+    };
+
+    match value.cmp(&node.value) {
+        Ordering::Equal => None,
+        Ordering::Less => {
+            let left = insert(&node.left, value)?;
+            Some(balance(node.value.clone(), Some(left), node.right.clone()))
+        }
+        Ordering::Greater => {
+            let right = insert(&node.right, value)?;
+            Some(balance(node.value.clone(), node.left.clone(), Some(right)))
+        }
+    }
+}
+
+/// Returns the path to `target` copied with `target` removed, along with the removed element.
+fn remove<T: Ord + Clone>(link: &Link<T>, target: &T) -> Option<(Link<T>, T)> {
+    let node = link.as_deref()?;
+
+    match target.cmp(&node.value) {
+        Ordering::Less => {
+            let (left, removed) = remove(&node.left, target)?;
+            Some((
+                Some(balance(node.value.clone(), left, node.right.clone())),
+                removed,
+            ))
+        }
+        Ordering::Greater => {
+            let (right, removed) = remove(&node.right, target)?;
+            Some((
+                Some(balance(node.value.clone(), node.left.clone(), right)),
+                removed,
+            ))
+        }
+        Ordering::Equal => {
+            let removed = node.value.clone();
+            let root = match (&node.left, node.right.as_deref()) {
+                (left, None) => left.clone(),
+                (None, Some(_)) => node.right.clone(),
+                (Some(_), Some(right)) => {
+                    let (right, successor) = pop_min(right);
+                    Some(balance(successor, node.left.clone(), right))
+                }
+            };
+
+            Some((root, removed))
+        }
+    }
+}
+
+/// Returns the subtree rooted at `node` copied without its smallest element, along with that element.
+fn pop_min<T: Clone>(node: &Node<T>) -> (Link<T>, T) {
+    match node.left.as_deref() {
+        None => (node.right.clone(), node.value.clone()),
+        Some(left) => {
+            let (left, min) = pop_min(left);
+            (
+                Some(balance(node.value.clone(), left, node.right.clone())),
+                min,
+            )
+        }
+    }
+}
+
+/// Returns a new node joining the subtrees under `value`, rotating them if their heights differ by more than one.
+///
+/// Rotations copy the nodes they move, and share the subtrees below them.
+fn balance<T: Clone>(value: T, left: Link<T>, right: Link<T>) -> Arc<Node<T>> {
+    let (left_height, right_height) = (height(&left), height(&right));
+
+    if left_height > right_height + 1 {
+        if let Some(left) = left.as_deref() {
+            if height(&left.left) >= height(&left.right) {
+                let right = Node::new(value, left.right.clone(), right);
+                return Node::new(left.value.clone(), left.left.clone(), Some(right));
+            }
+            if let Some(middle) = left.right.as_deref() {
+                let new_left =
+                    Node::new(left.value.clone(), left.left.clone(), middle.left.clone());
+                let new_right = Node::new(value, middle.right.clone(), right);
+                return Node::new(middle.value.clone(), Some(new_left), Some(new_right));
+            }
+        }
+    } else if right_height > left_height + 1 {
+        if let Some(right) = right.as_deref() {
+            if height(&right.right) >= height(&right.left) {
+                let left = Node::new(value, left, right.left.clone());
+                return Node::new(right.value.clone(), Some(left), right.right.clone());
+            }
+            if let Some(middle) = right.left.as_deref() {
+                let new_left = Node::new(value, left, middle.left.clone());
+                let new_right = Node::new(
+                    right.value.clone(),
+                    middle.right.clone(),
+                    right.right.clone(),
+                );
+                return Node::new(middle.value.clone(), Some(new_left), Some(new_right));
+            }
+        }
+    }
+
+    Node::new(value, left, right)
+}
+
+/// Builds a perfectly balanced subtree from sorted and deduplicated values.
+fn build<T>(values: &mut std::vec::IntoIter<T>, count: usize) -> Link<T> {
+    if count == 0 {
+        return None;
+    }
+
+    let left_count = count / 2;
+    let left = build(values, left_count);
+    let value = values.next()?;
+    let right = build(values, count - left_count - 1);
+
+    Some(Node::new(value, left, right))
+}
+
+impl<T: Ord + Clone> SortedSet<T> for PersistentTree<T> {
+    type Iter<'a>
+        = Iter<'a, T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    fn insert(&mut self, value: T) -> bool {
+        let count = self.count;
+        *self = PersistentTree::insert(self, value);
+
+        self.count > count
+    }
+
+    fn remove(&mut self, target: &T) -> Option<T> {
+        let (root, removed) = remove(&self.root, target)?;
+        self.root = root;
+        self.count -= 1;
+
+        Some(removed)
+    }
+
+    fn contains(&self, target: &T) -> bool {
+        PersistentTree::contains(self, target)
+    }
+
+    fn min(&self) -> Option<&T> {
+        PersistentTree::min(self)
+    }
+
+    fn max(&self) -> Option<&T> {
+        PersistentTree::max(self)
+    }
+
+    fn len(&self) -> usize {
+        self.count
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        PersistentTree::iter(self)
+    }
+}
+
+impl<T: Ord> From<Vec<T>> for PersistentTree<T> {
+    /// Creates a balanced `PersistentTree<T>` from `Vec<T>`, discarding duplicate values.
+    fn from(mut vec: Vec<T>) -> Self {
+        vec.sort_unstable();
+        vec.dedup();
+
+        let count = vec.len();
+        Self {
+            root: build(&mut vec.into_iter(), count),
+            count,
+        }
+    }
+}
+
+impl<T: Ord> FromIterator<T> for PersistentTree<T> {
+    /// Constructs a balanced `PersistentTree<T>` from an iterator, discarding duplicate values.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+impl<T: Ord + Clone> Extend<T> for PersistentTree<T> {
+    /// Replaces the tree with a version that also contains the contents of the provided iterator.
+    ///
+    /// Other versions of the tree are not affected.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            *self = PersistentTree::insert(self, value);
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a PersistentTree<T> {
+    type Item = &'a T;
+
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let mut iter = Iter {
+            stack: Vec::new(),
+            remaining: self.count,
+        };
+        iter.push_left(self.root.as_deref());
+
+        iter
+    }
+}
+
+/// An iterator that borrows from the `PersistentTree`, and yields its items in ascending order.
+///
+/// This `struct` is created by the `iter` method on [`PersistentTree`].
+pub struct Iter<'a, T> {
+    stack: Vec<&'a Node<T>>,
+    remaining: usize,
+}
+
+impl<'a, T> Iter<'a, T> {
+    /// Pushes the left spine of the subtree rooted at `node`.
+    fn push_left(&mut self, mut node: Option<&'a Node<T>>) {
+        while let Some(current) = node {
+            self.stack.push(current);
+            node = current.left.as_deref();
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left(node.right.as_deref());
+        self.remaining -= 1;
+
+        Some(&node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[derive(Debug, PartialEq, Eq, Hash)]
+struct Node<T> {
+    value: T,
+    height: usize,
+    left: Link<T>,
+    right: Link<T>,
+}
+
+impl<T> Node<T> {
+    /// Constructs a new shared `Node<T>` with the given subtrees.
+    fn new(value: T, left: Link<T>, right: Link<T>) -> Arc<Self> {
+        Arc::new(Self {
+            value,
+            height: height(&left).max(height(&right)) + 1,
+            left,
+            right,
+        })
+    }
+}
+
+#[cfg(test)]
+impl<T: Ord> PersistentTree<T> {
+    /// Checks the ordering of the elements, the stored heights, and the balance of every node.
+    fn assert_invariants(&self) {
+        /// Returns the height and the number of elements of the subtree.
+        fn walk<T: Ord>(link: &Link<T>) -> (usize, usize) {
+            let Some(node) = link.as_deref() else {
+                return (0, 0);
+            };
+            let ((left_height, left_count), (right_height, right_count)) =
+                (walk(&node.left), walk(&node.right));
+
+            assert!(left_height.abs_diff(right_height) <= 1);
+            assert_eq!(node.height, left_height.max(right_height) + 1);
+            (node.height, left_count + right_count + 1)
+        }
+
+        assert_eq!(walk(&self.root).1, self.count);
+        assert!(self.iter().zip(self.iter().skip(1)).all(|(a, b)| a < b));
+    }
+}
+
+#[cfg(test)]
+mod versions {
+    use super::PersistentTree;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use std::collections::BTreeSet;
+    use std::sync::Arc;
+
+    #[test]
+    fn clone_shares_the_root() {
+        let tree: PersistentTree<i32> = (0..100).collect();
+        let clone = tree.clone();
+
+        assert!(tree.ptr_eq(&clone));
+        assert_eq!(tree, clone);
+    }
+
+    #[test]
+    fn insert_shares_untouched_subtrees() {
+        let before: PersistentTree<i32> = (0..15).collect();
+        let after = before.insert(100);
+
+        let (before_root, after_root) = (before.root.as_deref(), after.root.as_deref());
+        let shared = before_root
+            .zip(after_root)
+            .and_then(|(before, after)| before.left.as_ref().zip(after.left.as_ref()))
+            .is_some_and(|(before, after)| Arc::ptr_eq(before, after));
+
+        // the new value lands on the right, so the left subtree is shared as is
+        assert!(shared);
+        assert!(!before.ptr_eq(&after));
+    }
+
+    #[test]
+    fn removing_missing_value_returns_same_version() {
+        let tree = PersistentTree::from(vec![1, 2, 3]);
+
+        assert!(tree.ptr_eq(&tree.remove(&4)));
+    }
+
+    #[test]
+    fn old_versions_stay_valid() {
+        let mut rng = StdRng::seed_from_u64(37);
+        let mut versions = vec![(PersistentTree::new(), BTreeSet::new())];
+
+        for _ in 0..500 {
+            let (tree, expected) = versions
+                .get(rng.gen_range(0..versions.len()))
+                .cloned()
+                .unwrap_or_default();
+            let value = rng.gen_range(0..100);
+
+            let next = if rng.gen_bool(0.6) {
+                let mut expected = expected.clone();
+                expected.insert(value);
+                (tree.insert(value), expected)
+            } else {
+                let mut expected = expected.clone();
+                expected.remove(&value);
+                (tree.remove(&value), expected)
+            };
+            versions.push(next);
+        }
+
+        for (tree, expected) in &versions {
+            tree.assert_invariants();
+            assert_eq!(tree.count(), expected.len());
+            assert!(tree.iter().eq(expected));
+        }
+    }
+
+    #[test]
+    fn versions_are_shared_across_threads() {
+        let tree: PersistentTree<i32> = (0..1000).collect();
+
+        std::thread::scope(|scope| {
+            let readers: Vec<_> = (0..4)
+                .map(|reader| {
+                    let snapshot = tree.clone();
+                    scope.spawn(move || {
+                        snapshot
+                            .iter()
+                            .filter(|&&value| value % 4 == reader)
+                            .count()
+                    })
+                })
+                .collect();
+
+            let tree = tree.remove(&0).insert(1000);
+            assert_eq!(tree.count(), 1000);
+
+            for reader in readers {
+                assert_eq!(reader.join().ok(), Some(250));
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod construction {
+    use super::PersistentTree;
+
+    #[test]
+    fn from_vec_is_balanced_and_deduplicated() {
+        let tree = PersistentTree::from(vec![3, 1, 2, 3, 1, 7, 5, 6, 4]);
+
+        tree.assert_invariants();
+        assert_eq!(tree.count(), 7);
+        assert_eq!(tree.height(), 3);
+    }
+
+    #[test]
+    fn ascending_inserts_stay_balanced() {
+        let mut tree = PersistentTree::new();
+        tree.extend(0..1023);
+
+        tree.assert_invariants();
+        assert_eq!(tree.height(), 10);
+    }
+
+    #[test]
+    fn min_and_max() {
+        let tree = PersistentTree::from(vec![4, -2, 9]);
+
+        assert_eq!(tree.min(), Some(&-2));
+        assert_eq!(tree.max(), Some(&9));
+        assert_eq!(PersistentTree::<i32>::new().min(), None);
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod json {
+    use super::PersistentTree;
+
+    #[test]
+    fn round_trips_through_json() {
+        let tree: PersistentTree<i32> = (0..20).collect();
+
+        let json = serde_json::to_string(&tree).expect("should parse tree into json");
+        let actual: PersistentTree<i32> =
+            serde_json::from_str(&json).expect("should parse json into tree");
+
+        assert_eq!(actual, tree);
+    }
+}
@@ -0,0 +1,1070 @@
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::{mem, slice, vec};
+
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
+
+pub mod rect;
+
+use rect::{Axis, Bounded, Rect};
+
+/// The number of entries a node holds before it is split.
+const MAX_ENTRIES: usize = 8;
+
+/// The number of entries every node but the root holds at least.
+const MIN_ENTRIES: usize = 3;
+
+/// A tree of values with a position in two dimensions, found through the rectangles around them.
+///
+/// Every node covers the rectangles of its entries with one rectangle of its own, and searches only
+/// descend into nodes whose rectangle can hold a result. All values are stored in leaves at the same
+/// depth, and every node but the root holds between 3 and 8 entries.
+///
+/// Values expose their rectangle through the [`Bounded`] trait, and equal values are all kept.
+///
+/// # Examples
+/// ```
+/// # use ds_rs::r_tree::{rect::Rect, RTree};
+/// let shapes = RTree::from(vec![
+///     Rect::new([0.0, 0.0], [2.0, 1.0]),
+///     Rect::new([5.0, 5.0], [6.0, 8.0]),
+///     Rect::new([1.0, 3.0], [2.0, 4.0]),
+/// ]);
+///
+/// let hits: Vec<_> = shapes.intersecting(Rect::new([1.5, 0.5], [3.0, 3.5])).collect();
+/// assert_eq!(hits.len(), 2);
+/// ```
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct RTree<T> {
+    root: Node<T>,
+    count: usize,
+}
+
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+enum Node<T> {
+    Leaf(Vec<T>),
+    Branch(Vec<Child<T>>),
+}
+
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+struct Child<T> {
+    bounds: Rect,
+    node: Node<T>,
+}
+
+impl<T> RTree<T> {
+    /// Constructs a new empty `RTree<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ds_rs::r_tree::{rect::Rect, RTree};
+    /// # #[allow(unused_mut)]
+    /// let mut tree: RTree<Rect> = RTree::new();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            root: Node::Leaf(Vec::new()),
+            count: 0,
+        }
+    }
+
+    /// Returns `true` if the tree contains no values.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::r_tree::RTree;
+    /// let mut tree = RTree::new();
+    /// assert!(tree.is_empty());
+    ///
+    /// tree.insert([0.0, 0.0]);
+    /// assert!(!tree.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    /// Clears the tree of all values.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::r_tree::RTree;
+    /// let mut tree = RTree::from(vec![[0.0, 0.0], [1.0, 1.0]]);
+    ///
+    /// tree.clear();
+    /// assert!(tree.is_empty());
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.root = Node::Leaf(Vec::new());
+        self.count = 0;
+    }
+
+    /// Returns the number of values in the tree.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::r_tree::RTree;
+    /// let tree = RTree::from(vec![[0.0, 0.0], [1.0, 1.0], [0.0, 0.0]]);
+    /// assert_eq!(tree.count(), 3);
+    /// ```
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns the number of levels in the tree.
+    ///
+    /// An empty tree has a height of `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::r_tree::RTree;
+    /// let tree: RTree<[f64; 2]> = (0..64).map(|i| [f64::from(i % 8), f64::from(i / 8)]).collect();
+    /// assert_eq!(tree.height(), 2);
+    /// ```
+    pub fn height(&self) -> usize {
+        if self.is_empty() {
+            return 0;
+        }
+
+        let mut height = 1;
+        let mut node = &self.root;
+        while let Some(child) = node.children().first() {
+            height += 1;
+            node = &child.node;
+        }
+
+        height
+    }
+
+    /// Returns a non-consuming iterator over the `RTree`.
+    ///
+    /// The values are yielded in no particular order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::r_tree::RTree;
+    /// let tree = RTree::from(vec![[0.0, 0.0], [1.0, 1.0]]);
+    /// let mut values: Vec<_> = tree.iter().collect();
+    /// values.sort_by(|[a, _], [b, _]| a.total_cmp(b));
+    ///
+    /// assert_eq!(values, vec![&[0.0, 0.0], &[1.0, 1.0]]);
+    /// ```
+    #[inline]
+    #[must_use = "iterators are evaluated lazily"]
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.into_iter()
+    }
+}
+
+impl<T> Default for RTree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The methods in this implementation block require trait bounds to correctly apply the logic of the methods.
+///
+/// The trait bound in question is [`Bounded`], which is used to place and find values by their rectangles.
+impl<T: Bounded> RTree<T> {
+    /// Inserts the provided value into the `RTree`.
+    ///
+    /// The value is added to the leaf whose rectangle grows the least, and nodes that overflow are
+    /// split in two, which may add a level at the root.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::r_tree::{rect::Rect, RTree};
+    /// let mut tree = RTree::new();
+    /// tree.insert(Rect::new([0.0, 0.0], [1.0, 1.0]));
+    /// tree.insert(Rect::new([4.0, 4.0], [5.0, 6.0]));
+    ///
+    /// assert_eq!(tree.bounds(), Some(Rect::new([0.0, 0.0], [5.0, 6.0])));
+    /// ```
+    pub fn insert(&mut self, value: T) {
+        self.insert_value(value);
+        self.count += 1;
+    }
+
+    /// Removes a value equal to the provided value from the `RTree`, and returns it.
+    ///
+    /// A node left with too few entries is dissolved, and its values are inserted again.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::r_tree::RTree;
+    /// let mut tree = RTree::from(vec![[0.0, 0.0], [1.0, 1.0]]);
+    ///
+    /// assert_eq!(tree.remove(&[1.0, 1.0]), Some([1.0, 1.0]));
+    /// assert_eq!(tree.remove(&[1.0, 1.0]), None);
+    /// assert_eq!(tree.count(), 1);
+    /// ```
+    pub fn remove(&mut self, target: &T) -> Option<T>
+    where
+        T: PartialEq,
+    {
+        let mut orphans = Vec::new();
+        let removed = remove(&mut self.root, target, &target.bounding_box(), &mut orphans)?;
+        self.count -= 1;
+
+        // a root with a single child is replaced by it, removing a level
+        while let Node::Branch(children) = &mut self.root {
+            if children.len() > 1 {
+                break;
+            }
+            self.root = children
+                .pop()
+                .map_or_else(|| Node::Leaf(Vec::new()), |child| child.node);
+        }
+
+        for orphan in orphans {
+            self.insert_value(orphan);
+        }
+
+        Some(removed)
+    }
+
+    /// Returns the smallest rectangle containing every value, or `None` if the tree is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::r_tree::{rect::Rect, RTree};
+    /// let tree = RTree::from(vec![[3.0, 1.0], [-1.0, 2.0]]);
+    /// assert_eq!(tree.bounds(), Some(Rect::new([-1.0, 1.0], [3.0, 2.0])));
+    /// ```
+    pub fn bounds(&self) -> Option<Rect> {
+        (!self.is_empty()).then(|| self.root.bounds())
+    }
+
+    /// Returns an iterator over the values whose rectangles intersect `area`, including those only touching its boundary.
+    ///
+    /// The values are yielded in no particular order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::r_tree::{rect::Rect, RTree};
+    /// let tree = RTree::from(vec![[0.0, 0.0], [1.0, 1.0], [2.0, 2.0]]);
+    ///
+    /// let inside: Vec<_> = tree.intersecting(Rect::new([0.5, 0.5], [1.5, 4.0])).collect();
+    /// assert_eq!(inside, vec![&[1.0, 1.0]]);
+    /// ```
+    #[must_use = "iterators are evaluated lazily"]
+    pub fn intersecting(&self, area: Rect) -> Intersecting<'_, T> {
+        Intersecting {
+            stack: vec![&self.root],
+            values: [].iter(),
+            area,
+        }
+    }
+
+    /// Returns the value whose rectangle is closest to `point`.
+    ///
+    /// If several values are equally close, any of them may be returned.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::r_tree::{rect::Rect, RTree};
+    /// let tree = RTree::from(vec![
+    ///     Rect::new([0.0, 0.0], [1.0, 4.0]),
+    ///     Rect::new([3.0, 3.0], [4.0, 4.0]),
+    /// ]);
+    ///
+    /// assert_eq!(tree.nearest([2.2, 0.0]), Some(&Rect::new([0.0, 0.0], [1.0, 4.0])));
+    /// ```
+    pub fn nearest(&self, point: [f64; 2]) -> Option<&T> {
+        self.nearest_iter(point).next()
+    }
+
+    /// Returns an iterator over all values, from the closest to `point` to the furthest.
+    ///
+    /// The distance of a value is the distance from `point` to the closest point of its rectangle,
+    /// and values at equal distances are yielded in any order. The tree is only searched as far as
+    /// the iterator is advanced.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::r_tree::RTree;
+    /// let tree = RTree::from(vec![[0.0, 0.0], [5.0, 0.0], [2.0, 0.0]]);
+    /// let mut by_distance = tree.nearest_iter([6.0, 0.0]);
+    ///
+    /// assert_eq!(by_distance.next(), Some(&[5.0, 0.0]));
+    /// assert_eq!(by_distance.next(), Some(&[2.0, 0.0]));
+    /// assert_eq!(by_distance.next(), Some(&[0.0, 0.0]));
+    /// assert_eq!(by_distance.next(), None);
+    /// ```
+    #[must_use = "iterators are evaluated lazily"]
+    pub fn nearest_iter(&self, point: [f64; 2]) -> NearestIter<'_, T> {
+        NearestIter {
+            queue: BinaryHeap::from(vec![Queued {
+                distance: 0.0,
+                item: Item::Node(&self.root),
+            }]),
+            point,
+        }
+    }
+
+    /// Inserts the value without counting it, which lets removals reinsert their orphans.
+    fn insert_value(&mut self, value: T) {
+        let bounds = value.bounding_box();
+        if let Some(sibling) = insert(&mut self.root, value, &bounds) {
+            let root = mem::replace(&mut self.root, Node::Branch(Vec::new()));
+            self.root = Node::Branch(vec![Child::new(root), Child::new(sibling)]);
+        }
+    }
+}
+
+impl<T> Node<T> {
+    /// Returns the number of entries in the node.
+    fn len(&self) -> usize {
+        match self {
+            Node::Leaf(values) => values.len(),
+            Node::Branch(children) => children.len(),
+        }
+    }
+
+    /// Returns the children of the node, which leaves have none of.
+    fn children(&self) -> &[Child<T>] {
+        match self {
+            Node::Leaf(_) => &[],
+            Node::Branch(children) => children,
+        }
+    }
+
+    /// Moves every value below the node into `values`.
+    fn drain_into(self, values: &mut Vec<T>) {
+        match self {
+            Node::Leaf(leaf) => values.extend(leaf),
+            Node::Branch(children) => {
+                for child in children {
+                    child.node.drain_into(values);
+                }
+            }
+        }
+    }
+}
+
+impl<T: Bounded> Node<T> {
+    /// Returns the smallest rectangle containing every entry of the node.
+    fn bounds(&self) -> Rect {
+        match self {
+            Node::Leaf(values) => cover(values, T::bounding_box),
+            Node::Branch(children) => cover(children, |child| child.bounds),
+        }
+    }
+}
+
+impl<T: Bounded> Child<T> {
+    /// Constructs a new `Child<T>` with the rectangle covering the node.
+    fn new(node: Node<T>) -> Self {
+        Self {
+            bounds: node.bounds(),
+            node,
+        }
+    }
+}
+
+/// Returns the smallest rectangle containing every entry.
+fn cover<E>(entries: &[E], bounds: impl Fn(&E) -> Rect) -> Rect {
+    entries
+        .iter()
+        .fold(Rect::EMPTY, |cover, entry| cover.union(&bounds(entry)))
+}
+
+/// Inserts the value below `node`, and returns the new sibling of `node` if it had to be split.
+fn insert<T: Bounded>(node: &mut Node<T>, value: T, bounds: &Rect) -> Option<Node<T>> {
+    match node {
+        Node::Leaf(values) => {
+            values.push(value);
+            (values.len() > MAX_ENTRIES).then(|| Node::Leaf(split(values, T::bounding_box)))
+        }
+        Node::Branch(children) => {
+            let sibling = match choose_subtree(children, bounds) {
+                Some(child) => match insert(&mut child.node, value, bounds) {
+                    Some(sibling) => {
+                        child.bounds = child.node.bounds();
+                        Child::new(sibling)
+                    }
+                    None => {
+                        child.bounds = child.bounds.union(bounds);
+                        return None;
+                    }
+                },
+                // branches are never left empty, but a lone leaf keeps the value if one were
+                None => Child::new(Node::Leaf(vec![value])),
+            };
+
+            children.push(sibling);
+            (children.len() > MAX_ENTRIES)
+                .then(|| Node::Branch(split(children, |child| child.bounds)))
+        }
+    }
+}
+
+/// Returns the child whose rectangle grows the least by covering `bounds`, preferring smaller rectangles on ties.
+fn choose_subtree<'a, T>(children: &'a mut [Child<T>], bounds: &Rect) -> Option<&'a mut Child<T>> {
+    let growth = |child: &Child<T>| {
+        let area = child.bounds.area();
+        (child.bounds.union(bounds).area() - area, area)
+    };
+
+    children.iter_mut().min_by(|a, b| {
+        let ((a_growth, a_area), (b_growth, b_area)) = (growth(a), growth(b));
+        a_growth
+            .total_cmp(&b_growth)
+            .then(a_area.total_cmp(&b_area))
+    })
+}
+
+/// Splits an overflowing node, keeping the first group of entries in `entries` and returning the second.
+///
+/// The entries are sorted along the axis where the groups have the smallest outlines, and divided
+/// where the groups overlap the least, preferring the smallest total area on ties.
+fn split<E>(entries: &mut Vec<E>, bounds: impl Fn(&E) -> Rect) -> Vec<E> {
+    let sort = |entries: &mut Vec<E>, axis| {
+        entries.sort_by(|a, b| bounds(a).center(axis).total_cmp(&bounds(b).center(axis)));
+    };
+
+    let mut best_axis = Axis::X;
+    let mut best_margin = f64::INFINITY;
+    for axis in [Axis::X, Axis::Y] {
+        sort(entries, axis);
+        let margin: f64 = divisions(entries, &bounds)
+            .map(|(_, first, second)| first.margin() + second.margin())
+            .sum();
+
+        if margin < best_margin {
+            best_axis = axis;
+            best_margin = margin;
+        }
+    }
+
+    sort(entries, best_axis);
+    let position = divisions(entries, &bounds)
+        .min_by(|(_, a_first, a_second), (_, b_first, b_second)| {
+            let a = (a_first.overlap(a_second), a_first.area() + a_second.area());
+            let b = (b_first.overlap(b_second), b_first.area() + b_second.area());
+            a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1))
+        })
+        .map_or(entries.len() / 2, |(position, _, _)| position);
+
+    entries.split_off(position)
+}
+
+/// Returns every way of dividing the entries in two groups of at least [`MIN_ENTRIES`] in their current order,
+/// with the position of the division and the rectangles covering both groups.
+fn divisions<'a, E>(
+    entries: &'a [E],
+    bounds: &'a impl Fn(&E) -> Rect,
+) -> impl Iterator<Item = (usize, Rect, Rect)> + 'a {
+    (MIN_ENTRIES..=entries.len().saturating_sub(MIN_ENTRIES)).map(move |position| {
+        let (first, second) = entries.split_at(position);
+        (position, cover(first, bounds), cover(second, bounds))
+    })
+}
+
+/// Removes a value equal to `target` from below `node`.
+///
+/// Children left with too few entries are dissolved, and their values are moved into `orphans`.
+fn remove<T: Bounded + PartialEq>(
+    node: &mut Node<T>,
+    target: &T,
+    bounds: &Rect,
+    orphans: &mut Vec<T>,
+) -> Option<T> {
+    match node {
+        Node::Leaf(values) => {
+            let position = values.iter().position(|value| value == target)?;
+            Some(values.swap_remove(position))
+        }
+        Node::Branch(children) => {
+            let (position, removed) = children
+                .iter_mut()
+                .enumerate()
+                .filter(|(_, child)| child.bounds.contains(bounds))
+                .find_map(|(position, child)| {
+                    remove(&mut child.node, target, bounds, orphans)
+                        .map(|removed| (position, removed))
+                })?;
+
+            if let Some(child) = children.get_mut(position) {
+                if child.node.len() < MIN_ENTRIES {
+                    children.swap_remove(position).node.drain_into(orphans);
+                } else {
+                    child.bounds = child.node.bounds();
+                }
+            }
+
+            Some(removed)
+        }
+    }
+}
+
+/// Divides the entries into nodes by sort-tile-recursive packing.
+///
+/// The entries are sorted into vertical slices by their centers along the x-axis, and every slice
+/// is sorted along the y-axis and cut into groups of at most [`MAX_ENTRIES`]. The groups are spread
+/// over the slices and sized evenly, so every group holds at least [`MIN_ENTRIES`] unless there are
+/// fewer entries than that.
+fn tile<E>(mut entries: Vec<E>, bounds: impl Fn(&E) -> Rect) -> Vec<Vec<E>> {
+    let mut remaining_groups = entries.len().div_ceil(MAX_ENTRIES);
+    let mut slices = 1;
+    while slices * slices < remaining_groups {
+        slices += 1;
+    }
+
+    let bounds = &bounds;
+    let by_center =
+        |axis| move |a: &E, b: &E| bounds(a).center(axis).total_cmp(&bounds(b).center(axis));
+    entries.sort_by(by_center(Axis::X));
+
+    let mut tiles = Vec::with_capacity(remaining_groups);
+    for remaining_slices in (1..=slices).rev() {
+        if remaining_groups == 0 {
+            break;
+        }
+
+        let groups = remaining_groups / remaining_slices;
+        let rest = entries.split_off(entries.len() * groups / remaining_groups);
+        let mut slice = mem::replace(&mut entries, rest);
+        slice.sort_by(by_center(Axis::Y));
+
+        tiles.extend(split_evenly(slice, groups));
+        remaining_groups -= groups;
+    }
+
+    tiles
+}
+
+/// Splits the entries into the given number of groups, whose sizes differ by at most one.
+fn split_evenly<E>(mut entries: Vec<E>, groups: usize) -> Vec<Vec<E>> {
+    let mut split: Vec<_> = (1..=groups)
+        .rev()
+        .map(|remaining| {
+            let size = entries.len() / remaining;
+            entries.split_off(entries.len() - size)
+        })
+        .collect();
+    split.reverse();
+
+    split
+}
+
+impl<T: Bounded> From<Vec<T>> for RTree<T> {
+    /// Creates an `RTree<T>` from `Vec<T>` by sort-tile-recursive bulk loading.
+    ///
+    /// Every level is packed from the nodes of the level below, which produces fuller nodes that overlap
+    /// less than inserting the values one at a time, in `n log(n)` time.
+    fn from(vec: Vec<T>) -> Self {
+        let count = vec.len();
+        let mut level: Vec<_> = tile(vec, T::bounding_box)
+            .into_iter()
+            .map(Node::Leaf)
+            .collect();
+
+        while level.len() > 1 {
+            let children = level.into_iter().map(Child::new).collect();
+            level = tile(children, |child| child.bounds)
+                .into_iter()
+                .map(Node::Branch)
+                .collect();
+        }
+
+        Self {
+            root: level.pop().unwrap_or(Node::Leaf(Vec::new())),
+            count,
+        }
+    }
+}
+
+impl<T: Bounded> FromIterator<T> for RTree<T> {
+    /// Constructs an `RTree<T>` from an iterator by bulk loading.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+impl<T: Bounded> Extend<T> for RTree<T> {
+    /// Extends the `RTree` with the contents of the provided iterator, inserting one value at a time.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.insert(value);
+        }
+    }
+}
+
+impl<T> IntoIterator for RTree<T> {
+    type Item = T;
+
+    type IntoIter = IntoIter<T>;
+
+    /// Returns a consuming iterator over the `RTree`.
+    ///
+    /// The values are yielded in no particular order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::r_tree::RTree;
+    /// let tree = RTree::from(vec![[0.0, 0.0], [1.0, 1.0]]);
+    /// let mut values: Vec<_> = tree.into_iter().collect();
+    /// values.sort_by(|[a, _], [b, _]| a.total_cmp(b));
+    ///
+    /// assert_eq!(values, vec![[0.0, 0.0], [1.0, 1.0]]);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            stack: vec![self.root],
+            values: Vec::new().into_iter(),
+            remaining: self.count,
+        }
+    }
+}
+
+/// An iterator that moves out of the `RTree`.
+///
+/// This `struct` is created by the `into_iter` method on [`RTree`] (provided by the [`IntoIterator`] trait).
+pub struct IntoIter<T> {
+    stack: Vec<Node<T>>,
+    values: vec::IntoIter<T>,
+    remaining: usize,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(value) = self.values.next() {
+                self.remaining -= 1;
+                return Some(value);
+            }
+
+            match self.stack.pop()? {
+                Node::Leaf(values) => self.values = values.into_iter(),
+                Node::Branch(children) => self
+                    .stack
+                    .extend(children.into_iter().map(|child| child.node)),
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<'a, T> IntoIterator for &'a RTree<T> {
+    type Item = &'a T;
+
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Iter {
+            stack: vec![&self.root],
+            values: [].iter(),
+            remaining: self.count,
+        }
+    }
+}
+
+/// An iterator that borrows from the `RTree`.
+///
+/// This `struct` is created by the `iter` method on [`RTree`].
+pub struct Iter<'a, T> {
+    stack: Vec<&'a Node<T>>,
+    values: slice::Iter<'a, T>,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(value) = self.values.next() {
+                self.remaining -= 1;
+                return Some(value);
+            }
+
+            match self.stack.pop()? {
+                Node::Leaf(values) => self.values = values.iter(),
+                Node::Branch(children) => {
+                    self.stack.extend(children.iter().map(|child| &child.node))
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+/// An iterator over the values whose rectangles intersect an area.
+///
+/// This `struct` is created by the `intersecting` method on [`RTree`].
+pub struct Intersecting<'a, T> {
+    stack: Vec<&'a Node<T>>,
+    values: slice::Iter<'a, T>,
+    area: Rect,
+}
+
+impl<'a, T: Bounded> Iterator for Intersecting<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let area = &self.area;
+            if let Some(value) = self
+                .values
+                .find(|value| value.bounding_box().intersects(area))
+            {
+                return Some(value);
+            }
+
+            match self.stack.pop()? {
+                Node::Leaf(values) => self.values = values.iter(),
+                Node::Branch(children) => self.stack.extend(
+                    children
+                        .iter()
+                        .filter(|child| child.bounds.intersects(area))
+                        .map(|child| &child.node),
+                ),
+            }
+        }
+    }
+}
+
+/// An iterator over the values of an `RTree`, from the closest to a point to the furthest.
+///
+/// This `struct` is created by the `nearest_iter` method on [`RTree`].
+pub struct NearestIter<'a, T> {
+    queue: BinaryHeap<Queued<'a, T>>,
+    point: [f64; 2],
+}
+
+impl<'a, T: Bounded> Iterator for NearestIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // nodes are never closer than their entries, so the closest value leaves the queue before any further one
+        loop {
+            match self.queue.pop()?.item {
+                Item::Value(value) => return Some(value),
+                Item::Node(Node::Leaf(values)) => {
+                    self.queue.extend(values.iter().map(|value| Queued {
+                        distance: value.bounding_box().distance_squared(self.point),
+                        item: Item::Value(value),
+                    }));
+                }
+                Item::Node(Node::Branch(children)) => {
+                    self.queue.extend(children.iter().map(|child| Queued {
+                        distance: child.bounds.distance_squared(self.point),
+                        item: Item::Node(&child.node),
+                    }));
+                }
+            }
+        }
+    }
+}
+
+/// A node or value waiting in the queue of a [`NearestIter`], ordered so the closest is popped first.
+struct Queued<'a, T> {
+    distance: f64,
+    item: Item<'a, T>,
+}
+
+enum Item<'a, T> {
+    Node(&'a Node<T>),
+    Value(&'a T),
+}
+
+impl<'a, T> PartialEq for Queued<'a, T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<'a, T> Eq for Queued<'a, T> {}
+
+impl<'a, T> PartialOrd for Queued<'a, T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a, T> Ord for Queued<'a, T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.distance.total_cmp(&self.distance)
+    }
+}
+
+#[cfg(test)]
+impl<T: Bounded> RTree<T> {
+    /// Checks that all leaves share a depth, that every rectangle is the tightest around its node,
+    /// and that every node but the root holds between [`MIN_ENTRIES`] and [`MAX_ENTRIES`] entries.
+    fn assert_invariants(&self) {
+        fn walk<T: Bounded>(node: &Node<T>, depth: usize, leaf_depths: &mut Vec<usize>) -> usize {
+            match node {
+                Node::Leaf(values) => {
+                    leaf_depths.push(depth);
+                    values.len()
+                }
+                Node::Branch(children) => children
+                    .iter()
+                    .map(|child| {
+                        assert!((MIN_ENTRIES..=MAX_ENTRIES).contains(&child.node.len()));
+                        assert_eq!(child.bounds, child.node.bounds());
+                        walk(&child.node, depth + 1, leaf_depths)
+                    })
+                    .sum(),
+            }
+        }
+
+        let mut leaf_depths = Vec::new();
+        assert!(self.root.len() <= MAX_ENTRIES);
+        assert_eq!(walk(&self.root, 1, &mut leaf_depths), self.count);
+        assert!(leaf_depths
+            .windows(2)
+            .all(|depths| depths.first() == depths.last()));
+        assert_eq!(
+            leaf_depths
+                .first()
+                .copied()
+                .filter(|_| self.count > 0)
+                .unwrap_or(0),
+            self.height()
+        );
+    }
+}
+
+#[cfg(test)]
+mod insert_remove {
+    use super::rect::Rect;
+    use super::RTree;
+    use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+    #[test]
+    fn splits_leaf_when_full() {
+        let mut tree = RTree::new();
+        tree.extend((0..8).map(|i| [f64::from(i), 0.0]));
+        assert_eq!(tree.height(), 1);
+
+        tree.insert([8.0, 0.0]);
+        tree.assert_invariants();
+        assert_eq!(tree.height(), 2);
+        assert_eq!(tree.count(), 9);
+    }
+
+    #[test]
+    fn keeps_equal_values() {
+        let mut tree = RTree::new();
+        tree.insert([1.0, 1.0]);
+        tree.insert([1.0, 1.0]);
+
+        assert_eq!(tree.count(), 2);
+        assert_eq!(tree.remove(&[1.0, 1.0]), Some([1.0, 1.0]));
+        assert_eq!(tree.remove(&[1.0, 1.0]), Some([1.0, 1.0]));
+        assert!(tree.is_empty());
+    }
+
+    #[test]
+    fn missing_value_is_not_removed() {
+        let mut tree = RTree::from(vec![[0.0, 0.0], [1.0, 1.0]]);
+
+        assert_eq!(tree.remove(&[0.5, 0.5]), None);
+        assert_eq!(tree.count(), 2);
+    }
+
+    #[test]
+    fn random_operations_keep_invariants() {
+        let mut rng = StdRng::seed_from_u64(36);
+        let mut tree = RTree::new();
+        let mut expected = Vec::new();
+
+        for _ in 0..2000 {
+            if rng.gen_bool(0.6) || expected.is_empty() {
+                let [x, y]: [f64; 2] = [rng.gen_range(0.0..100.0), rng.gen_range(0.0..100.0)];
+                let rect = Rect::new(
+                    [x, y],
+                    [x + rng.gen_range(0.0..5.0), y + rng.gen_range(0.0..5.0)],
+                );
+                tree.insert(rect);
+                expected.push(rect);
+            } else {
+                let position = rng.gen_range(0..expected.len());
+                let rect = expected.swap_remove(position);
+                assert_eq!(tree.remove(&rect), Some(rect));
+            }
+            assert_eq!(tree.count(), expected.len());
+        }
+        tree.assert_invariants();
+
+        expected.shuffle(&mut rng);
+        for rect in expected {
+            assert_eq!(tree.remove(&rect), Some(rect));
+            tree.assert_invariants();
+        }
+        assert_eq!(tree.height(), 0);
+    }
+}
+
+#[cfg(test)]
+mod bulk_load {
+    use super::RTree;
+
+    #[test]
+    fn packs_full_levels() {
+        for count in [0, 1, 3, 8, 9, 17, 64, 65, 500, 4096] {
+            let tree: RTree<[f64; 2]> = (0..count)
+                .map(|i| [f64::from(i % 97), f64::from(i / 97)])
+                .collect();
+
+            tree.assert_invariants();
+            assert_eq!(tree.count(), count as usize);
+        }
+    }
+
+    #[test]
+    fn height_grows_logarithmically() {
+        let tree: RTree<[f64; 2]> = (0..4096)
+            .map(|i| [f64::from(i % 64), f64::from(i / 64)])
+            .collect();
+        assert_eq!(tree.height(), 4);
+    }
+}
+
+#[cfg(test)]
+mod queries {
+    use super::rect::{Bounded, Rect};
+    use super::RTree;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    fn random_rect(rng: &mut StdRng) -> Rect {
+        let [x, y]: [f64; 2] = [rng.gen_range(-100.0..100.0), rng.gen_range(-100.0..100.0)];
+        Rect::new(
+            [x, y],
+            [x + rng.gen_range(0.0..10.0), y + rng.gen_range(0.0..10.0)],
+        )
+    }
+
+    fn sorted(mut rects: Vec<&Rect>) -> Vec<&Rect> {
+        rects.sort_by(|a, b| {
+            let ([a_x, a_y], [b_x, b_y]) = (a.min(), b.min());
+            a_x.total_cmp(&b_x).then(a_y.total_cmp(&b_y))
+        });
+        rects
+    }
+
+    /// Builds one tree by bulk loading and one by inserting, from the same rectangles.
+    fn trees(seed: u64) -> (StdRng, Vec<Rect>, [RTree<Rect>; 2]) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let rects: Vec<_> = (0..600).map(|_| random_rect(&mut rng)).collect();
+        let mut inserted = RTree::new();
+        inserted.extend(rects.iter().copied());
+
+        (rng, rects.clone(), [RTree::from(rects), inserted])
+    }
+
+    #[test]
+    fn intersecting_matches_brute_force() {
+        let (mut rng, rects, trees) = trees(136);
+
+        for _ in 0..100 {
+            let area = random_rect(&mut rng);
+            let expected = sorted(rects.iter().filter(|rect| rect.intersects(&area)).collect());
+
+            for tree in &trees {
+                assert_eq!(sorted(tree.intersecting(area).collect()), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn nearest_matches_brute_force() {
+        let (mut rng, rects, trees) = trees(236);
+
+        for _ in 0..100 {
+            let point = [rng.gen_range(-120.0..120.0), rng.gen_range(-120.0..120.0)];
+            let mut expected: Vec<_> = rects
+                .iter()
+                .map(|rect| rect.distance_squared(point))
+                .collect();
+            expected.sort_by(f64::total_cmp);
+
+            for tree in &trees {
+                let nearest = tree.nearest(point).map(|rect| rect.distance_squared(point));
+                assert_eq!(nearest, expected.first().copied());
+
+                // ties may be broken either way, so distances are compared instead of rectangles
+                let distances: Vec<_> = tree
+                    .nearest_iter(point)
+                    .map(|rect| rect.bounding_box().distance_squared(point))
+                    .collect();
+                assert_eq!(distances, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn empty_tree_finds_nothing() {
+        let tree: RTree<Rect> = RTree::new();
+
+        assert_eq!(tree.nearest([0.0, 0.0]), None);
+        assert_eq!(
+            tree.intersecting(Rect::new([0.0, 0.0], [1.0, 1.0])).count(),
+            0
+        );
+        assert_eq!(tree.bounds(), None);
+    }
+}
+
+#[cfg(test)]
+mod iterator_trait_impls {
+    use super::RTree;
+
+    #[test]
+    fn iter_and_into_iter_yield_every_value() {
+        let points: Vec<[f64; 2]> = (0..100).map(|i| [f64::from(i), f64::from(i % 7)]).collect();
+        let tree: RTree<[f64; 2]> = points.iter().copied().collect();
+        let by_x = |[a, _]: &[f64; 2], [b, _]: &[f64; 2]| a.total_cmp(b);
+
+        assert_eq!(tree.iter().len(), 100);
+        let mut borrowed: Vec<_> = tree.iter().copied().collect();
+        let mut owned: Vec<_> = tree.into_iter().collect();
+        borrowed.sort_by(by_x);
+        owned.sort_by(by_x);
+
+        assert_eq!(borrowed, points);
+        assert_eq!(owned, points);
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod json {
+    use super::rect::Rect;
+    use super::RTree;
+
+    #[test]
+    fn round_trips_through_json() {
+        let tree: RTree<Rect> = (0..50)
+            .map(|i| Rect::new([f64::from(i), 0.0], [f64::from(i) + 1.5, 2.0]))
+            .collect();
+
+        let json = serde_json::to_string(&tree).expect("should parse tree into json");
+        let actual: RTree<Rect> = serde_json::from_str(&json).expect("should parse json into tree");
+
+        assert_eq!(actual, tree);
+    }
+}
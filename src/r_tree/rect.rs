@@ -0,0 +1,262 @@
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
+
+/// An axis-aligned rectangle in two dimensions, including its boundary.
+///
+/// A rectangle may have no width or height, which makes it a line or a point.
+///
+/// # Examples
+/// ```
+/// # use ds_rs::r_tree::rect::Rect;
+/// let a = Rect::new([0.0, 0.0], [2.0, 2.0]);
+/// let b = Rect::new([1.0, 1.0], [3.0, 4.0]);
+///
+/// assert!(a.intersects(&b));
+/// assert_eq!(a.union(&b), Rect::new([0.0, 0.0], [3.0, 4.0]));
+/// ```
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Rect {
+    min: [f64; 2],
+    max: [f64; 2],
+}
+
+impl Rect {
+    /// The rectangle containing nothing, which leaves any rectangle unchanged in a union.
+    pub(super) const EMPTY: Self = Self {
+        min: [f64::INFINITY; 2],
+        max: [f64::NEG_INFINITY; 2],
+    };
+
+    /// Constructs the rectangle spanning from the `min` corner to the `max` corner.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a coordinate of `min` is greater than that of `max`, or if any coordinate is `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::r_tree::rect::Rect;
+    /// let rect = Rect::new([1.0, 2.0], [4.0, 6.0]);
+    /// assert_eq!(rect.area(), 12.0);
+    /// ```
+    #[must_use]
+    pub fn new(min: [f64; 2], max: [f64; 2]) -> Self {
+        let [min_x, min_y] = min;
+        let [max_x, max_y] = max;
+        assert!(
+            min_x <= max_x && min_y <= max_y,
+            "min corner must not exceed max corner"
+        );
+
+        Self { min, max }
+    }
+
+    /// Constructs the rectangle covering nothing but the given point.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any coordinate is `NaN`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::r_tree::rect::Rect;
+    /// let rect = Rect::point([1.0, 2.0]);
+    /// assert_eq!(rect.min(), rect.max());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn point(point: [f64; 2]) -> Self {
+        Self::new(point, point)
+    }
+
+    /// Returns the corner with the smallest coordinates.
+    #[inline]
+    pub fn min(&self) -> [f64; 2] {
+        self.min
+    }
+
+    /// Returns the corner with the largest coordinates.
+    #[inline]
+    pub fn max(&self) -> [f64; 2] {
+        self.max
+    }
+
+    /// Returns the area of the rectangle.
+    pub fn area(&self) -> f64 {
+        let [min_x, min_y] = self.min;
+        let [max_x, max_y] = self.max;
+
+        (max_x - min_x) * (max_y - min_y)
+    }
+
+    /// Returns the sum of the width and height of the rectangle.
+    pub(super) fn margin(&self) -> f64 {
+        let [min_x, min_y] = self.min;
+        let [max_x, max_y] = self.max;
+
+        (max_x - min_x) + (max_y - min_y)
+    }
+
+    /// Returns `true` if the rectangles share at least one point, including points on their boundaries.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::r_tree::rect::Rect;
+    /// let a = Rect::new([0.0, 0.0], [1.0, 1.0]);
+    ///
+    /// // touching corners count as intersecting
+    /// assert!(a.intersects(&Rect::new([1.0, 1.0], [2.0, 2.0])));
+    /// assert!(!a.intersects(&Rect::new([1.5, 0.0], [2.0, 1.0])));
+    /// ```
+    pub fn intersects(&self, other: &Self) -> bool {
+        let [min_x, min_y] = self.min;
+        let [max_x, max_y] = self.max;
+        let [other_min_x, other_min_y] = other.min;
+        let [other_max_x, other_max_y] = other.max;
+
+        min_x <= other_max_x && other_min_x <= max_x && min_y <= other_max_y && other_min_y <= max_y
+    }
+
+    /// Returns `true` if every point of `other` lies within the rectangle.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::r_tree::rect::Rect;
+    /// let a = Rect::new([0.0, 0.0], [4.0, 4.0]);
+    ///
+    /// assert!(a.contains(&Rect::new([1.0, 1.0], [4.0, 2.0])));
+    /// assert!(!a.contains(&Rect::new([1.0, 1.0], [5.0, 2.0])));
+    /// ```
+    pub fn contains(&self, other: &Self) -> bool {
+        let [min_x, min_y] = self.min;
+        let [max_x, max_y] = self.max;
+        let [other_min_x, other_min_y] = other.min;
+        let [other_max_x, other_max_y] = other.max;
+
+        min_x <= other_min_x && min_y <= other_min_y && other_max_x <= max_x && other_max_y <= max_y
+    }
+
+    /// Returns the smallest rectangle containing both rectangles.
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Self {
+        let [min_x, min_y] = self.min;
+        let [max_x, max_y] = self.max;
+        let [other_min_x, other_min_y] = other.min;
+        let [other_max_x, other_max_y] = other.max;
+
+        Self {
+            min: [min_x.min(other_min_x), min_y.min(other_min_y)],
+            max: [max_x.max(other_max_x), max_y.max(other_max_y)],
+        }
+    }
+
+    /// Returns the area shared by both rectangles.
+    pub(super) fn overlap(&self, other: &Self) -> f64 {
+        let [min_x, min_y] = self.min;
+        let [max_x, max_y] = self.max;
+        let [other_min_x, other_min_y] = other.min;
+        let [other_max_x, other_max_y] = other.max;
+
+        let width = max_x.min(other_max_x) - min_x.max(other_min_x);
+        let height = max_y.min(other_max_y) - min_y.max(other_min_y);
+
+        width.max(0.0) * height.max(0.0)
+    }
+
+    /// Returns the squared distance from the point to the closest point of the rectangle.
+    ///
+    /// Points within the rectangle have a distance of `0.0`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::r_tree::rect::Rect;
+    /// let rect = Rect::new([0.0, 0.0], [2.0, 2.0]);
+    ///
+    /// assert_eq!(rect.distance_squared([1.0, 1.0]), 0.0);
+    /// assert_eq!(rect.distance_squared([5.0, 6.0]), 25.0);
+    /// ```
+    pub fn distance_squared(&self, point: [f64; 2]) -> f64 {
+        let [x, y] = point;
+        let [min_x, min_y] = self.min;
+        let [max_x, max_y] = self.max;
+
+        let dx = (min_x - x).max(x - max_x).max(0.0);
+        let dy = (min_y - y).max(y - max_y).max(0.0);
+
+        dx * dx + dy * dy
+    }
+
+    /// Returns the position of the center of the rectangle along the given axis, doubled.
+    pub(super) fn center(&self, axis: Axis) -> f64 {
+        let ([min_x, min_y], [max_x, max_y]) = (self.min, self.max);
+
+        match axis {
+            Axis::X => min_x + max_x,
+            Axis::Y => min_y + max_y,
+        }
+    }
+}
+
+/// One of the two axes that rectangles are sorted along.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum Axis {
+    X,
+    Y,
+}
+
+/// A value with a position, described by the smallest rectangle around it.
+///
+/// The rectangle of a value must not change while the value is stored in an [`RTree`](super::RTree).
+pub trait Bounded {
+    /// Returns the smallest rectangle containing the value.
+    fn bounding_box(&self) -> Rect;
+}
+
+impl Bounded for Rect {
+    #[inline]
+    fn bounding_box(&self) -> Rect {
+        *self
+    }
+}
+
+impl Bounded for [f64; 2] {
+    #[inline]
+    fn bounding_box(&self) -> Rect {
+        Rect::point(*self)
+    }
+}
+
+#[cfg(test)]
+mod geometry {
+    use super::Rect;
+
+    #[test]
+    #[should_panic(expected = "min corner must not exceed max corner")]
+    fn rejects_inverted_corners() {
+        let _ = Rect::new([1.0, 0.0], [0.0, 1.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "min corner must not exceed max corner")]
+    fn rejects_nan() {
+        let _ = Rect::point([f64::NAN, 0.0]);
+    }
+
+    #[test]
+    fn empty_rect_is_identity_of_union() {
+        let rect = Rect::new([-1.0, 2.0], [3.0, 5.0]);
+
+        assert_eq!(Rect::EMPTY.union(&rect), rect);
+        assert!(!Rect::EMPTY.intersects(&rect));
+    }
+
+    #[test]
+    fn overlap_of_disjoint_rects_is_zero() {
+        let a = Rect::new([0.0, 0.0], [2.0, 2.0]);
+
+        assert_eq!(a.overlap(&Rect::new([1.0, 1.0], [4.0, 3.0])), 1.0);
+        assert_eq!(a.overlap(&Rect::new([3.0, 0.0], [4.0, 1.0])), 0.0);
+        assert_eq!(a.overlap(&Rect::new([0.0, 3.0], [1.0, 4.0])), 0.0);
+    }
+}
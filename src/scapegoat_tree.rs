@@ -0,0 +1,809 @@
+use std::cmp::Ordering;
+
+#[cfg(feature = "json")]
+use serde::{de, Deserialize, Deserializer, Serialize};
+
+use crate::binary_tree::{InOrder, IntoIter, Iter, Node, NodeBox};
+use crate::sorted_set::SortedSet;
+
+/// The balance factor used by [`ScapegoatTree::new`].
+pub const DEFAULT_ALPHA: f64 = 2.0 / 3.0;
+
+/// A self-balancing binary search tree that stores nothing but the values in its nodes.
+///
+/// Instead of keeping balance information per node, the tree rebuilds a subtree once an insert
+/// lands too deep, keeping the height at `O(log(n))`.
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScapegoatTree<T> {
+    root: Option<NodeBox<T>>,
+    count: usize,
+    max_count: usize,
+    #[cfg_attr(feature = "json", serde(deserialize_with = "deserialize_alpha"))]
+    alpha: f64,
+}
+
+/// Deserializes the balance factor, rejecting the values [`ScapegoatTree::with_alpha`] panics on.
+#[cfg(feature = "json")]
+fn deserialize_alpha<'de, D: Deserializer<'de>>(deserializer: D) -> Result<f64, D::Error> {
+    let alpha = f64::deserialize(deserializer)?;
+    if !(0.5..1.0).contains(&alpha) {
+        return Err(de::Error::custom(format_args!(
+            "alpha must be within 0.5..1.0, but was {alpha}"
+        )));
+    }
+
+    Ok(alpha)
+}
+
+impl<T> ScapegoatTree<T> {
+    /// Constructs a new empty `ScapegoatTree<T>` using the [`DEFAULT_ALPHA`] balance factor.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ds_rs::scapegoat_tree::ScapegoatTree;
+    /// # #[allow(unused_mut)]
+    /// let mut tree: ScapegoatTree<i32> = ScapegoatTree::new();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::with_alpha(DEFAULT_ALPHA)
+    }
+
+    /// Constructs a new empty `ScapegoatTree<T>` with the given balance factor.
+    ///
+    /// No child of a rebuilt subtree holds more than `alpha` of its elements, and the height of
+    /// the tree stays below `log(n)` in base `1 / alpha`. A low `alpha` keeps the tree close to
+    /// perfectly balanced, at the cost of rebuilding more often.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alpha` is not within `0.5..1.0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ds_rs::scapegoat_tree::ScapegoatTree;
+    /// let mut tree = ScapegoatTree::with_alpha(0.5);
+    /// tree.extend(0..7);
+    ///
+    /// // an alpha of 0.5 keeps the tree perfectly balanced
+    /// assert_eq!(tree.height(), 3);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_alpha(alpha: f64) -> Self {
+        assert!(
+            (0.5..1.0).contains(&alpha),
+            "alpha must be within 0.5..1.0, but was {alpha}"
+        );
+
+        Self {
+            root: None,
+            count: 0,
+            max_count: 0,
+            alpha,
+        }
+    }
+
+    /// Returns the balance factor of the scapegoat tree.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::scapegoat_tree::ScapegoatTree;
+    /// let tree: ScapegoatTree<i32> = ScapegoatTree::with_alpha(0.75);
+    /// assert_eq!(tree.alpha(), 0.75);
+    /// ```
+    #[inline]
+    pub fn alpha(&self) -> f64 {
+        self.alpha
+    }
+
+    /// Returns `true` if the scapegoat tree contains no elements.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::scapegoat_tree::ScapegoatTree;
+    /// let mut tree = ScapegoatTree::new();
+    /// assert!(tree.is_empty());
+    ///
+    /// tree.insert(0);
+    /// assert!(!tree.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Clears the scapegoat tree of all elements.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::scapegoat_tree::ScapegoatTree;
+    /// let mut tree = ScapegoatTree::new();
+    /// tree.insert(0);
+    /// assert!(!tree.is_empty());
+    ///
+    /// tree.clear();
+    /// assert!(tree.is_empty());
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.root = None;
+        self.count = 0;
+        self.max_count = 0;
+    }
+
+    /// Traverses and returns the height of the scapegoat tree.
+    ///
+    /// An empty tree has a height of `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::scapegoat_tree::ScapegoatTree;
+    /// let mut tree = ScapegoatTree::new();
+    /// assert_eq!(tree.height(), 0);
+    ///
+    /// // ascending inserts would form a chain in an unbalanced tree
+    /// tree.extend(0..1000);
+    /// assert!(tree.height() <= 18);
+    /// ```
+    #[inline]
+    pub fn height(&self) -> usize {
+        let mut height = 0;
+        let mut queue = Vec::new();
+
+        if let Some(root) = self.root.as_deref() {
+            queue.push((1, root));
+        }
+
+        while let Some((node_height, node)) = queue.pop() {
+            height = height.max(node_height);
+
+            if let Some(left) = node.left() {
+                queue.push((node_height + 1, left));
+            }
+
+            if let Some(right) = node.right() {
+                queue.push((node_height + 1, right));
+            }
+        }
+
+        height
+    }
+
+    /// Returns the value contained within the root element.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::scapegoat_tree::ScapegoatTree;
+    /// let mut tree = ScapegoatTree::new();
+    /// assert_eq!(tree.root(), None);
+    ///
+    /// tree.insert(5);
+    /// assert_eq!(tree.root(), Some(&5));
+    /// ```
+    pub fn root(&self) -> Option<&T> {
+        self.root.as_deref().map(Node::value)
+    }
+
+    /// Returns the number of elements in the scapegoat tree.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::scapegoat_tree::ScapegoatTree;
+    /// let mut tree = ScapegoatTree::new();
+    /// assert_eq!(tree.count(), 0);
+    /// tree.insert(5);
+    /// assert_eq!(tree.count(), 1);
+    ///
+    /// // duplicates do not enter the tree
+    /// // and therefore do not affect the count
+    /// tree.insert(5);
+    /// assert_eq!(tree.count(), 1);
+    /// ```
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns a non-consuming iterator over the `ScapegoatTree`.
+    ///
+    /// The iterator yields all items in the tree using the **preorder tree traversal technique**.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::scapegoat_tree::ScapegoatTree;
+    /// let tree = ScapegoatTree::from(vec![5, 4, 6]);
+    /// let mut tree_iter = tree.iter();
+    ///
+    /// assert_eq!(tree_iter.next(), Some(&5));
+    /// assert_eq!(tree_iter.next(), Some(&4));
+    /// assert_eq!(tree_iter.next(), Some(&6));
+    ///
+    /// // the iterator is now empty
+    /// assert_eq!(tree_iter.next(), None);
+    /// ```
+    #[inline]
+    #[must_use = "iterators are evaluated lazily"]
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.into_iter()
+    }
+
+    /// Returns the smallest element in the `ScapegoatTree`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::scapegoat_tree::ScapegoatTree;
+    /// let tree = ScapegoatTree::from(vec![8, 4, 6, 16, -5, 25]);
+    /// assert_eq!(tree.min(), Some(&-5));
+    /// ```
+    pub fn min(&self) -> Option<&T> {
+        let mut node = self.root.as_deref()?;
+        while let Some(left) = node.left() {
+            node = left;
+        }
+
+        Some(node.value())
+    }
+
+    /// Returns the largest element in the `ScapegoatTree`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::scapegoat_tree::ScapegoatTree;
+    /// let tree = ScapegoatTree::from(vec![8, 4, 6, 16, -5, 25]);
+    /// assert_eq!(tree.max(), Some(&25));
+    /// ```
+    pub fn max(&self) -> Option<&T> {
+        let mut node = self.root.as_deref()?;
+        while let Some(right) = node.right() {
+            node = right;
+        }
+
+        Some(node.value())
+    }
+
+    /// Returns the deepest an element may be inserted, when the tree holds `count` elements,
+    /// before a scapegoat has to be rebuilt.
+    ///
+    /// This is `log(count)` in base `1 / alpha`, rounded down, and the root has a depth of `0`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `alpha` is not within `0.5..1.0`, as the weight would never exceed `count`.
+    fn depth_limit(&self, count: usize) -> usize {
+        assert!(
+            (0.5..1.0).contains(&self.alpha),
+            "alpha must be within 0.5..1.0, but was {}",
+            self.alpha
+        );
+
+        let mut limit = 0;
+        let mut weight = 1.0 / self.alpha;
+
+        while weight <= count as f64 {
+            weight /= self.alpha;
+            limit += 1;
+        }
+
+        limit
+    }
+}
+
+impl<T> Default for ScapegoatTree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The methods in this implementation block require trait bounds to correctly apply the logic of the methods.
+///
+/// The trait bound in question is [`Ord`], which is used to compare elements in the tree.
+impl<T> ScapegoatTree<T>
+where
+    T: Ord,
+{
+    /// Inserts the provided value into the `ScapegoatTree`,
+    /// and preserves the properties of the scapegoat tree.
+    ///
+    /// # Time Complexity
+    ///
+    /// Finding the position of the value takes `log(n)` time, as the tree is kept balanced.
+    ///
+    /// An insert that lands too deep rebuilds a subtree, which takes time linear in its size,
+    /// but this happens rarely enough that inserts take `log(n)` amortized time.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::scapegoat_tree::ScapegoatTree;
+    /// let mut tree = ScapegoatTree::new();
+    /// tree.insert(5);
+    /// assert_eq!(tree.root(), Some(&5));
+    /// assert_eq!(tree.height(), 1);
+    /// assert_eq!(tree.count(), 1);
+    /// ```
+    pub fn insert(&mut self, value: T) {
+        let limit = self.depth_limit(self.count + 1);
+
+        match insert_at(&mut self.root, value, 0, limit, self.alpha) {
+            Insertion::Duplicate => return,
+            Insertion::Balanced | Insertion::Unbalanced(_) => (),
+        }
+
+        self.count += 1;
+        self.max_count = self.max_count.max(self.count);
+    }
+
+    /// Returns `true` if the `ScapegoatTree` contains an element with the given value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::scapegoat_tree::ScapegoatTree;
+    /// let tree = ScapegoatTree::from(vec![8, 4, 6, 16, -5, 25]);
+    /// assert!(tree.contains(&8));
+    /// assert!(!tree.contains(&5));
+    /// ```
+    pub fn contains(&self, target: &T) -> bool {
+        let mut node = self.root.as_deref();
+
+        while let Some(current) = node {
+            node = match target.cmp(current.value()) {
+                Ordering::Equal => return true,
+                Ordering::Less => current.left(),
+                Ordering::Greater => current.right(),
+            };
+        }
+
+        false
+    }
+
+    /// Removes the element equal to the given value from the `ScapegoatTree`, and returns it.
+    ///
+    /// Once enough elements have been removed, the entire tree is rebuilt to restore its balance.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::scapegoat_tree::ScapegoatTree;
+    /// let mut tree = ScapegoatTree::from(vec![8, 4, 6]);
+    /// assert_eq!(tree.remove(&4), Some(4));
+    /// assert_eq!(tree.remove(&4), None);
+    /// assert_eq!(tree.count(), 2);
+    /// ```
+    pub fn remove(&mut self, target: &T) -> Option<T> {
+        let value = Node::remove(&mut self.root, target)?;
+        self.count -= 1;
+
+        if (self.count as f64) < self.alpha * self.max_count as f64 {
+            rebuild(&mut self.root);
+            self.max_count = self.count;
+        }
+
+        Some(value)
+    }
+}
+
+/// The outcome of inserting a value into a subtree.
+enum Insertion {
+    /// The value was already present.
+    Duplicate,
+    /// The value was inserted, and the tree is balanced.
+    Balanced,
+    /// The value was inserted too deep, and the subtree holding it, with the given size,
+    /// is not yet a scapegoat.
+    Unbalanced(usize),
+}
+
+/// Inserts `value` into the subtree in `slot`, whose root is at `depth`, and rebuilds the lowest
+/// subtree on the insertion path that is out of balance if the value lands below `limit`.
+fn insert_at<T: Ord>(
+    slot: &mut Option<NodeBox<T>>,
+    value: T,
+    depth: usize,
+    limit: usize,
+    alpha: f64,
+) -> Insertion {
+    let Some(node) = slot.as_deref_mut() else {
+        *slot = Some(NodeBox::new(Node::new(value)));
+
+        return match depth > limit {
+            true => Insertion::Unbalanced(1),
+            false => Insertion::Balanced,
+        };
+    };
+
+    let (child, sibling) = match value.cmp(node.value()) {
+        Ordering::Equal => return Insertion::Duplicate,
+        Ordering::Less => (&mut node.left, node.right.as_deref()),
+        Ordering::Greater => (&mut node.right, node.left.as_deref()),
+    };
+
+    match insert_at(child, value, depth + 1, limit, alpha) {
+        Insertion::Unbalanced(child_size) => {
+            let size = child_size + size_of(sibling) + 1;
+
+            if child_size as f64 > alpha * size as f64 {
+                rebuild(slot);
+                Insertion::Balanced
+            } else {
+                Insertion::Unbalanced(size)
+            }
+        }
+        insertion => insertion,
+    }
+}
+
+/// Counts the elements in the subtree rooted at `node`.
+fn size_of<T>(node: Option<&Node<T>>) -> usize {
+    let mut size = 0;
+    let mut stack: Vec<_> = node.into_iter().collect();
+
+    while let Some(node) = stack.pop() {
+        size += 1;
+        stack.extend(node.left());
+        stack.extend(node.right());
+    }
+
+    size
+}
+
+/// Rebuilds the subtree in `slot` into a perfectly balanced one.
+fn rebuild<T>(slot: &mut Option<NodeBox<T>>) {
+    let mut values = Vec::new();
+    Node::drain_sorted(slot.take(), &mut values);
+    *slot = Node::from_sorted(values);
+}
+
+impl<T: Ord> SortedSet<T> for ScapegoatTree<T> {
+    type Iter<'a>
+        = InOrder<'a, T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    fn insert(&mut self, value: T) -> bool {
+        let count = self.count;
+        ScapegoatTree::insert(self, value);
+
+        self.count > count
+    }
+
+    fn remove(&mut self, target: &T) -> Option<T> {
+        ScapegoatTree::remove(self, target)
+    }
+
+    fn contains(&self, target: &T) -> bool {
+        ScapegoatTree::contains(self, target)
+    }
+
+    fn min(&self) -> Option<&T> {
+        ScapegoatTree::min(self)
+    }
+
+    fn max(&self) -> Option<&T> {
+        ScapegoatTree::max(self)
+    }
+
+    fn len(&self) -> usize {
+        self.count
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        InOrder::new(self.root.as_deref(), self.count)
+    }
+}
+
+impl<T: Ord> From<Vec<T>> for ScapegoatTree<T> {
+    /// Creates a `ScapegoatTree<T>` from `Vec<T>`.
+    fn from(vec: Vec<T>) -> Self {
+        let mut tree = ScapegoatTree::new();
+        for v in vec {
+            tree.insert(v);
+        }
+
+        tree
+    }
+}
+
+impl<T: Ord> FromIterator<T> for ScapegoatTree<T> {
+    /// Constructs a `ScapegoatTree<T>` from an iterator for `T`.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut tree = ScapegoatTree::new();
+
+        for v in iter {
+            tree.insert(v);
+        }
+
+        tree
+    }
+}
+
+impl<T: Ord> Extend<T> for ScapegoatTree<T> {
+    /// Extends the `ScapegoatTree` with the contents of the provided iterator.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for v in iter {
+            self.insert(v);
+        }
+    }
+}
+
+impl<T> IntoIterator for ScapegoatTree<T> {
+    type Item = T;
+
+    type IntoIter = IntoIter<T>;
+
+    /// Returns a consuming iterator over the `ScapegoatTree`.
+    ///
+    /// The iterator yields all items in the tree using the **preorder tree traversal technique**.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::scapegoat_tree::ScapegoatTree;
+    /// let tree = ScapegoatTree::from(vec![5, 4, 6]);
+    /// let mut tree_iter = tree.into_iter();
+    ///
+    /// assert_eq!(tree_iter.next(), Some(5));
+    /// assert_eq!(tree_iter.next(), Some(4));
+    /// assert_eq!(tree_iter.next(), Some(6));
+    ///
+    /// // the iterator is now empty
+    /// assert_eq!(tree_iter.next(), None);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter::new(self.root, self.count)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a ScapegoatTree<T> {
+    type Item = &'a T;
+
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Iter::new(self.root.as_deref(), self.count)
+    }
+}
+
+#[cfg(test)]
+mod insert {
+    use super::{Node, NodeBox, ScapegoatTree};
+
+    fn leaf(value: i32) -> Option<NodeBox<i32>> {
+        Some(NodeBox::new(Node::new(value)))
+    }
+
+    #[test]
+    fn insert_one_element_that_becomes_root() {
+        let mut tree = ScapegoatTree::new();
+        tree.insert(5);
+
+        assert_eq!(tree.root, leaf(5));
+        assert_eq!(tree.count(), 1);
+    }
+
+    #[test]
+    fn discards_duplicates() {
+        let mut tree = ScapegoatTree::new();
+        tree.insert(1);
+        tree.insert(2);
+        tree.insert(1);
+        tree.insert(2);
+
+        assert_eq!(tree.count(), 2);
+        assert_eq!(tree.max_count, 2);
+    }
+
+    #[test]
+    fn shallow_inserts_do_not_rebuild() {
+        let mut tree = ScapegoatTree::new();
+        let expected = Some(NodeBox::new(Node {
+            value: 1,
+            left: None,
+            right: Some(NodeBox::new(Node {
+                value: 2,
+                left: None,
+                right: Some(NodeBox::new(Node {
+                    value: 3,
+                    left: None,
+                    right: leaf(4),
+                })),
+            })),
+        }));
+
+        tree.extend([1, 2, 3, 4]);
+        assert_eq!(tree.root, expected);
+    }
+
+    #[test]
+    fn deep_insert_rebuilds_scapegoat_subtree() {
+        let mut tree = ScapegoatTree::new();
+        let expected = Some(NodeBox::new(Node {
+            value: 1,
+            left: None,
+            right: Some(NodeBox::new(Node {
+                value: 4,
+                left: Some(NodeBox::new(Node {
+                    value: 3,
+                    left: leaf(2),
+                    right: None,
+                })),
+                right: leaf(5),
+            })),
+        }));
+
+        tree.extend([1, 2, 3, 4, 5]);
+        assert_eq!(tree.root, expected);
+    }
+
+    #[test]
+    fn ascending_inserts_stay_within_height_bound() {
+        let mut tree = ScapegoatTree::new();
+
+        for v in 0..1000 {
+            tree.insert(v);
+            assert!(tree.height() <= tree.depth_limit(tree.count()) + 1);
+        }
+
+        assert_eq!(tree.count(), 1000);
+    }
+
+    #[test]
+    fn alpha_of_one_half_keeps_tree_perfectly_balanced() {
+        let mut tree = ScapegoatTree::with_alpha(0.5);
+        tree.extend(0..15);
+
+        assert_eq!(tree.height(), 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_alpha_of_one() {
+        let _: ScapegoatTree<i32> = ScapegoatTree::with_alpha(1.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn rejects_alpha_below_one_half() {
+        let _: ScapegoatTree<i32> = ScapegoatTree::with_alpha(0.4);
+    }
+}
+
+#[cfg(test)]
+mod remove {
+    use super::ScapegoatTree;
+
+    #[test]
+    fn empty_tree_returns_none() {
+        let mut tree: ScapegoatTree<i32> = ScapegoatTree::new();
+        assert_eq!(tree.remove(&0), None);
+    }
+
+    #[test]
+    fn missing_element_is_not_removed() {
+        let mut tree = ScapegoatTree::from(vec![1, 3, 5]);
+
+        assert_eq!(tree.remove(&2), None);
+        assert_eq!(tree.count(), 3);
+    }
+
+    #[test]
+    fn removes_root_with_two_children() {
+        let mut tree = ScapegoatTree::from(vec![5, 4, 6]);
+
+        assert_eq!(tree.remove(&5), Some(5));
+        assert_eq!(tree.root(), Some(&6));
+        assert!(tree.contains(&4));
+        assert_eq!(tree.count(), 2);
+    }
+
+    #[test]
+    fn many_removals_rebuild_entire_tree() {
+        let mut tree: ScapegoatTree<i32> = (0..1024).collect();
+
+        for v in 0..1000 {
+            assert_eq!(tree.remove(&v), Some(v));
+        }
+
+        assert_eq!(tree.count(), 24);
+        assert!(tree.max_count < 1024);
+        assert!(tree.height() <= tree.depth_limit(tree.max_count) + 1);
+        assert!((1000..1024).all(|v| tree.contains(&v)));
+    }
+}
+
+#[cfg(test)]
+mod getters {
+    use super::ScapegoatTree;
+
+    #[test]
+    fn empty_tree() {
+        let tree: ScapegoatTree<i32> = ScapegoatTree::default();
+
+        assert!(tree.is_empty());
+        assert_eq!(tree.height(), 0);
+        assert_eq!(tree.root(), None);
+        assert_eq!(tree.min(), None);
+        assert_eq!(tree.max(), None);
+    }
+
+    #[test]
+    fn min_and_max() {
+        let tree = ScapegoatTree::from(vec![8, 4, 6, 16, -5, 25]);
+
+        assert_eq!(tree.min(), Some(&-5));
+        assert_eq!(tree.max(), Some(&25));
+    }
+
+    #[test]
+    fn clear() {
+        let mut tree = ScapegoatTree::from(vec![8, 4, 6]);
+        tree.clear();
+
+        assert_eq!(tree, ScapegoatTree::new());
+    }
+
+    #[test]
+    fn depth_limit_is_log_of_count() {
+        let tree: ScapegoatTree<i32> = ScapegoatTree::with_alpha(0.5);
+
+        assert_eq!(tree.depth_limit(1), 0);
+        assert_eq!(tree.depth_limit(3), 1);
+        assert_eq!(tree.depth_limit(4), 2);
+        assert_eq!(tree.depth_limit(1024), 10);
+    }
+}
+
+#[cfg(test)]
+mod iterator_trait_impls {
+    use super::ScapegoatTree;
+
+    #[test]
+    fn iter_from_tree() {
+        let tree = ScapegoatTree::from(vec![5, 4, 6]);
+        let mut iter = tree.iter();
+
+        assert_eq!(iter.next(), Some(&5));
+        assert_eq!(iter.next(), Some(&4));
+        assert_eq!(iter.next(), Some(&6));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn into_iter_from_tree() {
+        let tree = ScapegoatTree::from(vec![5, 4, 6]);
+        let mut iter = tree.into_iter();
+
+        assert_eq!(iter.next(), Some(5));
+        assert_eq!(iter.next(), Some(4));
+        assert_eq!(iter.next(), Some(6));
+        assert_eq!(iter.next(), None);
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod json {
+    use super::ScapegoatTree;
+
+    #[test]
+    fn round_trips_through_json() {
+        let tree: ScapegoatTree<i32> = (0..20).collect();
+
+        let json = serde_json::to_string(&tree).expect("should parse tree into json");
+        let actual: ScapegoatTree<i32> =
+            serde_json::from_str(&json).expect("should parse json into tree");
+
+        assert_eq!(actual, tree);
+    }
+
+    #[test]
+    fn rejects_alpha_outside_range() {
+        for alpha in ["1.0", "0.4", "-1.0"] {
+            let json = format!(r#"{{"root":null,"count":0,"max_count":0,"alpha":{alpha}}}"#);
+            let actual = serde_json::from_str::<ScapegoatTree<i32>>(&json);
+
+            assert!(actual.is_err(), "alpha {alpha} should be rejected");
+        }
+    }
+}
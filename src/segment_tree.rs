@@ -0,0 +1,627 @@
+use std::marker::PhantomData;
+use std::ops::{Bound, RangeBounds};
+
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
+
+/// An associative operation that a [`SegmentTree`] combines its elements with,
+/// together with updates that can be applied to whole ranges at once.
+///
+/// For lazy propagation to be correct, applying an update must distribute over combining:
+/// applying it to the combination of two segments must equal combining the two updated segments.
+pub trait Operation<T> {
+    /// An update that can be applied to every element of a range.
+    type Update: Clone;
+
+    /// Returns the result of combining no elements.
+    fn identity() -> T;
+
+    /// Combines the result of a segment with the result of the segment that follows it.
+    fn combine(left: &T, right: &T) -> T;
+
+    /// Returns the result of a segment of `len` elements after `update` is applied to each of them.
+    fn apply(update: &Self::Update, value: &T, len: usize) -> T;
+
+    /// Returns the single update equivalent to applying `older` and then `newer`.
+    fn compose(newer: &Self::Update, older: &Self::Update) -> Self::Update;
+}
+
+/// Sums the elements of a range, where updates add to every element in a range.
+///
+/// # Examples
+/// ```
+/// # use ds_rs::segment_tree::{SegmentTree, Sum};
+/// let mut tree: SegmentTree<i32, Sum> = SegmentTree::from(vec![1, 2, 3, 4]);
+/// tree.update(1..3, 10);
+/// assert_eq!(tree.query(..), Some(30));
+/// ```
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Sum;
+
+/// Finds the smallest element of a range, where updates add to every element in a range.
+///
+/// # Examples
+/// ```
+/// # use ds_rs::segment_tree::{Min, SegmentTree};
+/// let mut tree: SegmentTree<i32, Min> = SegmentTree::from(vec![5, 2, 7, 4]);
+/// tree.update(1..2, 10);
+/// assert_eq!(tree.query(..), Some(4));
+/// ```
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Min;
+
+/// Finds the largest element of a range, where updates add to every element in a range.
+///
+/// # Examples
+/// ```
+/// # use ds_rs::segment_tree::{Max, SegmentTree};
+/// let mut tree: SegmentTree<i32, Max> = SegmentTree::from(vec![5, 2, 7, 4]);
+/// tree.update(3.., 10);
+/// assert_eq!(tree.query(..), Some(14));
+/// ```
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Max;
+
+macro_rules! impl_add_operations {
+    ($($t:ty),*) => {$(
+        impl Operation<$t> for Sum {
+            type Update = $t;
+
+            #[inline]
+            fn identity() -> $t {
+                0 as $t
+            }
+
+            #[inline]
+            fn combine(left: &$t, right: &$t) -> $t {
+                left + right
+            }
+
+            #[inline]
+            fn apply(update: &$t, value: &$t, len: usize) -> $t {
+                value + update * len as $t
+            }
+
+            #[inline]
+            fn compose(newer: &$t, older: &$t) -> $t {
+                newer + older
+            }
+        }
+
+        impl Operation<$t> for Min {
+            type Update = $t;
+
+            #[inline]
+            fn identity() -> $t {
+                <$t>::MAX
+            }
+
+            #[inline]
+            fn combine(left: &$t, right: &$t) -> $t {
+                if right < left { *right } else { *left }
+            }
+
+            #[inline]
+            fn apply(update: &$t, value: &$t, _: usize) -> $t {
+                value + update
+            }
+
+            #[inline]
+            fn compose(newer: &$t, older: &$t) -> $t {
+                newer + older
+            }
+        }
+
+        impl Operation<$t> for Max {
+            type Update = $t;
+
+            #[inline]
+            fn identity() -> $t {
+                <$t>::MIN
+            }
+
+            #[inline]
+            fn combine(left: &$t, right: &$t) -> $t {
+                if right > left { *right } else { *left }
+            }
+
+            #[inline]
+            fn apply(update: &$t, value: &$t, _: usize) -> $t {
+                value + update
+            }
+
+            #[inline]
+            fn compose(newer: &$t, older: &$t) -> $t {
+                newer + older
+            }
+        }
+    )*};
+}
+
+impl_add_operations!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+/// A fixed-length sequence that answers queries over any range, and applies updates to whole ranges.
+///
+/// The elements are combined under the operation `Op`, see [`Operation`].
+/// The tree is stored in a vector, where the children of the node at index `i` sit at `2i` and `2i + 1`.
+/// An update to a range is recorded on the largest nodes covering it,
+/// and only pushed down to their children when a later operation needs them.
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "json",
+    serde(bound(
+        serialize = "T: Serialize, Op::Update: Serialize",
+        deserialize = "T: Deserialize<'de>, Op::Update: Deserialize<'de>"
+    ))
+)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SegmentTree<T, Op: Operation<T>> {
+    nodes: Vec<T>,
+    pending: Vec<Option<Op::Update>>,
+    len: usize,
+    op: PhantomData<Op>,
+}
+
+impl<T, Op: Operation<T>> SegmentTree<T, Op> {
+    /// Returns the number of elements in the sequence.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::segment_tree::{SegmentTree, Sum};
+    /// let tree: SegmentTree<i32, Sum> = SegmentTree::from(vec![1, 2, 3]);
+    /// assert_eq!(tree.len(), 3);
+    /// ```
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the sequence contains no elements.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::segment_tree::{SegmentTree, Sum};
+    /// let tree: SegmentTree<i32, Sum> = SegmentTree::from(vec![]);
+    /// assert!(tree.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the half-open bounds of `range` within the sequence, or `None` if they fall outside it.
+    fn bounds<R: RangeBounds<usize>>(&self, range: &R) -> Option<(usize, usize)> {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.checked_add(1)?,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1)?,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.len,
+        };
+
+        (start <= end && end <= self.len).then_some((start, end))
+    }
+
+    /// Applies `update` to the node covering `len` elements, and records it for the children of the node.
+    fn apply(&mut self, node: usize, len: usize, update: &Op::Update) -> Option<()> {
+        let value = self.nodes.get_mut(node)?;
+        *value = Op::apply(update, value, len);
+
+        if len > 1 {
+            let pending = self.pending.get_mut(node)?;
+            *pending = Some(match pending.as_ref() {
+                Some(older) => Op::compose(update, older),
+                None => update.clone(),
+            });
+        }
+
+        Some(())
+    }
+
+    /// Moves the update recorded on the node down to its two children.
+    fn push(&mut self, node: usize, left_len: usize, right_len: usize) -> Option<()> {
+        if let Some(update) = self.pending.get_mut(node)?.take() {
+            self.apply(2 * node, left_len, &update)?;
+            self.apply(2 * node + 1, right_len, &update)?;
+        }
+
+        Some(())
+    }
+
+    /// Recomputes the node from its two children.
+    fn pull(&mut self, node: usize) -> Option<()> {
+        let combined = Op::combine(self.nodes.get(2 * node)?, self.nodes.get(2 * node + 1)?);
+        *self.nodes.get_mut(node)? = combined;
+
+        Some(())
+    }
+
+    /// Builds the subtree of `node` covering `lo..hi` from the next values of the iterator.
+    fn build<I: Iterator<Item = T>>(&mut self, node: usize, lo: usize, hi: usize, values: &mut I) {
+        if hi - lo == 1 {
+            if let (Some(slot), Some(value)) = (self.nodes.get_mut(node), values.next()) {
+                *slot = value;
+            }
+            return;
+        }
+
+        let mid = lo + (hi - lo) / 2;
+        self.build(2 * node, lo, mid, values);
+        self.build(2 * node + 1, mid, hi, values);
+        self.pull(node);
+    }
+
+    /// Applies `update` to the part of `start..end` covered by the subtree of `node`, which covers `lo..hi`.
+    fn update_range(
+        &mut self,
+        node: usize,
+        (lo, hi): (usize, usize),
+        (start, end): (usize, usize),
+        update: &Op::Update,
+    ) -> Option<()> {
+        if end <= lo || hi <= start {
+            return Some(());
+        }
+        if start <= lo && hi <= end {
+            return self.apply(node, hi - lo, update);
+        }
+
+        let mid = lo + (hi - lo) / 2;
+        self.push(node, mid - lo, hi - mid)?;
+        self.update_range(2 * node, (lo, mid), (start, end), update)?;
+        self.update_range(2 * node + 1, (mid, hi), (start, end), update)?;
+        self.pull(node)
+    }
+
+    /// Replaces the element at `index` within the subtree of `node`, which covers `lo..hi`.
+    fn set_at(
+        &mut self,
+        node: usize,
+        (lo, hi): (usize, usize),
+        index: usize,
+        value: T,
+    ) -> Option<T> {
+        if hi - lo == 1 {
+            return Some(std::mem::replace(self.nodes.get_mut(node)?, value));
+        }
+
+        let mid = lo + (hi - lo) / 2;
+        self.push(node, mid - lo, hi - mid)?;
+        let old = if index < mid {
+            self.set_at(2 * node, (lo, mid), index, value)?
+        } else {
+            self.set_at(2 * node + 1, (mid, hi), index, value)?
+        };
+        self.pull(node)?;
+
+        Some(old)
+    }
+
+    /// Applies `update` to every element within the range.
+    ///
+    /// Returns `false` and leaves the sequence unchanged if the range reaches outside of it.
+    ///
+    /// # Time Complexity
+    ///
+    /// The update is recorded on at most `2 log(n)` nodes, and pushed further down by later calls as needed.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::segment_tree::{SegmentTree, Sum};
+    /// let mut tree: SegmentTree<i32, Sum> = SegmentTree::from(vec![0; 8]);
+    /// assert!(tree.update(2..6, 5));
+    /// assert_eq!(tree.query(0..4), Some(10));
+    ///
+    /// assert!(!tree.update(6..10, 5));
+    /// ```
+    pub fn update<R: RangeBounds<usize>>(&mut self, range: R, update: Op::Update) -> bool {
+        let Some((start, end)) = self.bounds(&range) else {
+            return false;
+        };
+
+        if start < end {
+            self.update_range(1, (0, self.len), (start, end), &update);
+        }
+
+        true
+    }
+
+    /// Replaces the element at `index`, and returns the old element.
+    ///
+    /// Returns `None` and leaves the sequence unchanged if the index is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::segment_tree::{SegmentTree, Sum};
+    /// let mut tree: SegmentTree<i32, Sum> = SegmentTree::from(vec![1, 2, 3]);
+    /// assert_eq!(tree.set(1, 10), Some(2));
+    /// assert_eq!(tree.query(..), Some(14));
+    ///
+    /// assert_eq!(tree.set(3, 10), None);
+    /// ```
+    pub fn set(&mut self, index: usize, value: T) -> Option<T> {
+        if index >= self.len {
+            return None;
+        }
+
+        self.set_at(1, (0, self.len), index, value)
+    }
+}
+
+/// The methods in this implementation block require trait bounds to correctly apply the logic of the methods.
+///
+/// The trait bound in question is [`Clone`], which is used to copy results out of the tree.
+impl<T, Op> SegmentTree<T, Op>
+where
+    T: Clone,
+    Op: Operation<T>,
+{
+    /// Returns the elements within the range combined in order, or `None` if the range reaches outside the sequence.
+    ///
+    /// An empty range returns the identity of the operation.
+    ///
+    /// # Time Complexity
+    ///
+    /// The range is covered by at most `2 log(n)` nodes, so this takes `log(n)` time.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::segment_tree::{Min, SegmentTree};
+    /// let tree: SegmentTree<i32, Min> = SegmentTree::from(vec![5, 2, 7, 4]);
+    /// assert_eq!(tree.query(2..), Some(4));
+    /// assert_eq!(tree.query(..=1), Some(2));
+    /// assert_eq!(tree.query(2..2), Some(i32::MAX));
+    /// assert_eq!(tree.query(3..5), None);
+    /// ```
+    pub fn query<R: RangeBounds<usize>>(&self, range: R) -> Option<T> {
+        let (start, end) = self.bounds(&range)?;
+
+        if start == end {
+            return Some(Op::identity());
+        }
+
+        self.query_range(1, (0, self.len), (start, end))
+    }
+
+    /// Returns the element at `index`, or `None` if the index is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::segment_tree::{SegmentTree, Sum};
+    /// let mut tree: SegmentTree<i32, Sum> = SegmentTree::from(vec![1, 2, 3]);
+    /// tree.update(.., 10);
+    /// assert_eq!(tree.get(1), Some(12));
+    /// assert_eq!(tree.get(3), None);
+    /// ```
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<T> {
+        self.query(index..=index)
+    }
+
+    /// Combines the part of `start..end` covered by the subtree of `node`, which covers `lo..hi`.
+    ///
+    /// Updates still recorded on the path are applied to the partial result on the way back up,
+    /// which leaves the tree itself untouched.
+    fn query_range(
+        &self,
+        node: usize,
+        (lo, hi): (usize, usize),
+        (start, end): (usize, usize),
+    ) -> Option<T> {
+        if start <= lo && hi <= end {
+            return self.nodes.get(node).cloned();
+        }
+
+        let mid = lo + (hi - lo) / 2;
+        let combined = match (start < mid, mid < end) {
+            (true, true) => Op::combine(
+                &self.query_range(2 * node, (lo, mid), (start, end))?,
+                &self.query_range(2 * node + 1, (mid, hi), (start, end))?,
+            ),
+            (true, false) => self.query_range(2 * node, (lo, mid), (start, end))?,
+            (false, _) => self.query_range(2 * node + 1, (mid, hi), (start, end))?,
+        };
+
+        match self.pending.get(node)? {
+            Some(update) => Some(Op::apply(update, &combined, end.min(hi) - start.max(lo))),
+            None => Some(combined),
+        }
+    }
+}
+
+impl<T, Op: Operation<T>> From<Vec<T>> for SegmentTree<T, Op> {
+    /// Creates a `SegmentTree<T, Op>` over the elements of `Vec<T>`.
+    fn from(vec: Vec<T>) -> Self {
+        let len = vec.len();
+        let size = 4 * len.max(1);
+
+        let mut tree = Self {
+            nodes: std::iter::repeat_with(Op::identity).take(size).collect(),
+            pending: std::iter::repeat_with(|| None).take(size).collect(),
+            len,
+            op: PhantomData,
+        };
+
+        if len > 0 {
+            tree.build(1, 0, len, &mut vec.into_iter());
+        }
+
+        tree
+    }
+}
+
+impl<T, Op: Operation<T>> FromIterator<T> for SegmentTree<T, Op> {
+    /// Constructs a `SegmentTree<T, Op>` over the elements of an iterator.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+#[cfg(test)]
+mod query_update {
+    use super::{Max, Min, Operation, SegmentTree, Sum};
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    #[test]
+    fn single_element_sequence() {
+        let mut tree: SegmentTree<i32, Sum> = SegmentTree::from(vec![5]);
+
+        assert!(tree.update(.., 2));
+        assert_eq!(tree.query(..), Some(7));
+        assert_eq!(tree.set(0, 1), Some(7));
+        assert_eq!(tree.get(0), Some(1));
+    }
+
+    #[test]
+    fn empty_sequence() {
+        let mut tree: SegmentTree<i32, Sum> = SegmentTree::from(vec![]);
+
+        assert_eq!(tree.query(..), Some(0));
+        assert_eq!(tree.get(0), None);
+        assert!(tree.update(.., 1));
+        assert!(!tree.update(0..1, 1));
+        assert_eq!(tree.set(0, 1), None);
+    }
+
+    #[test]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn out_of_bounds_ranges_are_rejected() {
+        let mut tree: SegmentTree<i32, Sum> = (0..10).collect();
+        let before = tree.clone();
+
+        assert_eq!(tree.query(5..11), None);
+        assert_eq!(tree.query(6..5), None);
+        assert_eq!(tree.query(..=10), None);
+        assert!(!tree.update(5..11, 1));
+        assert!(!tree.update(6..5, 1));
+        assert_eq!(tree, before);
+    }
+
+    fn random_range(rng: &mut StdRng, len: usize) -> (usize, usize) {
+        let start = rng.gen_range(0..=len);
+        (start, rng.gen_range(start..=len))
+    }
+
+    fn matches_naive<Op: Operation<i64, Update = i64>>(seed: u64, fold: fn(&[i64]) -> i64) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut naive: Vec<i64> = (0..137).map(|_| rng.gen_range(-100..100)).collect();
+        let mut tree: SegmentTree<i64, Op> = SegmentTree::from(naive.clone());
+
+        for _ in 0..2000 {
+            let (start, end) = random_range(&mut rng, naive.len());
+
+            match rng.gen_range(0..3) {
+                0 => {
+                    let update = rng.gen_range(-10..10);
+                    assert!(tree.update(start..end, update));
+                    naive
+                        .iter_mut()
+                        .take(end)
+                        .skip(start)
+                        .for_each(|v| *v += update);
+                }
+                1 if start < naive.len() => {
+                    let value = rng.gen_range(-100..100);
+                    let old = naive
+                        .get_mut(start)
+                        .map(|slot| std::mem::replace(slot, value));
+                    assert_eq!(tree.set(start, value), old);
+                }
+                _ => {
+                    let expected = naive.get(start..end).map(fold);
+                    assert_eq!(tree.query(start..end), expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn sum_matches_naive() {
+        matches_naive::<Sum>(33, |values| values.iter().sum());
+    }
+
+    #[test]
+    fn min_matches_naive() {
+        matches_naive::<Min>(133, |values| {
+            values.iter().copied().min().unwrap_or(i64::MAX)
+        });
+    }
+
+    #[test]
+    fn max_matches_naive() {
+        matches_naive::<Max>(233, |values| {
+            values.iter().copied().max().unwrap_or(i64::MIN)
+        });
+    }
+
+    /// Sums elements, where updates replace every element `x` with `a * x + b`.
+    struct Affine;
+
+    impl Operation<i64> for Affine {
+        type Update = (i64, i64);
+
+        fn identity() -> i64 {
+            0
+        }
+
+        fn combine(left: &i64, right: &i64) -> i64 {
+            left + right
+        }
+
+        fn apply(&(a, b): &(i64, i64), value: &i64, len: usize) -> i64 {
+            a * value + b * len as i64
+        }
+
+        fn compose(&(a, b): &(i64, i64), &(c, d): &(i64, i64)) -> (i64, i64) {
+            (a * c, a * d + b)
+        }
+    }
+
+    #[test]
+    fn updates_compose_in_order() {
+        let mut tree: SegmentTree<i64, Affine> = SegmentTree::from(vec![1, 2, 3, 4]);
+
+        // (x + 1) * 2 differs from x * 2 + 1, so applying them out of order is caught
+        tree.update(0..4, (1, 1));
+        tree.update(0..4, (2, 0));
+        tree.update(1..3, (1, -1));
+
+        assert_eq!(tree.get(0), Some(4));
+        assert_eq!(tree.get(1), Some(5));
+        assert_eq!(tree.get(2), Some(7));
+        assert_eq!(tree.get(3), Some(10));
+        assert_eq!(tree.query(..), Some(26));
+    }
+
+    #[test]
+    fn float_sums() {
+        let mut tree: SegmentTree<f64, Sum> = SegmentTree::from(vec![0.5, 1.5, 2.0]);
+        tree.update(..2, 0.25);
+
+        assert_eq!(tree.query(..), Some(4.5));
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod json {
+    use super::{SegmentTree, Sum};
+
+    #[test]
+    fn round_trips_with_pending_updates() {
+        let mut tree: SegmentTree<i32, Sum> = (0..10).collect();
+        tree.update(2..7, 3);
+
+        let json = serde_json::to_string(&tree).expect("should parse tree into json");
+        let actual: SegmentTree<i32, Sum> =
+            serde_json::from_str(&json).expect("should parse json into tree");
+
+        assert_eq!(actual, tree);
+        assert_eq!(actual.query(..), Some(60));
+    }
+}
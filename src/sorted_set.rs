@@ -0,0 +1,231 @@
+/// An ordered collection of unique elements.
+///
+/// The trait lets code be generic over the tree types of this crate that store a set of values.
+/// Implementations can be checked against the reference behaviour of
+/// [`BTreeSet`](alloc::collections::BTreeSet) with [`sorted_set_conformance!`](crate::sorted_set_conformance).
+///
+/// Several trees have inherent methods with the same names, which take precedence in method calls.
+/// Call the trait methods through generic code, or as `SortedSet::insert(&mut tree, value)`.
+///
+/// # Examples
+/// ```
+/// # use ds_rs::binary_tree::BinaryTree;
+/// # use ds_rs::sorted_set::SortedSet;
+/// fn smallest_gap<S: SortedSet<i32>>(set: &S) -> Option<i32> {
+///     let mut iter = set.iter();
+///     let mut previous = *iter.next()?;
+///
+///     iter.map(|&value| {
+///         let gap = value - previous;
+///         previous = value;
+///         gap
+///     })
+///     .min()
+/// }
+///
+/// let tree = BinaryTree::from(vec![10, 1, 7, 3]);
+/// assert_eq!(smallest_gap(&tree), Some(2));
+/// ```
+pub trait SortedSet<T: Ord> {
+    /// The iterator returned by [`SortedSet::iter`].
+    type Iter<'a>: Iterator<Item = &'a T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    /// Adds a value to the set.
+    ///
+    /// Returns `true` if the value was not already in the set, otherwise the set is left unchanged.
+    fn insert(&mut self, value: T) -> bool;
+
+    /// Removes the element equal to the given value from the set, and returns it.
+    fn remove(&mut self, target: &T) -> Option<T>;
+
+    /// Returns `true` if the set contains an element equal to the given value.
+    fn contains(&self, target: &T) -> bool;
+
+    /// Returns the smallest element in the set.
+    fn min(&self) -> Option<&T>;
+
+    /// Returns the largest element in the set.
+    fn max(&self) -> Option<&T>;
+
+    /// Returns the number of elements in the set.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if the set contains no elements.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator that yields all elements in ascending order.
+    fn iter(&self) -> Self::Iter<'_>;
+}
+
+/// Generates a module of tests that check a [`SortedSet`] implementation against
+/// [`BTreeSet`](alloc::collections::BTreeSet).
+///
+/// The first argument names the generated module, and the second is an expression that creates an
+/// empty set of `i32`. The module is only compiled for tests.
+///
+/// # Examples
+/// ```
+/// # use ds_rs::binary_tree::BinaryTree;
+/// ds_rs::sorted_set_conformance!(binary_tree, BinaryTree::new());
+/// # fn main() {}
+/// ```
+/// The reference set of [`sorted_set_conformance!`](crate::sorted_set_conformance), reachable through `$crate`
+/// so the generated tests also build without the standard library.
+#[doc(hidden)]
+pub use alloc::collections::BTreeSet as __BTreeSet;
+
+#[macro_export]
+macro_rules! sorted_set_conformance {
+    ($name:ident, $new:expr) => {
+        #[cfg(test)]
+        mod $name {
+            use super::*;
+            use $crate::sorted_set::__BTreeSet as BTreeSet;
+            use $crate::sorted_set::SortedSet;
+
+            // hides inherent methods of the same name, so the trait methods are the ones tested
+            fn new_set() -> impl SortedSet<i32> {
+                $new
+            }
+
+            /// Returns pseudo-random keys from a fixed seed, so failures can be reproduced.
+            fn keys(count: usize, max: u64) -> impl Iterator<Item = i32> {
+                let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+
+                (0..count).map(move |_| {
+                    state = state
+                        .wrapping_mul(6_364_136_223_846_793_005)
+                        .wrapping_add(1_442_695_040_888_963_407);
+                    ((state >> 33) % max) as i32
+                })
+            }
+
+            fn assert_matches(set: &impl SortedSet<i32>, reference: &BTreeSet<i32>) {
+                assert_eq!(set.len(), reference.len());
+                assert_eq!(set.is_empty(), reference.is_empty());
+                assert_eq!(set.min(), reference.first());
+                assert_eq!(set.max(), reference.last());
+                assert!(set.iter().eq(reference.iter()));
+            }
+
+            #[test]
+            fn starts_empty() {
+                let set = new_set();
+
+                assert_matches(&set, &BTreeSet::new());
+                assert!(!set.contains(&0));
+            }
+
+            #[test]
+            fn insert_reports_new_elements() {
+                let mut set = new_set();
+
+                assert!(set.insert(5));
+                assert!(set.insert(3));
+                assert!(!set.insert(5));
+                assert_eq!(set.len(), 2);
+                assert!(set.contains(&5));
+                assert!(set.contains(&3));
+                assert!(!set.contains(&4));
+            }
+
+            #[test]
+            fn remove_returns_the_element() {
+                let mut set = new_set();
+                set.insert(5);
+                set.insert(3);
+                set.insert(8);
+
+                assert_eq!(set.remove(&3), Some(3));
+                assert_eq!(set.remove(&3), None);
+                assert_eq!(set.remove(&4), None);
+                assert!(!set.contains(&3));
+                assert_eq!(set.len(), 2);
+            }
+
+            #[test]
+            fn removes_every_element() {
+                let mut set = new_set();
+                let mut reference = BTreeSet::new();
+
+                for key in keys(200, 1000) {
+                    assert_eq!(set.insert(key), reference.insert(key));
+                }
+                for key in reference.clone() {
+                    assert_eq!(set.remove(&key), Some(key));
+                    reference.remove(&key);
+                    assert_matches(&set, &reference);
+                }
+
+                assert!(set.is_empty());
+            }
+
+            #[test]
+            fn iter_is_sorted() {
+                let mut set = new_set();
+
+                for key in [50, -3, 20, 7, 100, 0, 7] {
+                    set.insert(key);
+                }
+
+                assert!(set.iter().copied().eq([-3, 0, 7, 20, 50, 100]));
+            }
+
+            #[test]
+            fn random_operations_match_btree_set() {
+                let mut set = new_set();
+                let mut reference = BTreeSet::new();
+
+                // each value encodes both the operation and the key it applies to
+                for (step, value) in keys(4000, 900).enumerate() {
+                    let key = value / 3;
+
+                    match value % 3 {
+                        0 | 1 => assert_eq!(set.insert(key), reference.insert(key)),
+                        _ => assert_eq!(set.remove(&key), reference.take(&key)),
+                    }
+                    assert_eq!(set.contains(&key), reference.contains(&key));
+
+                    if step % 100 == 0 {
+                        assert_matches(&set, &reference);
+                    }
+                }
+
+                assert_matches(&set, &reference);
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod conformance {
+    use crate::arena_tree::ArenaTree;
+    #[cfg(feature = "std")]
+    use crate::b_tree::BTree;
+    use crate::binary_tree::BinaryTree;
+    #[cfg(feature = "std")]
+    use crate::persistent::PersistentTree;
+    #[cfg(feature = "std")]
+    use crate::scapegoat_tree::ScapegoatTree;
+    #[cfg(feature = "std")]
+    use crate::weight_balanced_tree::WbTree;
+
+    crate::sorted_set_conformance!(binary_tree, BinaryTree::new());
+    #[cfg(feature = "std")]
+    crate::sorted_set_conformance!(scapegoat_tree, ScapegoatTree::new());
+    #[cfg(feature = "std")]
+    crate::sorted_set_conformance!(b_tree, BTree::<i32>::new());
+    #[cfg(feature = "std")]
+    crate::sorted_set_conformance!(b_tree_min_order, BTree::<i32, 2>::new());
+    #[cfg(feature = "std")]
+    crate::sorted_set_conformance!(weight_balanced_tree, WbTree::new());
+    #[cfg(feature = "std")]
+    crate::sorted_set_conformance!(persistent, PersistentTree::new());
+    crate::sorted_set_conformance!(arena_tree, ArenaTree::new());
+}
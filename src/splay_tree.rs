@@ -0,0 +1,965 @@
+use std::cmp::Ordering;
+
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
+
+/// A self-adjusting binary search tree.
+///
+/// Every lookup moves the accessed element to the root of the tree, which makes
+/// repeated access to the same few elements very cheap.
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SplayTree<T> {
+    root: Option<Box<Node<T>>>,
+    count: usize,
+}
+
+impl<T> SplayTree<T> {
+    /// Constructs a new empty `SplayTree<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ds_rs::splay_tree::SplayTree;
+    /// # #[allow(unused_mut)]
+    /// let mut tree: SplayTree<i32> = SplayTree::new();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            root: None,
+            count: 0,
+        }
+    }
+
+    /// Returns `true` if the splay tree contains no elements.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::splay_tree::SplayTree;
+    /// let mut tree = SplayTree::new();
+    /// assert!(tree.is_empty());
+    ///
+    /// tree.insert(0);
+    /// assert!(!tree.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Clears the splay tree of all elements.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::splay_tree::SplayTree;
+    /// let mut tree = SplayTree::new();
+    /// tree.insert(0);
+    /// assert!(!tree.is_empty());
+    ///
+    /// tree.clear();
+    /// assert!(tree.is_empty());
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.root = None;
+        self.count = 0;
+    }
+
+    /// Traverses and returns the height of the splay tree.
+    ///
+    /// An empty tree has a height of `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::splay_tree::SplayTree;
+    /// let mut tree = SplayTree::new();
+    /// assert_eq!(tree.height(), 0);
+    ///
+    /// tree.insert(0);
+    /// assert_eq!(tree.height(), 1);
+    ///
+    /// // every insert splays the new element to the root,
+    /// // so ascending inserts form a chain of left children
+    /// tree.insert(1);
+    /// tree.insert(2);
+    /// assert_eq!(tree.height(), 3);
+    /// ```
+    #[inline]
+    pub fn height(&self) -> usize {
+        let mut height = 0;
+        let mut queue = Vec::new();
+
+        if let Some(root) = self.root.as_deref() {
+            queue.push((1, root));
+        }
+
+        while let Some((node_height, node)) = queue.pop() {
+            height = height.max(node_height);
+
+            if let Some(left) = node.left.as_deref() {
+                queue.push((node_height + 1, left));
+            }
+
+            if let Some(right) = node.right.as_deref() {
+                queue.push((node_height + 1, right));
+            }
+        }
+
+        height
+    }
+
+    /// Returns the value contained within the root element.
+    ///
+    /// This is the most recently accessed element, unless it has since been removed.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::splay_tree::SplayTree;
+    /// let mut tree = SplayTree::from(vec![1, 2, 3]);
+    /// assert_eq!(tree.root(), Some(&3));
+    ///
+    /// tree.contains(&1);
+    /// assert_eq!(tree.root(), Some(&1));
+    /// ```
+    pub fn root(&self) -> Option<&T> {
+        self.root.as_deref().map(|root| &root.value)
+    }
+
+    /// Returns the number of elements in the splay tree.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::splay_tree::SplayTree;
+    /// let mut tree = SplayTree::new();
+    /// assert_eq!(tree.count(), 0);
+    /// tree.insert(5);
+    /// assert_eq!(tree.count(), 1);
+    ///
+    /// // duplicates do not enter the tree
+    /// // and therefore do not affect the count
+    /// tree.insert(5);
+    /// assert_eq!(tree.count(), 1);
+    /// ```
+    #[inline]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns a non-consuming iterator over the `SplayTree`.
+    ///
+    /// The iterator yields all items in the tree using the **preorder tree traversal technique**.
+    ///
+    /// Iterating is read-only, and does not splay any elements.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::splay_tree::SplayTree;
+    /// let tree = SplayTree::from(vec![4, 6, 5]);
+    /// let mut tree_iter = tree.iter();
+    ///
+    /// assert_eq!(tree_iter.next(), Some(&5));
+    /// assert_eq!(tree_iter.next(), Some(&4));
+    /// assert_eq!(tree_iter.next(), Some(&6));
+    ///
+    /// // the iterator is now empty
+    /// assert_eq!(tree_iter.next(), None);
+    /// ```
+    #[inline]
+    #[must_use = "iterators are evaluated lazily"]
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.into_iter()
+    }
+
+    /// Returns the smallest element in the `SplayTree`.
+    ///
+    /// Unlike [`SplayTree::get`], this does not splay the element.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::splay_tree::SplayTree;
+    /// let tree = SplayTree::from(vec![8, 4, 6, 16, -5, 25]);
+    /// assert_eq!(tree.min(), Some(&-5));
+    /// ```
+    pub fn min(&self) -> Option<&T> {
+        let mut node = self.root.as_deref()?;
+        while let Some(left) = node.left.as_deref() {
+            node = left;
+        }
+
+        Some(&node.value)
+    }
+
+    /// Returns the largest element in the `SplayTree`.
+    ///
+    /// Unlike [`SplayTree::get`], this does not splay the element.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::splay_tree::SplayTree;
+    /// let tree = SplayTree::from(vec![8, 4, 6, 16, -5, 25]);
+    /// assert_eq!(tree.max(), Some(&25));
+    /// ```
+    pub fn max(&self) -> Option<&T> {
+        let mut node = self.root.as_deref()?;
+        while let Some(right) = node.right.as_deref() {
+            node = right;
+        }
+
+        Some(&node.value)
+    }
+}
+
+/// The methods in this implementation block require trait bounds to correctly apply the logic of the methods.
+///
+/// The trait bound in question is [`Ord`], which is used to compare elements in the tree.
+impl<T> SplayTree<T>
+where
+    T: Ord,
+{
+    /// Inserts the provided value into the `SplayTree`,
+    /// and splays it to the root of the tree.
+    ///
+    /// If the value is already present, the existing element is splayed instead.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::splay_tree::SplayTree;
+    /// let mut tree = SplayTree::new();
+    /// tree.insert(5);
+    /// tree.insert(3);
+    /// assert_eq!(tree.root(), Some(&3));
+    /// assert_eq!(tree.count(), 2);
+    /// ```
+    pub fn insert(&mut self, value: T) {
+        let Some(root) = self.root.take() else {
+            self.root = Some(Box::new(Node::new(value)));
+            self.count = 1;
+            return;
+        };
+
+        let mut root = splay(root, &value);
+        let mut node = Box::new(Node::new(value));
+
+        match node.value.cmp(&root.value) {
+            Ordering::Equal => {
+                self.root = Some(root);
+                return;
+            }
+            Ordering::Less => {
+                node.left = root.left.take();
+                node.right = Some(root);
+            }
+            Ordering::Greater => {
+                node.right = root.right.take();
+                node.left = Some(root);
+            }
+        }
+
+        self.root = Some(node);
+        self.count += 1;
+    }
+
+    /// Returns a reference to the element equal to the given value, if present.
+    ///
+    /// The search splays the tree, moving the element to the root if it is found,
+    /// and otherwise moving the last element visited by the search to the root.
+    ///
+    /// # Time Complexity
+    ///
+    /// A single lookup can take linear time, but any sequence of `m` operations on a tree
+    /// with `n` elements takes `O(m log(n))` time, making the amortized cost `log(n)`.
+    ///
+    /// Elements that are accessed often stay near the root, and are found faster.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::splay_tree::SplayTree;
+    /// let mut tree = SplayTree::from(vec![8, 4, 6, 16, -5, 25]);
+    /// assert_eq!(tree.get(&6), Some(&6));
+    /// assert_eq!(tree.root(), Some(&6));
+    ///
+    /// assert_eq!(tree.get(&5), None);
+    /// ```
+    pub fn get(&mut self, target: &T) -> Option<&T> {
+        let root = splay(self.root.take()?, target);
+        let root = self.root.insert(root);
+
+        match target.cmp(&root.value) {
+            Ordering::Equal => Some(&root.value),
+            Ordering::Less | Ordering::Greater => None,
+        }
+    }
+
+    /// Returns `true` if the `SplayTree` contains an element with the given value.
+    ///
+    /// Like [`SplayTree::get`], this splays the tree.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::splay_tree::SplayTree;
+    /// let mut tree = SplayTree::from(vec![8, 4, 6, 16, -5, 25]);
+    /// assert!(tree.contains(&8));
+    /// assert!(!tree.contains(&5));
+    /// ```
+    pub fn contains(&mut self, target: &T) -> bool {
+        self.get(target).is_some()
+    }
+
+    /// Removes the element equal to the given value from the `SplayTree`, and returns it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::splay_tree::SplayTree;
+    /// let mut tree = SplayTree::from(vec![8, 4, 6]);
+    /// assert_eq!(tree.remove(&4), Some(4));
+    /// assert_eq!(tree.remove(&4), None);
+    /// assert_eq!(tree.count(), 2);
+    /// ```
+    pub fn remove(&mut self, target: &T) -> Option<T> {
+        let mut root = splay(self.root.take()?, target);
+
+        if target.cmp(&root.value) != Ordering::Equal {
+            self.root = Some(root);
+            return None;
+        }
+
+        // every element in the left subtree is smaller than the target,
+        // so splaying it brings its largest element up as a root without a right child
+        self.root = match root.left.take() {
+            Some(left) => {
+                let mut left = splay(left, target);
+                left.right = root.right.take();
+                Some(left)
+            }
+            None => root.right.take(),
+        };
+        self.count -= 1;
+
+        Some(root.value)
+    }
+}
+
+/// Splays the tree rooted at `root` using the top-down technique, and returns the new root.
+///
+/// The new root is the element equal to `target`, or the last element on the search path if
+/// no such element exists.
+fn splay<T: Ord>(mut root: Box<Node<T>>, target: &T) -> Box<Node<T>> {
+    // nodes split off during the descent, each attaching to the one pushed before it
+    let mut smaller: Vec<Box<Node<T>>> = Vec::new();
+    let mut larger: Vec<Box<Node<T>>> = Vec::new();
+
+    loop {
+        match target.cmp(&root.value) {
+            Ordering::Equal => break,
+            Ordering::Less => {
+                let Some(mut left) = root.left.take() else {
+                    break;
+                };
+
+                if target < &left.value {
+                    // zig-zig, rotate right before linking
+                    root.left = left.right.take();
+                    left.right = Some(root);
+                    root = left;
+
+                    let Some(next) = root.left.take() else {
+                        break;
+                    };
+                    larger.push(root);
+                    root = next;
+                } else {
+                    larger.push(root);
+                    root = left;
+                }
+            }
+            Ordering::Greater => {
+                let Some(mut right) = root.right.take() else {
+                    break;
+                };
+
+                if target > &right.value {
+                    // zig-zig, rotate left before linking
+                    root.right = right.left.take();
+                    right.left = Some(root);
+                    root = right;
+
+                    let Some(next) = root.right.take() else {
+                        break;
+                    };
+                    smaller.push(root);
+                    root = next;
+                } else {
+                    smaller.push(root);
+                    root = right;
+                }
+            }
+        }
+    }
+
+    let mut left = root.left.take();
+    while let Some(mut node) = smaller.pop() {
+        node.right = left;
+        left = Some(node);
+    }
+
+    let mut right = root.right.take();
+    while let Some(mut node) = larger.pop() {
+        node.left = right;
+        right = Some(node);
+    }
+
+    root.left = left;
+    root.right = right;
+    root
+}
+
+impl<T: Ord> From<Vec<T>> for SplayTree<T> {
+    /// Creates a `SplayTree<T>` from `Vec<T>`.
+    fn from(vec: Vec<T>) -> Self {
+        let mut tree = SplayTree::new();
+        for v in vec {
+            tree.insert(v);
+        }
+
+        tree
+    }
+}
+
+impl<T: Ord> FromIterator<T> for SplayTree<T> {
+    /// Constructs a `SplayTree<T>` from an iterator for `T`.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut tree = SplayTree::new();
+
+        for v in iter {
+            tree.insert(v);
+        }
+
+        tree
+    }
+}
+
+impl<T: Ord> Extend<T> for SplayTree<T> {
+    /// Extends the `SplayTree` with the contents of the provided iterator.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for v in iter {
+            self.insert(v);
+        }
+    }
+}
+
+impl<T> IntoIterator for SplayTree<T> {
+    type Item = T;
+
+    type IntoIter = IntoIter<T>;
+
+    /// Returns a consuming iterator over the `SplayTree`.
+    ///
+    /// The iterator yields all items in the tree using the **preorder tree traversal technique**.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::splay_tree::SplayTree;
+    /// let tree = SplayTree::from(vec![4, 6, 5]);
+    /// let mut tree_iter = tree.into_iter();
+    ///
+    /// assert_eq!(tree_iter.next(), Some(5));
+    /// assert_eq!(tree_iter.next(), Some(4));
+    /// assert_eq!(tree_iter.next(), Some(6));
+    ///
+    /// // the iterator is now empty
+    /// assert_eq!(tree_iter.next(), None);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter {
+            stack: self.root.into_iter().collect(),
+        }
+    }
+}
+
+/// An iterator that moves out of the `SplayTree`.
+///
+/// This `struct` is created by the `into_iter` method on [`SplayTree`] (provided by the [`IntoIterator`] trait).
+pub struct IntoIter<T> {
+    stack: Vec<Box<Node<T>>>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        let Node { value, left, right } = *node;
+
+        self.stack.extend(right);
+        self.stack.extend(left);
+
+        Some(value)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a SplayTree<T> {
+    type Item = &'a T;
+
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        Iter {
+            stack: self.root.as_deref().into_iter().collect(),
+        }
+    }
+}
+
+/// An iterator that borrows from the `SplayTree`.
+///
+/// This `struct` is created by the `iter` method on [`SplayTree`].
+pub struct Iter<'a, T> {
+    stack: Vec<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+
+        self.stack.extend(node.right.as_deref());
+        self.stack.extend(node.left.as_deref());
+
+        Some(&node.value)
+    }
+}
+
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+struct Node<T> {
+    value: T,
+    left: Option<Box<Node<T>>>,
+    right: Option<Box<Node<T>>>,
+}
+
+impl<T> Node<T> {
+    /// Constructs a new `Node<T>` without any children.
+    fn new(value: T) -> Self {
+        Self {
+            value,
+            left: None,
+            right: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod insert {
+    use super::{Node, SplayTree};
+
+    #[test]
+    fn insert_one_element_that_becomes_root() {
+        let mut tree = SplayTree::new();
+        let expected = SplayTree {
+            root: Some(Box::new(Node {
+                value: 5,
+                left: None,
+                right: None,
+            })),
+            count: 1,
+        };
+        tree.insert(5);
+        assert_eq!(tree, expected);
+    }
+
+    #[test]
+    fn ascending_inserts_form_left_chain() {
+        let mut tree = SplayTree::new();
+        let expected = SplayTree {
+            root: Some(Box::new(Node {
+                value: 3,
+                left: Some(Box::new(Node {
+                    value: 2,
+                    left: Some(Box::new(Node {
+                        value: 1,
+                        left: None,
+                        right: None,
+                    })),
+                    right: None,
+                })),
+                right: None,
+            })),
+            count: 3,
+        };
+        tree.insert(1);
+        tree.insert(2);
+        tree.insert(3);
+        assert_eq!(tree, expected);
+    }
+
+    #[test]
+    fn inserts_between_existing_elements() {
+        let mut tree = SplayTree::new();
+        let expected = SplayTree {
+            root: Some(Box::new(Node {
+                value: 5,
+                left: Some(Box::new(Node {
+                    value: 4,
+                    left: None,
+                    right: None,
+                })),
+                right: Some(Box::new(Node {
+                    value: 6,
+                    left: None,
+                    right: None,
+                })),
+            })),
+            count: 3,
+        };
+        tree.insert(4);
+        tree.insert(6);
+        tree.insert(5);
+        assert_eq!(tree, expected);
+    }
+
+    #[test]
+    fn duplicate_insert_splays_existing_element() {
+        let mut tree = SplayTree::new();
+        let expected = SplayTree {
+            root: Some(Box::new(Node {
+                value: 1,
+                left: None,
+                right: Some(Box::new(Node {
+                    value: 2,
+                    left: None,
+                    right: Some(Box::new(Node {
+                        value: 3,
+                        left: None,
+                        right: None,
+                    })),
+                })),
+            })),
+            count: 3,
+        };
+        tree.insert(1);
+        tree.insert(2);
+        tree.insert(3);
+        tree.insert(1);
+        assert_eq!(tree, expected);
+    }
+}
+
+#[cfg(test)]
+mod get {
+    use super::{Node, SplayTree};
+
+    #[test]
+    fn empty_tree_returns_none() {
+        let mut tree: SplayTree<i32> = SplayTree::new();
+        assert_eq!(tree.get(&0), None);
+    }
+
+    #[test]
+    fn zig_zig_splays_deepest_element_to_root() {
+        let mut tree = SplayTree::from(vec![1, 2, 3]);
+        let expected = SplayTree {
+            root: Some(Box::new(Node {
+                value: 1,
+                left: None,
+                right: Some(Box::new(Node {
+                    value: 2,
+                    left: None,
+                    right: Some(Box::new(Node {
+                        value: 3,
+                        left: None,
+                        right: None,
+                    })),
+                })),
+            })),
+            count: 3,
+        };
+
+        assert_eq!(tree.get(&1), Some(&1));
+        assert_eq!(tree, expected);
+    }
+
+    #[test]
+    fn zig_zag_splays_element_to_root() {
+        let mut tree = SplayTree {
+            root: Some(Box::new(Node {
+                value: 10,
+                left: Some(Box::new(Node {
+                    value: 0,
+                    left: None,
+                    right: Some(Box::new(Node {
+                        value: 5,
+                        left: None,
+                        right: None,
+                    })),
+                })),
+                right: None,
+            })),
+            count: 3,
+        };
+        let expected = SplayTree {
+            root: Some(Box::new(Node {
+                value: 5,
+                left: Some(Box::new(Node {
+                    value: 0,
+                    left: None,
+                    right: None,
+                })),
+                right: Some(Box::new(Node {
+                    value: 10,
+                    left: None,
+                    right: None,
+                })),
+            })),
+            count: 3,
+        };
+
+        assert_eq!(tree.get(&5), Some(&5));
+        assert_eq!(tree, expected);
+    }
+
+    #[test]
+    fn missing_element_splays_last_visited_element() {
+        let mut tree = SplayTree::from(vec![10, 20, 30]);
+
+        // the search passes 30 and 20 before it ends at 10, which has no right child for 15
+        let expected = SplayTree {
+            root: Some(Box::new(Node {
+                value: 10,
+                left: None,
+                right: Some(Box::new(Node {
+                    value: 20,
+                    left: None,
+                    right: Some(Box::new(Node {
+                        value: 30,
+                        left: None,
+                        right: None,
+                    })),
+                })),
+            })),
+            count: 3,
+        };
+
+        assert_eq!(tree.get(&15), None);
+        assert_eq!(tree, expected);
+    }
+
+    #[test]
+    fn repeated_access_keeps_element_at_root() {
+        let mut tree: SplayTree<i32> = (0..100).collect();
+
+        assert!(tree.contains(&42));
+        assert_eq!(tree.root(), Some(&42));
+        assert!(tree.contains(&42));
+        assert_eq!(tree.root(), Some(&42));
+    }
+
+    #[test]
+    fn splaying_halves_degenerate_chain() {
+        let mut tree: SplayTree<i32> = (0..64).collect();
+        assert_eq!(tree.height(), 64);
+
+        assert!(tree.contains(&0));
+        assert!(tree.height() <= 34);
+    }
+}
+
+#[cfg(test)]
+mod remove {
+    use super::{Node, SplayTree};
+
+    #[test]
+    fn empty_tree_returns_none() {
+        let mut tree: SplayTree<i32> = SplayTree::new();
+        assert_eq!(tree.remove(&0), None);
+    }
+
+    #[test]
+    fn removes_only_element() {
+        let mut tree = SplayTree::from(vec![5]);
+
+        assert_eq!(tree.remove(&5), Some(5));
+        assert_eq!(tree, SplayTree::new());
+    }
+
+    #[test]
+    fn removes_root_with_two_children() {
+        let mut tree = SplayTree {
+            root: Some(Box::new(Node {
+                value: 5,
+                left: Some(Box::new(Node {
+                    value: 4,
+                    left: None,
+                    right: None,
+                })),
+                right: Some(Box::new(Node {
+                    value: 6,
+                    left: None,
+                    right: None,
+                })),
+            })),
+            count: 3,
+        };
+        let expected = SplayTree {
+            root: Some(Box::new(Node {
+                value: 4,
+                left: None,
+                right: Some(Box::new(Node {
+                    value: 6,
+                    left: None,
+                    right: None,
+                })),
+            })),
+            count: 2,
+        };
+
+        assert_eq!(tree.remove(&5), Some(5));
+        assert_eq!(tree, expected);
+    }
+
+    #[test]
+    fn missing_element_is_not_removed() {
+        let mut tree = SplayTree::from(vec![1, 3, 5]);
+
+        assert_eq!(tree.remove(&2), None);
+        assert_eq!(tree.count(), 3);
+    }
+
+    #[test]
+    fn removes_every_element() {
+        let mut tree: SplayTree<i32> = [5, 2, 8, 1, 9, 3, 7].into_iter().collect();
+
+        for v in [3, 9, 5, 1, 7, 8, 2] {
+            assert_eq!(tree.remove(&v), Some(v));
+            assert!(!tree.contains(&v));
+        }
+
+        assert!(tree.is_empty());
+        assert_eq!(tree.count(), 0);
+    }
+}
+
+#[cfg(test)]
+mod min_max {
+    use super::SplayTree;
+
+    #[test]
+    fn empty_tree_returns_none() {
+        let tree: SplayTree<i32> = SplayTree::new();
+        assert_eq!(tree.min(), None);
+        assert_eq!(tree.max(), None);
+    }
+
+    #[test]
+    fn does_not_splay() {
+        let tree = SplayTree::from(vec![8, 4, 6, 16, -5, 25]);
+        let before = tree.clone();
+
+        assert_eq!(tree.min(), Some(&-5));
+        assert_eq!(tree.max(), Some(&25));
+        assert_eq!(tree, before);
+    }
+}
+
+#[cfg(test)]
+mod iterator_trait_impls {
+    use super::{Node, SplayTree};
+
+    #[test]
+    fn iter_from_tree() {
+        let tree = SplayTree {
+            root: Some(Box::new(Node {
+                value: 5,
+                left: Some(Box::new(Node {
+                    value: 4,
+                    left: None,
+                    right: None,
+                })),
+                right: Some(Box::new(Node {
+                    value: 6,
+                    left: None,
+                    right: None,
+                })),
+            })),
+            count: 3,
+        };
+
+        let mut iter = tree.iter();
+
+        assert_eq!(iter.next(), Some(&5));
+        assert_eq!(iter.next(), Some(&4));
+        assert_eq!(iter.next(), Some(&6));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn iter_does_not_splay() {
+        let tree = SplayTree::from(vec![8, 4, 6, 16, -5, 25]);
+        let before = tree.clone();
+
+        assert_eq!(tree.iter().count(), 6);
+        assert_eq!(tree, before);
+    }
+
+    #[test]
+    fn into_iter_from_tree() {
+        let tree = SplayTree::from(vec![4, 6, 5]);
+        let mut iter = tree.into_iter();
+
+        assert_eq!(iter.next(), Some(5));
+        assert_eq!(iter.next(), Some(4));
+        assert_eq!(iter.next(), Some(6));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn extends_collection_with_iterator() {
+        let mut tree = SplayTree::from(vec![1, 2]);
+        tree.extend([3, 2, 0]);
+
+        let mut values: Vec<_> = tree.into_iter().collect();
+        values.sort();
+
+        assert_eq!(values, vec![0, 1, 2, 3]);
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod json {
+    use super::{Node, SplayTree};
+    use rstest::{fixture, rstest};
+
+    #[fixture]
+    fn json_tree() -> &'static str {
+        r#"{"root":{"value":5,"left":{"value":4,"left":null,"right":null},"right":null},"count":2}"#
+    }
+
+    #[rstest]
+    fn deserializes_tree_from_json(json_tree: &'static str) {
+        let tree: SplayTree<i32> =
+            serde_json::from_str(json_tree).expect("should parse json into tree");
+        let expected = SplayTree {
+            root: Some(Box::new(Node {
+                value: 5,
+                left: Some(Box::new(Node {
+                    value: 4,
+                    left: None,
+                    right: None,
+                })),
+                right: None,
+            })),
+            count: 2,
+        };
+
+        assert_eq!(tree, expected);
+    }
+
+    #[rstest]
+    fn serialize_tree_into_json(json_tree: &'static str) {
+        let tree = SplayTree::from(vec![4, 5]);
+        let actual = serde_json::to_string(&tree).expect("should parse tree into json");
+
+        assert_eq!(actual, json_tree);
+    }
+}
@@ -0,0 +1,1196 @@
+use std::cmp::Ordering;
+
+use crate::sorted_set::SortedSet;
+
+#[cfg(feature = "json")]
+use serde::{Deserialize, Serialize};
+
+/// A balanced binary search tree that keeps the size of every subtree.
+///
+/// The weight of a subtree is its size plus one, and no child of a node weighs less than
+/// `2 / 7` of the node. Storing sizes lets the tree answer order queries with [`WbTree::rank`]
+/// and [`WbTree::select`], and combine whole trees through [`WbTree::join`] and [`WbTree::split`].
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct WbTree<T> {
+    root: Link<T>,
+}
+
+type Link<T> = Option<Box<Node<T>>>;
+
+impl<T> WbTree<T> {
+    /// Constructs a new empty `WbTree<T>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use ds_rs::weight_balanced_tree::WbTree;
+    /// # #[allow(unused_mut)]
+    /// let mut tree: WbTree<i32> = WbTree::new();
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self { root: None }
+    }
+
+    /// Returns `true` if the tree contains no elements.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::weight_balanced_tree::WbTree;
+    /// let mut tree = WbTree::new();
+    /// assert!(tree.is_empty());
+    ///
+    /// tree.insert(0);
+    /// assert!(!tree.is_empty());
+    /// ```
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// Clears the tree of all elements.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::weight_balanced_tree::WbTree;
+    /// let mut tree = WbTree::from(vec![1, 2, 3]);
+    ///
+    /// tree.clear();
+    /// assert!(tree.is_empty());
+    /// ```
+    #[inline]
+    pub fn clear(&mut self) {
+        self.root = None;
+    }
+
+    /// Returns the number of elements in the tree.
+    ///
+    /// The count is stored in the root, so this takes constant time.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::weight_balanced_tree::WbTree;
+    /// let mut tree = WbTree::new();
+    /// tree.insert(5);
+    ///
+    /// // duplicates do not enter the tree
+    /// // and therefore do not affect the count
+    /// tree.insert(5);
+    /// assert_eq!(tree.count(), 1);
+    /// ```
+    #[inline]
+    pub fn count(&self) -> usize {
+        size(&self.root)
+    }
+
+    /// Traverses and returns the height of the tree.
+    ///
+    /// An empty tree has a height of `0`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::weight_balanced_tree::WbTree;
+    /// let tree: WbTree<i32> = (0..1000).collect();
+    /// assert!(tree.height() <= 20);
+    /// ```
+    pub fn height(&self) -> usize {
+        let mut height = 0;
+        let mut stack: Vec<_> = self
+            .root
+            .as_deref()
+            .map(|root| (1, root))
+            .into_iter()
+            .collect();
+
+        while let Some((node_height, node)) = stack.pop() {
+            height = height.max(node_height);
+            stack.extend(node.left.as_deref().map(|left| (node_height + 1, left)));
+            stack.extend(node.right.as_deref().map(|right| (node_height + 1, right)));
+        }
+
+        height
+    }
+
+    /// Returns the value contained within the root element.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::weight_balanced_tree::WbTree;
+    /// let tree = WbTree::from(vec![1, 2, 3]);
+    /// assert_eq!(tree.root(), Some(&2));
+    /// ```
+    pub fn root(&self) -> Option<&T> {
+        self.root.as_deref().map(|root| &root.value)
+    }
+
+    /// Returns the smallest element in the `WbTree`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::weight_balanced_tree::WbTree;
+    /// let tree = WbTree::from(vec![8, 4, 6, 16, -5, 25]);
+    /// assert_eq!(tree.min(), Some(&-5));
+    /// ```
+    pub fn min(&self) -> Option<&T> {
+        let mut node = self.root.as_deref()?;
+        while let Some(left) = node.left.as_deref() {
+            node = left;
+        }
+
+        Some(&node.value)
+    }
+
+    /// Returns the largest element in the `WbTree`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::weight_balanced_tree::WbTree;
+    /// let tree = WbTree::from(vec![8, 4, 6, 16, -5, 25]);
+    /// assert_eq!(tree.max(), Some(&25));
+    /// ```
+    pub fn max(&self) -> Option<&T> {
+        let mut node = self.root.as_deref()?;
+        while let Some(right) = node.right.as_deref() {
+            node = right;
+        }
+
+        Some(&node.value)
+    }
+
+    /// Returns the element at position `index` in ascending order, counting from `0`.
+    ///
+    /// # Time Complexity
+    ///
+    /// The subtree sizes guide the search straight to the element, which takes `log(n)` time.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::weight_balanced_tree::WbTree;
+    /// let tree = WbTree::from(vec![30, 10, 20]);
+    /// assert_eq!(tree.select(0), Some(&10));
+    /// assert_eq!(tree.select(2), Some(&30));
+    /// assert_eq!(tree.select(3), None);
+    /// ```
+    pub fn select(&self, mut index: usize) -> Option<&T> {
+        let mut node = self.root.as_deref()?;
+
+        loop {
+            let left_size = size(&node.left);
+
+            match index.cmp(&left_size) {
+                Ordering::Equal => return Some(&node.value),
+                Ordering::Less => node = node.left.as_deref()?,
+                Ordering::Greater => {
+                    index -= left_size + 1;
+                    node = node.right.as_deref()?;
+                }
+            }
+        }
+    }
+
+    /// Returns a non-consuming iterator over the `WbTree`.
+    ///
+    /// The iterator yields all items in ascending order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::weight_balanced_tree::WbTree;
+    /// let tree = WbTree::from(vec![5, 4, 6]);
+    /// let mut tree_iter = tree.iter();
+    ///
+    /// assert_eq!(tree_iter.next(), Some(&4));
+    /// assert_eq!(tree_iter.next(), Some(&5));
+    /// assert_eq!(tree_iter.next(), Some(&6));
+    ///
+    /// // the iterator is now empty
+    /// assert_eq!(tree_iter.next(), None);
+    /// ```
+    #[inline]
+    #[must_use = "iterators are evaluated lazily"]
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.into_iter()
+    }
+}
+
+/// The methods in this implementation block require trait bounds to correctly apply the logic of the methods.
+///
+/// The trait bound in question is [`Ord`], which is used to compare elements in the tree.
+impl<T> WbTree<T>
+where
+    T: Ord,
+{
+    /// Inserts the provided value into the `WbTree`,
+    /// and preserves the balance of the tree.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::weight_balanced_tree::WbTree;
+    /// let mut tree = WbTree::new();
+    /// tree.insert(5);
+    /// assert_eq!(tree.root(), Some(&5));
+    /// assert_eq!(tree.count(), 1);
+    /// ```
+    pub fn insert(&mut self, value: T) {
+        self.root = Some(insert(self.root.take(), value));
+    }
+
+    /// Returns `true` if the `WbTree` contains an element with the given value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::weight_balanced_tree::WbTree;
+    /// let tree = WbTree::from(vec![8, 4, 6, 16, -5, 25]);
+    /// assert!(tree.contains(&8));
+    /// assert!(!tree.contains(&5));
+    /// ```
+    pub fn contains(&self, target: &T) -> bool {
+        let mut node = self.root.as_deref();
+
+        while let Some(current) = node {
+            node = match target.cmp(&current.value) {
+                Ordering::Equal => return true,
+                Ordering::Less => current.left.as_deref(),
+                Ordering::Greater => current.right.as_deref(),
+            };
+        }
+
+        false
+    }
+
+    /// Removes the element equal to the given value from the `WbTree`, and returns it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::weight_balanced_tree::WbTree;
+    /// let mut tree = WbTree::from(vec![8, 4, 6]);
+    /// assert_eq!(tree.remove(&4), Some(4));
+    /// assert_eq!(tree.remove(&4), None);
+    /// assert_eq!(tree.count(), 2);
+    /// ```
+    pub fn remove(&mut self, target: &T) -> Option<T> {
+        let (root, removed) = remove(self.root.take(), target);
+        self.root = root;
+
+        removed
+    }
+
+    /// Returns the number of elements in the tree that are smaller than the given value.
+    ///
+    /// If the value is in the tree, this is its position in ascending order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::weight_balanced_tree::WbTree;
+    /// let tree = WbTree::from(vec![30, 10, 20]);
+    /// assert_eq!(tree.rank(&20), 1);
+    /// assert_eq!(tree.rank(&25), 2);
+    /// assert_eq!(tree.rank(&0), 0);
+    /// ```
+    pub fn rank(&self, target: &T) -> usize {
+        let mut rank = 0;
+        let mut node = self.root.as_deref();
+
+        while let Some(current) = node {
+            node = match target.cmp(&current.value) {
+                Ordering::Equal => return rank + size(&current.left),
+                Ordering::Less => current.left.as_deref(),
+                Ordering::Greater => {
+                    rank += size(&current.left) + 1;
+                    current.right.as_deref()
+                }
+            };
+        }
+
+        rank
+    }
+
+    /// Joins two trees and a value between them into one balanced tree.
+    ///
+    /// Every element of `left` must be smaller than `value`, and every element of `right` must be
+    /// larger, otherwise the resulting tree is not ordered.
+    ///
+    /// # Time Complexity
+    ///
+    /// Only the spine of the larger tree is walked, which takes time logarithmic in the ratio of
+    /// the two sizes.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::weight_balanced_tree::WbTree;
+    /// let left: WbTree<i32> = (0..10).collect();
+    /// let right: WbTree<i32> = (11..100).collect();
+    ///
+    /// let tree = WbTree::join(left, 10, right);
+    /// assert_eq!(tree.count(), 100);
+    /// assert!(tree.iter().copied().eq(0..100));
+    /// ```
+    #[must_use]
+    pub fn join(left: Self, value: T, right: Self) -> Self {
+        debug_assert!(left.max().into_iter().all(|max| max < &value));
+        debug_assert!(right.min().into_iter().all(|min| min > &value));
+
+        Self {
+            root: Some(join(left.root, value, right.root)),
+        }
+    }
+
+    /// Splits the tree into the elements smaller than the given value, the element equal to it if
+    /// present, and the elements larger than it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::weight_balanced_tree::WbTree;
+    /// let tree: WbTree<i32> = (0..10).collect();
+    ///
+    /// let (smaller, found, larger) = tree.split(&4);
+    /// assert!(smaller.iter().copied().eq(0..4));
+    /// assert_eq!(found, Some(4));
+    /// assert!(larger.iter().copied().eq(5..10));
+    /// ```
+    #[must_use]
+    pub fn split(self, value: &T) -> (Self, Option<T>, Self) {
+        let (left, found, right) = split(self.root, value);
+
+        (Self { root: left }, found, Self { root: right })
+    }
+
+    /// Returns a tree with the elements found in either tree.
+    ///
+    /// Where both trees hold an equal element, the one from `self` is kept.
+    ///
+    /// # Time Complexity
+    ///
+    /// For trees of sizes `m` and `n` where `m <= n`, this takes `O(m log(n / m + 1))` time,
+    /// which is linear for trees of similar size, and logarithmic when one of them is tiny.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::weight_balanced_tree::WbTree;
+    /// let a = WbTree::from(vec![1, 2, 3]);
+    /// let b = WbTree::from(vec![3, 4, 5]);
+    ///
+    /// let union = a.union(b);
+    /// assert!(union.iter().copied().eq(1..=5));
+    /// ```
+    #[must_use]
+    pub fn union(self, other: Self) -> Self {
+        Self {
+            root: union(self.root, other.root),
+        }
+    }
+
+    /// Returns a tree with the elements found in both trees.
+    ///
+    /// The elements are taken from `self`.
+    ///
+    /// # Time Complexity
+    ///
+    /// For trees of sizes `m` and `n` where `m <= n`, this takes `O(m log(n / m + 1))` time.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::weight_balanced_tree::WbTree;
+    /// let a = WbTree::from(vec![1, 2, 3]);
+    /// let b = WbTree::from(vec![3, 4, 5]);
+    ///
+    /// let intersection = a.intersection(b);
+    /// assert!(intersection.iter().copied().eq([3]));
+    /// ```
+    #[must_use]
+    pub fn intersection(self, other: Self) -> Self {
+        Self {
+            root: intersection(self.root, other.root),
+        }
+    }
+
+    /// Returns a tree with the elements of `self` that are not found in `other`.
+    ///
+    /// # Time Complexity
+    ///
+    /// For trees of sizes `m` and `n` where `m <= n`, this takes `O(m log(n / m + 1))` time.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::weight_balanced_tree::WbTree;
+    /// let a = WbTree::from(vec![1, 2, 3]);
+    /// let b = WbTree::from(vec![3, 4, 5]);
+    ///
+    /// let difference = a.difference(b);
+    /// assert!(difference.iter().copied().eq([1, 2]));
+    /// ```
+    #[must_use]
+    pub fn difference(self, other: Self) -> Self {
+        Self {
+            root: difference(self.root, other.root),
+        }
+    }
+}
+
+/// Returns the number of elements in the subtree.
+#[inline]
+fn size<T>(link: &Link<T>) -> usize {
+    link.as_deref().map_or(0, |node| node.size)
+}
+
+/// Returns the weight of the subtree, which is its size plus one.
+#[inline]
+fn weight<T>(link: &Link<T>) -> usize {
+    size(link) + 1
+}
+
+/// Returns `true` if subtrees of the given weights may be siblings.
+///
+/// This holds when neither weighs less than `2 / 7` of their combined weight.
+#[inline]
+fn like(a: usize, b: usize) -> bool {
+    5 * a >= 2 * b && 5 * b >= 2 * a
+}
+
+/// Joins two subtrees and a value between them into one balanced subtree.
+fn join<T>(left: Link<T>, value: T, right: Link<T>) -> Box<Node<T>> {
+    let (left_weight, right_weight) = (weight(&left), weight(&right));
+
+    if like(left_weight, right_weight) {
+        Node::new(left, value, right)
+    } else if left_weight > right_weight {
+        join_right(left, value, right)
+    } else {
+        join_left(left, value, right)
+    }
+}
+
+/// Joins the lighter `right` subtree into the right spine of the heavier `left` subtree.
+fn join_right<T>(left: Link<T>, value: T, right: Link<T>) -> Box<Node<T>> {
+    if like(weight(&left), weight(&right)) {
+        return Node::new(left, value, right);
+    }
+    let Some(left) = left else {
+        return Node::new(None, value, right);
+    };
+    let Node {
+        value: left_value,
+        left: outer,
+        right: inner,
+        ..
+    } = *left;
+
+    let joined = join_right(inner, value, right);
+    let outer_weight = weight(&outer);
+
+    if like(outer_weight, joined.size + 1) {
+        Node::new(outer, left_value, Some(joined))
+    } else if like(outer_weight, weight(&joined.left))
+        && like(outer_weight + weight(&joined.left), weight(&joined.right))
+    {
+        rotate_left(Node::new(outer, left_value, Some(joined)))
+    } else {
+        rotate_left(Node::new(outer, left_value, Some(rotate_right(joined))))
+    }
+}
+
+/// Joins the lighter `left` subtree into the left spine of the heavier `right` subtree.
+fn join_left<T>(left: Link<T>, value: T, right: Link<T>) -> Box<Node<T>> {
+    if like(weight(&left), weight(&right)) {
+        return Node::new(left, value, right);
+    }
+    let Some(right) = right else {
+        return Node::new(left, value, None);
+    };
+    let Node {
+        value: right_value,
+        left: inner,
+        right: outer,
+        ..
+    } = *right;
+
+    let joined = join_left(left, value, inner);
+    let outer_weight = weight(&outer);
+
+    if like(joined.size + 1, outer_weight) {
+        Node::new(Some(joined), right_value, outer)
+    } else if like(weight(&joined.right), outer_weight)
+        && like(weight(&joined.left), weight(&joined.right) + outer_weight)
+    {
+        rotate_right(Node::new(Some(joined), right_value, outer))
+    } else {
+        rotate_right(Node::new(Some(rotate_left(joined)), right_value, outer))
+    }
+}
+
+/// Joins two subtrees, where every element of `left` is smaller than every element of `right`.
+fn join2<T>(left: Link<T>, right: Link<T>) -> Link<T> {
+    match left {
+        None => right,
+        Some(left) => {
+            let (rest, last) = split_last(*left);
+            Some(join(rest, last, right))
+        }
+    }
+}
+
+/// Removes the largest element of the subtree, and returns the rest alongside it.
+fn split_last<T>(node: Node<T>) -> (Link<T>, T) {
+    let Node {
+        value, left, right, ..
+    } = node;
+
+    match right {
+        None => (left, value),
+        Some(right) => {
+            let (rest, last) = split_last(*right);
+            (Some(join(left, value, rest)), last)
+        }
+    }
+}
+
+/// Splits the subtree into the elements smaller than `target`, the element equal to it,
+/// and the elements larger than it.
+fn split<T: Ord>(link: Link<T>, target: &T) -> (Link<T>, Option<T>, Link<T>) {
+    let Some(node) = link else {
+        return (None, None, None);
+    };
+    let Node {
+        value, left, right, ..
+    } = *node;
+
+    match target.cmp(&value) {
+        Ordering::Equal => (left, Some(value), right),
+        Ordering::Less => {
+            let (smaller, found, larger) = split(left, target);
+            (smaller, found, Some(join(larger, value, right)))
+        }
+        Ordering::Greater => {
+            let (smaller, found, larger) = split(right, target);
+            (Some(join(left, value, smaller)), found, larger)
+        }
+    }
+}
+
+fn insert<T: Ord>(link: Link<T>, value: T) -> Box<Node<T>> {
+    let Some(node) = link else {
+        return Node::new(None, value, None);
+    };
+    let Node {
+        value: current,
+        left,
+        right,
+        ..
+    } = *node;
+
+    match value.cmp(&current) {
+        Ordering::Equal => Node::new(left, current, right),
+        Ordering::Less => join(Some(insert(left, value)), current, right),
+        Ordering::Greater => join(left, current, Some(insert(right, value))),
+    }
+}
+
+fn remove<T: Ord>(link: Link<T>, target: &T) -> (Link<T>, Option<T>) {
+    let Some(node) = link else {
+        return (None, None);
+    };
+    let Node {
+        value, left, right, ..
+    } = *node;
+
+    match target.cmp(&value) {
+        Ordering::Equal => (join2(left, right), Some(value)),
+        Ordering::Less => {
+            let (left, removed) = remove(left, target);
+            (Some(join(left, value, right)), removed)
+        }
+        Ordering::Greater => {
+            let (right, removed) = remove(right, target);
+            (Some(join(left, value, right)), removed)
+        }
+    }
+}
+
+fn union<T: Ord>(a: Link<T>, b: Link<T>) -> Link<T> {
+    let (a, b) = match (a, b) {
+        (None, link) | (link, None) => return link,
+        (Some(a), Some(b)) => (a, b),
+    };
+    let Node {
+        value, left, right, ..
+    } = *b;
+
+    let (smaller, found, larger) = split(Some(a), &value);
+    let left = union(smaller, left);
+    let right = union(larger, right);
+
+    Some(join(left, found.unwrap_or(value), right))
+}
+
+fn intersection<T: Ord>(a: Link<T>, b: Link<T>) -> Link<T> {
+    let (a, b) = match (a, b) {
+        (None, _) | (_, None) => return None,
+        (Some(a), Some(b)) => (a, b),
+    };
+    let Node {
+        value, left, right, ..
+    } = *b;
+
+    let (smaller, found, larger) = split(Some(a), &value);
+    let left = intersection(smaller, left);
+    let right = intersection(larger, right);
+
+    match found {
+        Some(found) => Some(join(left, found, right)),
+        None => join2(left, right),
+    }
+}
+
+fn difference<T: Ord>(a: Link<T>, b: Link<T>) -> Link<T> {
+    let (a, b) = match (a, b) {
+        (None, _) => return None,
+        (link, None) => return link,
+        (Some(a), Some(b)) => (a, b),
+    };
+    let Node {
+        value, left, right, ..
+    } = *b;
+
+    let (smaller, _, larger) = split(Some(a), &value);
+    join2(difference(smaller, left), difference(larger, right))
+}
+
+/// Makes the right child of `node` its parent.
+fn rotate_left<T>(mut node: Box<Node<T>>) -> Box<Node<T>> {
+    let Some(mut right) = node.right.take() else {
+        return node;
+    };
+
+    node.right = right.left.take();
+    node.update_size();
+    right.left = Some(node);
+    right.update_size();
+
+    right
+}
+
+/// Makes the left child of `node` its parent.
+fn rotate_right<T>(mut node: Box<Node<T>>) -> Box<Node<T>> {
+    let Some(mut left) = node.left.take() else {
+        return node;
+    };
+
+    node.left = left.right.take();
+    node.update_size();
+    left.right = Some(node);
+    left.update_size();
+
+    left
+}
+
+impl<T: Ord> SortedSet<T> for WbTree<T> {
+    type Iter<'a>
+        = Iter<'a, T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    fn insert(&mut self, value: T) -> bool {
+        let count = self.count();
+        WbTree::insert(self, value);
+
+        self.count() > count
+    }
+
+    fn remove(&mut self, target: &T) -> Option<T> {
+        WbTree::remove(self, target)
+    }
+
+    fn contains(&self, target: &T) -> bool {
+        WbTree::contains(self, target)
+    }
+
+    fn min(&self) -> Option<&T> {
+        WbTree::min(self)
+    }
+
+    fn max(&self) -> Option<&T> {
+        WbTree::max(self)
+    }
+
+    fn len(&self) -> usize {
+        self.count()
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        WbTree::iter(self)
+    }
+}
+
+impl<T: Ord> From<Vec<T>> for WbTree<T> {
+    /// Creates a `WbTree<T>` from `Vec<T>`.
+    fn from(vec: Vec<T>) -> Self {
+        let mut tree = WbTree::new();
+        for v in vec {
+            tree.insert(v);
+        }
+
+        tree
+    }
+}
+
+impl<T: Ord> FromIterator<T> for WbTree<T> {
+    /// Constructs a `WbTree<T>` from an iterator for `T`.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut tree = WbTree::new();
+
+        for v in iter {
+            tree.insert(v);
+        }
+
+        tree
+    }
+}
+
+impl<T: Ord> Extend<T> for WbTree<T> {
+    /// Extends the `WbTree` with the contents of the provided iterator.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for v in iter {
+            self.insert(v);
+        }
+    }
+}
+
+impl<T> IntoIterator for WbTree<T> {
+    type Item = T;
+
+    type IntoIter = IntoIter<T>;
+
+    /// Returns a consuming iterator over the `WbTree`.
+    ///
+    /// The iterator yields all items in ascending order.
+    ///
+    /// # Examples
+    /// ```
+    /// # use ds_rs::weight_balanced_tree::WbTree;
+    /// let tree = WbTree::from(vec![5, 4, 6]);
+    /// let mut tree_iter = tree.into_iter();
+    ///
+    /// assert_eq!(tree_iter.next(), Some(4));
+    /// assert_eq!(tree_iter.next(), Some(5));
+    /// assert_eq!(tree_iter.next(), Some(6));
+    ///
+    /// // the iterator is now empty
+    /// assert_eq!(tree_iter.next(), None);
+    /// ```
+    fn into_iter(self) -> Self::IntoIter {
+        let mut iter = IntoIter { stack: Vec::new() };
+        iter.push_left(self.root);
+
+        iter
+    }
+}
+
+/// An iterator that moves out of the `WbTree`.
+///
+/// This `struct` is created by the `into_iter` method on [`WbTree`] (provided by the [`IntoIterator`] trait).
+pub struct IntoIter<T> {
+    stack: Vec<(T, Link<T>)>,
+}
+
+impl<T> IntoIter<T> {
+    /// Pushes the left spine of the subtree, keeping each right subtree for later.
+    fn push_left(&mut self, mut link: Link<T>) {
+        while let Some(node) = link {
+            let Node {
+                value, left, right, ..
+            } = *node;
+            self.stack.push((value, right));
+            link = left;
+        }
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (value, right) = self.stack.pop()?;
+        self.push_left(right);
+
+        Some(value)
+    }
+}
+
+impl<'a, T> IntoIterator for &'a WbTree<T> {
+    type Item = &'a T;
+
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let mut iter = Iter {
+            stack: Vec::new(),
+            remaining: self.count(),
+        };
+        iter.push_left(self.root.as_deref());
+
+        iter
+    }
+}
+
+/// An iterator that borrows from the `WbTree`.
+///
+/// This `struct` is created by the `iter` method on [`WbTree`].
+pub struct Iter<'a, T> {
+    stack: Vec<&'a Node<T>>,
+    remaining: usize,
+}
+
+impl<'a, T> Iter<'a, T> {
+    /// Pushes the left spine of the subtree.
+    fn push_left(&mut self, mut node: Option<&'a Node<T>>) {
+        while let Some(current) = node {
+            self.stack.push(current);
+            node = current.left.as_deref();
+        }
+    }
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.stack.pop()?;
+        self.push_left(node.right.as_deref());
+        self.remaining -= 1;
+
+        Some(&node.value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+#[cfg_attr(feature = "json", derive(Serialize, Deserialize))]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Node<T> {
+    value: T,
+    size: usize,
+    left: Link<T>,
+    right: Link<T>,
+}
+
+impl<T> Node<T> {
+    /// Constructs a new boxed `Node<T>`, and computes its size from its children.
+    fn new(left: Link<T>, value: T, right: Link<T>) -> Box<Self> {
+        Box::new(Self {
+            size: size(&left) + size(&right) + 1,
+            value,
+            left,
+            right,
+        })
+    }
+
+    #[inline]
+    fn update_size(&mut self) {
+        self.size = size(&self.left) + size(&self.right) + 1;
+    }
+}
+
+#[cfg(test)]
+impl<T: Ord> WbTree<T> {
+    /// Panics if any node breaks the size, balance or ordering invariants of the tree.
+    fn assert_invariants(&self) {
+        fn check<T: Ord>(link: &Link<T>, lower: Option<&T>, upper: Option<&T>) -> usize {
+            let Some(node) = link.as_deref() else {
+                return 0;
+            };
+
+            assert!(lower.into_iter().all(|lower| lower < &node.value));
+            assert!(upper.into_iter().all(|upper| upper > &node.value));
+            assert!(like(weight(&node.left), weight(&node.right)));
+
+            let size = check(&node.left, lower, Some(&node.value))
+                + check(&node.right, Some(&node.value), upper)
+                + 1;
+            assert_eq!(node.size, size);
+
+            size
+        }
+
+        check(&self.root, None, None);
+    }
+}
+
+#[cfg(test)]
+mod insert_remove {
+    use super::{Node, WbTree};
+
+    #[test]
+    fn insert_one_element_that_becomes_root() {
+        let mut tree = WbTree::new();
+        let expected = WbTree {
+            root: Some(Box::new(Node {
+                value: 5,
+                size: 1,
+                left: None,
+                right: None,
+            })),
+        };
+
+        tree.insert(5);
+        assert_eq!(tree, expected);
+    }
+
+    #[test]
+    fn ascending_inserts_rotate_into_balance() {
+        let mut tree = WbTree::new();
+        let expected = WbTree {
+            root: Some(Box::new(Node {
+                value: 2,
+                size: 3,
+                left: Some(Box::new(Node {
+                    value: 1,
+                    size: 1,
+                    left: None,
+                    right: None,
+                })),
+                right: Some(Box::new(Node {
+                    value: 3,
+                    size: 1,
+                    left: None,
+                    right: None,
+                })),
+            })),
+        };
+
+        tree.extend([1, 2, 3]);
+        assert_eq!(tree, expected);
+    }
+
+    #[test]
+    fn discards_duplicates() {
+        let tree = WbTree::from(vec![3, 1, 3, 2, 1]);
+
+        assert_eq!(tree.count(), 3);
+        tree.assert_invariants();
+    }
+
+    #[test]
+    fn sorted_inserts_stay_balanced() {
+        let mut tree = WbTree::new();
+
+        for v in 0..500 {
+            tree.insert(v);
+            tree.assert_invariants();
+        }
+
+        assert!(tree.height() <= 15);
+    }
+
+    #[test]
+    fn removes_every_element() {
+        let mut tree: WbTree<i32> = (0..300).collect();
+
+        for v in (0..300).map(|v| (v * 7) % 300) {
+            assert_eq!(tree.remove(&v), Some(v));
+            assert_eq!(tree.remove(&v), None);
+            tree.assert_invariants();
+        }
+
+        assert!(tree.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod order_statistics {
+    use super::WbTree;
+
+    #[test]
+    fn select_every_position() {
+        let tree: WbTree<i32> = (0..100).rev().map(|v| v * 2).collect();
+
+        for index in 0..100 {
+            assert_eq!(tree.select(index), Some(&(index as i32 * 2)));
+        }
+        assert_eq!(tree.select(100), None);
+    }
+
+    #[test]
+    fn rank_of_present_and_missing_values() {
+        let tree: WbTree<i32> = (0..100).map(|v| v * 2).collect();
+
+        assert_eq!(tree.rank(&0), 0);
+        assert_eq!(tree.rank(&50), 25);
+        assert_eq!(tree.rank(&51), 26);
+        assert_eq!(tree.rank(&1000), 100);
+        assert_eq!(tree.rank(&-1), 0);
+    }
+
+    #[test]
+    fn empty_tree() {
+        let tree: WbTree<i32> = WbTree::new();
+
+        assert_eq!(tree.select(0), None);
+        assert_eq!(tree.rank(&0), 0);
+    }
+}
+
+#[cfg(test)]
+mod join_split {
+    use super::WbTree;
+
+    #[test]
+    fn joins_trees_of_very_different_sizes() {
+        let left: WbTree<i32> = (0..3).collect();
+        let right: WbTree<i32> = (4..500).collect();
+
+        let tree = WbTree::join(left, 3, right);
+
+        tree.assert_invariants();
+        assert!(tree.iter().copied().eq(0..500));
+
+        let left: WbTree<i32> = (0..496).collect();
+        let right: WbTree<i32> = (497..500).collect();
+
+        let tree = WbTree::join(left, 496, right);
+
+        tree.assert_invariants();
+        assert!(tree.iter().copied().eq(0..500));
+    }
+
+    #[test]
+    fn joins_empty_trees() {
+        let tree = WbTree::join(WbTree::new(), 1, WbTree::new());
+
+        assert!(tree.iter().copied().eq([1]));
+    }
+
+    #[test]
+    fn splits_at_missing_value() {
+        let tree: WbTree<i32> = (0..100).map(|v| v * 2).collect();
+
+        let (smaller, found, larger) = tree.split(&51);
+
+        smaller.assert_invariants();
+        larger.assert_invariants();
+        assert_eq!(found, None);
+        assert!(smaller.iter().copied().eq((0..26).map(|v| v * 2)));
+        assert!(larger.iter().copied().eq((26..100).map(|v| v * 2)));
+    }
+
+    #[test]
+    fn splits_beyond_either_end() {
+        let tree: WbTree<i32> = (0..10).collect();
+
+        let (smaller, found, larger) = tree.clone().split(&-1);
+        assert!(smaller.is_empty() && found.is_none() && larger.count() == 10);
+
+        let (smaller, found, larger) = tree.split(&10);
+        assert!(smaller.count() == 10 && found.is_none() && larger.is_empty());
+    }
+}
+
+#[cfg(test)]
+mod set_operations {
+    use super::WbTree;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+    use std::collections::BTreeSet;
+
+    fn random_set(rng: &mut StdRng, count: usize, max: u32) -> BTreeSet<u32> {
+        (0..count).map(|_| rng.gen_range(0..max)).collect()
+    }
+
+    fn tree(set: &BTreeSet<u32>) -> WbTree<u32> {
+        set.iter().copied().collect()
+    }
+
+    #[test]
+    fn match_std_set_operations() {
+        let mut rng = StdRng::seed_from_u64(29);
+
+        for (a_count, b_count) in [(0, 10), (10, 0), (5, 500), (500, 5), (300, 300), (1, 1)] {
+            let a = random_set(&mut rng, a_count, 1000);
+            let b = random_set(&mut rng, b_count, 1000);
+
+            let union = tree(&a).union(tree(&b));
+            let intersection = tree(&a).intersection(tree(&b));
+            let difference = tree(&a).difference(tree(&b));
+
+            union.assert_invariants();
+            intersection.assert_invariants();
+            difference.assert_invariants();
+
+            assert!(union.iter().eq(a.union(&b)));
+            assert!(intersection.iter().eq(a.intersection(&b)));
+            assert!(difference.iter().eq(a.difference(&b)));
+            assert_eq!(union.count(), a.union(&b).count());
+        }
+    }
+
+    #[test]
+    fn union_keeps_elements_of_self() {
+        #[derive(Debug)]
+        struct Tagged(i32, &'static str);
+
+        impl PartialEq for Tagged {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+        impl Eq for Tagged {}
+        impl PartialOrd for Tagged {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl Ord for Tagged {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+
+        let a = WbTree::from(vec![Tagged(1, "a"), Tagged(2, "a")]);
+        let b = WbTree::from(vec![Tagged(2, "b"), Tagged(3, "b")]);
+
+        let tags: Vec<_> = a.union(b).into_iter().map(|tagged| tagged.1).collect();
+        assert_eq!(tags, vec!["a", "a", "b"]);
+    }
+}
+
+#[cfg(test)]
+mod iterator_trait_impls {
+    use super::WbTree;
+
+    #[test]
+    fn iter_yields_ascending_order() {
+        let tree: WbTree<i32> = [5, 3, 8, 1, 4, 7, 9].into_iter().collect();
+        let mut iter = tree.iter();
+
+        assert_eq!(iter.len(), 7);
+        assert!(iter.by_ref().copied().take(3).eq([1, 3, 4]));
+        assert_eq!(iter.len(), 4);
+        assert!(iter.copied().eq([5, 7, 8, 9]));
+    }
+
+    #[test]
+    fn into_iter_yields_ascending_order() {
+        let tree: WbTree<i32> = (0..50).rev().collect();
+
+        assert!(tree.into_iter().eq(0..50));
+    }
+}
+
+#[cfg(all(test, feature = "json"))]
+mod json {
+    use super::WbTree;
+
+    #[test]
+    fn round_trips_through_json() {
+        let tree: WbTree<i32> = (0..20).collect();
+
+        let json = serde_json::to_string(&tree).expect("should parse tree into json");
+        let actual: WbTree<i32> = serde_json::from_str(&json).expect("should parse json into tree");
+
+        assert_eq!(actual, tree);
+    }
+}
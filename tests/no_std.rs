@@ -0,0 +1,68 @@
+//! Exercises the data structures kept without the **std** feature, using only `core` and `alloc`.
+//!
+//! Run with `cargo test --no-default-features`, and `--features json` to cover the serde derives.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use ds_rs::binary_tree::diff::Change;
+use ds_rs::binary_tree::fallible::TryInsertError;
+use ds_rs::binary_tree::BinaryTree;
+use ds_rs::bounded_tree::{BoundedTree, Evict};
+
+#[test]
+fn binary_tree_iterators() {
+    let tree = BinaryTree::from(vec![8, 4, 12, 2, 6]);
+
+    assert!(tree.in_order().eq(&[2, 4, 6, 8, 12]));
+    assert!(tree.iter().eq(&[8, 4, 2, 6, 12]));
+    assert!(tree.into_iter().eq([8, 4, 2, 6, 12]));
+}
+
+#[test]
+fn binary_tree_set_operations() {
+    let a = BinaryTree::from(vec![1, 2, 3, 4]);
+    let b = BinaryTree::from(vec![3, 4, 5]);
+
+    assert!(a.union(&b).eq(&[1, 2, 3, 4, 5]));
+    assert!((&a & &b).in_order().eq(&[3, 4]));
+
+    let changes: Vec<_> = a.diff(&b).collect();
+    assert_eq!(
+        changes,
+        vec![Change::Removed(&1), Change::Removed(&2), Change::Added(&5)]
+    );
+}
+
+#[test]
+fn binary_tree_fallible_insertion() {
+    let mut tree = BinaryTree::with_max_len(2);
+
+    assert_eq!(tree.try_extend([2, 1]), Ok(()));
+    assert_eq!(tree.try_insert(3), Err(TryInsertError::MaxLenReached(3)));
+    assert_eq!(tree.pop_min(), Some(1));
+    assert_eq!(tree.try_insert(3), Ok(()));
+}
+
+#[test]
+fn bounded_tree_eviction() {
+    let mut tree = BoundedTree::new(2, Evict::Min);
+    tree.extend([5, 1, 9]);
+
+    assert!(tree.iter().eq(&[5, 9]));
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn binary_tree_json() {
+    let tree = BinaryTree::from(vec![2, 1, 3]);
+
+    let json = serde_json::to_string(&tree).expect("should parse tree into json");
+    let actual: BinaryTree<i32> = serde_json::from_str(&json).expect("should parse json into tree");
+
+    assert_eq!(actual, tree);
+}